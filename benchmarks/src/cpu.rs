@@ -18,12 +18,17 @@ fn inner(c: &mut Criterion) {
         b.iter(|| smol::block_on(heim::cpu::stats()))
     });
 
-    c.bench_function("cpu_time", |b| b.iter(|| smol::block_on(heim::cpu::time())));
-
     c.bench_function("cpu_times", |b| {
+        b.iter(|| smol::block_on(heim::cpu::times()))
+    });
+
+    c.bench_function("cpu_per_cpu_times", |b| {
         b.iter(|| {
             smol::block_on(async {
-                heim::cpu::times().await?.for_each(|_| async {}).await;
+                heim::cpu::per_cpu_times()
+                    .await?
+                    .for_each(|_| async {})
+                    .await;
                 Ok::<(), heim::Error>(())
             })
         })
@@ -1,56 +1,142 @@
+//! Minimal `top`-like view over the running processes.
+//!
+//! Processes are sorted by the CPU usage and the view is refreshed every two seconds.
+//! Amount of refreshes can be passed as a program argument (three by default), ex.
+//!
+//! ```
+//! $ cargo run --example top -- 10
+//! ```
+
+use std::collections::HashMap;
+use std::env;
 use std::time::Duration;
-use std::usize;
 
 use futures::prelude::*;
 #[cfg(unix)]
-use heim::cpu::os::unix::loadavg;
+use heim::{cpu::os::unix::loadavg, units::ratio};
 use heim::{
-    process::{self, Process, ProcessResult},
-    units::{ratio, Ratio},
+    process::{self, Pid, Process, ProcessInfo, ProcessResult, ProcessStreamExt},
+    units::information,
 };
 
-async fn usage(process: Process) -> ProcessResult<(process::Process, Ratio)> {
-    let usage_1 = process.cpu_usage().await?;
-    futures_timer::Delay::new(Duration::from_millis(100)).await;
-    let usage_2 = process.cpu_usage().await?;
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+const ROWS: usize = 20;
+
+struct Row {
+    pid: Pid,
+    name: String,
+    status: process::Status,
+    cpu: f32,
+    memory: f32,
+}
 
-    Ok((process, usage_2 - usage_1))
+/// Loads the CPU and memory usage for one process.
+///
+/// `Process::cpu_percent(None)` compares with the previous call for the same `Process` value,
+/// that's why process handles are stored between refreshes.
+async fn row(process: &Process, info: &ProcessInfo, total_memory: u64) -> ProcessResult<Row> {
+    let (cpu, memory) = future::try_join(process.cpu_percent(None), process.memory()).await?;
+    let rss = memory.rss().get::<information::byte>();
+
+    Ok(Row {
+        pid: process.pid(),
+        name: info.name().to_string(),
+        status: info.status(),
+        cpu,
+        memory: rss as f32 / total_memory as f32 * 100.0,
+    })
+}
+
+/// Refreshes the `known` processes handles and returns the usage rows for them.
+async fn refresh(known: &mut HashMap<Pid, Process>) -> ProcessResult<Vec<Row>> {
+    let total_memory = heim::memory::memory()
+        .await?
+        .total()
+        .get::<information::byte>();
+
+    let current = process::processes_with_info()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // Forgetting processes which are gone, PIDs might be reused by the new ones already
+    let mut alive = HashMap::with_capacity(current.len());
+    for (process, info) in current {
+        let process = match known.remove(&process.pid()) {
+            Some(previous) if previous == process => previous,
+            _ => process,
+        };
+        let _ = alive.insert(process.pid(), (process, info));
+    }
+
+    let rows = stream::iter(alive.values())
+        .map(|(process, info)| row(process, info, total_memory))
+        .buffer_unordered(64)
+        // Process exited meanwhile or is not accessible for the current user
+        .skip_transient()
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    known.extend(
+        alive
+            .into_iter()
+            .map(|(pid, (process, _info))| (pid, process)),
+    );
+
+    Ok(rows)
 }
 
 fn main() -> ProcessResult<()> {
+    let refreshes = env::args()
+        .nth(1)
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(3);
+
     smol::block_on(async {
-        #[cfg(unix)]
-        {
-            let (one, five, fifteen) = loadavg().await?;
-            println!(
-                "Load average: {} {} {}",
-                one.get::<ratio::ratio>(),
-                five.get::<ratio::ratio>(),
-                fifteen.get::<ratio::ratio>()
-            );
-        }
+        let mut known = HashMap::new();
+
+        for iteration in 0..refreshes {
+            let mut rows = refresh(&mut known).await?;
+
+            // First refresh has nothing to compare CPU usage with
+            if iteration > 0 {
+                #[cfg(unix)]
+                {
+                    let (one, five, fifteen) = loadavg().await?;
+                    println!(
+                        "Load average: {:.2} {:.2} {:.2}",
+                        one.get::<ratio::ratio>(),
+                        five.get::<ratio::ratio>(),
+                        fifteen.get::<ratio::ratio>()
+                    );
+                }
+                println!("Processes: {}", rows.len());
+
+                rows.sort_by(|a, b| {
+                    b.cpu
+                        .partial_cmp(&a.cpu)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                println!(
+                    "| {:>7} | {:30} | {:10} | {:>7} | {:>7} |",
+                    "PID", "NAME", "STATUS", "CPU %", "MEM %"
+                );
+                for row in rows.iter().take(ROWS) {
+                    println!(
+                        "| {:>7} | {:30.30} | {:10} | {:>7.1} | {:>7.1} |",
+                        row.pid,
+                        row.name,
+                        format!("{:?}", row.status),
+                        row.cpu,
+                        row.memory
+                    );
+                }
+                println!();
+            }
 
-        let processes = process::processes()
-            .await?
-            .map_ok(|process| {
-                // Note that there is no `.await` here,
-                // as we want to pass the returned future
-                // into the `.try_buffer_unordered`.
-                usage(process)
-            })
-            .try_buffer_unordered(usize::MAX);
-        futures::pin_mut!(processes);
-
-        println!("| {:6} | {:40} | {:4} % |", "pid", "name", "CPU");
-        while let Some(res) = processes.next().await {
-            let (process, usage) = res?;
-
-            println!(
-                "| {:6} | {:40} | {:.2} |",
-                process.pid(),
-                process.name().await?,
-                usage.get::<ratio::percent>()
-            );
+            if iteration + 1 < refreshes {
+                heim_runtime::time::sleep(REFRESH_INTERVAL).await;
+            }
         }
 
         Ok(())
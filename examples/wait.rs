@@ -27,8 +27,10 @@ fn main() -> Result<(), Box<dyn Error>> {
         let process = process::get(pid).await?;
 
         println!("Watching for process {} completion", pid);
-        process.wait().await?;
-        println!("Process {} had exited", pid);
+        match process.wait().await? {
+            Some(status) => println!("Process {} had exited with {}", pid, status),
+            None => println!("Process {} had exited", pid),
+        }
 
         Ok(())
     })
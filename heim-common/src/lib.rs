@@ -28,6 +28,7 @@
 
 mod errors;
 mod macros;
+mod pid;
 #[doc(hidden)]
 pub mod sys;
 pub mod units;
@@ -35,15 +36,12 @@ pub mod units;
 pub mod utils;
 
 pub use self::errors::{Context, Error, Result};
+pub use self::pid::{Pid, RawPid};
 
-/// Process identifier type.
-#[cfg(unix)]
-pub type Pid = libc::pid_t;
-
-/// Process identifier type.
-// TODO: Is it a correct type for pid?
-#[cfg(target_os = "windows")]
-pub type Pid = winapi::shared::minwindef::DWORD;
+// Used by the `heim-*` crates for `Serialize` and `Deserialize` derives
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde_crate as serde;
 
 /// Prelude intended to be used across `heim-*` crates.
 ///
@@ -34,3 +34,73 @@ macro_rules! wrap {
         }
     };
 }
+
+/// Implements `serde` traits for the public data types,
+/// if the `serde` feature of the calling crate is enabled.
+///
+/// Types hiding the platform-specific implementations are serialized
+/// as a struct of their accessor methods values, so they have the same fields set
+/// for all platforms; these types are not deserializable:
+///
+/// ```rust,ignore
+/// heim_common::serde!(Memory { total, available, free });
+/// ```
+///
+/// Field value can be converted into a serializable one by a closure-like expression,
+/// which receives the type reference:
+///
+/// ```rust,ignore
+/// heim_common::serde!(Partition {
+///     mount_point => |part| part.mount_point().to_string_lossy(),
+/// });
+/// ```
+///
+/// Plain data types are passed as is and derive both `Serialize` and `Deserialize`:
+///
+/// ```rust,ignore
+/// heim_common::serde! {
+///     #[derive(Debug)]
+///     pub enum State { On, Off }
+/// }
+/// ```
+#[macro_export]
+macro_rules! serde {
+    ($type:ident { $($field:ident $(=> |$arg:ident| $value:expr)?),+ $(,)? }) => {
+        #[cfg(feature = "serde")]
+        impl $crate::serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                use $crate::serde::ser::SerializeStruct;
+
+                let fields = [$(stringify!($field)),+].len();
+                let mut state = serializer.serialize_struct(stringify!($type), fields)?;
+                $(
+                    state.serialize_field(
+                        stringify!($field),
+                        &$crate::serde!(@value self, $field $(=> |$arg| $value)?),
+                    )?;
+                )+
+                state.end()
+            }
+        }
+    };
+    (@value $self:ident, $field:ident) => {
+        $self.$field()
+    };
+    (@value $self:ident, $field:ident => |$arg:ident| $value:expr) => {{
+        let $arg = $self;
+        $value
+    }};
+    ($($item:item)+) => {
+        $(
+            #[cfg_attr(
+                feature = "serde",
+                derive($crate::serde::Serialize, $crate::serde::Deserialize),
+                serde(crate = "heim_common::serde")
+            )]
+            $item
+        )+
+    };
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+use std::num::ParseIntError;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Raw process identifier type used by the operating system.
+#[cfg(unix)]
+pub type RawPid = libc::pid_t;
+
+/// Raw process identifier type used by the operating system.
+#[cfg(target_os = "windows")]
+pub type RawPid = winapi::shared::minwindef::DWORD;
+
+/// Process identifier.
+///
+/// It is a thin wrapper around the platform process identifier type ([RawPid]),
+/// which prevents accidental mixing of pids with other integers.
+///
+/// Raw value can be accessed via the `From` / `Into` conversions
+/// or by dereferencing the `Pid`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::Pid;
+/// let pid: Pid = "1234".parse().unwrap();
+///
+/// assert_eq!(Pid::from(1234), pid);
+/// assert_eq!(1234, *pid);
+/// assert_eq!("1234", pid.to_string());
+/// ```
+///
+/// [RawPid]: ./type.RawPid.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate", transparent)
+)]
+pub struct Pid(RawPid);
+
+impl Pid {
+    /// Process identifier of the `init` process, which is the ancestor of all other processes.
+    #[cfg(unix)]
+    pub const INIT: Pid = Pid(1);
+
+    /// Creates a new `Pid` from the raw process identifier.
+    pub const fn new(raw: RawPid) -> Pid {
+        Pid(raw)
+    }
+
+    /// Returns the raw process identifier.
+    pub const fn as_raw(self) -> RawPid {
+        self.0
+    }
+}
+
+impl From<RawPid> for Pid {
+    fn from(raw: RawPid) -> Pid {
+        Pid(raw)
+    }
+}
+
+impl From<Pid> for RawPid {
+    fn from(pid: Pid) -> RawPid {
+        pid.0
+    }
+}
+
+impl Deref for Pid {
+    type Target = RawPid;
+
+    fn deref(&self) -> &RawPid {
+        &self.0
+    }
+}
+
+impl PartialEq<RawPid> for Pid {
+    fn eq(&self, other: &RawPid) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Pid> for RawPid {
+    fn eq(&self, other: &Pid) -> bool {
+        *self == other.0
+    }
+}
+
+impl fmt::Display for Pid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for Pid {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Pid, ParseIntError> {
+        s.parse().map(Pid)
+    }
+}
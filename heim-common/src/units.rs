@@ -1,10 +1,68 @@
 //! Measurement units used in API.
 //!
 //! Check out the [`uom`](https://docs.rs/uom/) crate docs of how to use them.
+//!
+//! ## Serialization
+//!
+//! With the `serde` feature enabled, units are serialized as plain numbers
+//! in the base unit of the measured quantity:
+//!
+//!  * [`Information`] -- bytes
+//!  * [`Time`] -- seconds
+//!  * [`Frequency`] -- hertz
+//!  * [`Ratio`] -- ratio, where `1.0` stands for 100%
+//!  * [`ThermodynamicTemperature`] -- kelvins
+//!  * [`Energy`] -- joules
+//!  * [`AngularVelocity`] -- radians per second
+//!  * [`InformationRate`] -- bytes per second
+//!
+//! Deserialization expects numbers in the same units.
+//!
+//! ## Formatting
+//!
+//! With the `fmt` feature enabled, [`fmt`] module provides helpers
+//! to format information amounts and times in a human-friendly way.
+//!
+//! [`fmt`]: ./fmt/index.html
 
-pub use uom::si::f32::{Ratio, ThermodynamicTemperature};
+pub use uom::si::f32::{AngularVelocity, Energy, Ratio, ThermodynamicTemperature};
 pub use uom::si::f64::Time;
-pub use uom::si::u64::{Frequency, Information};
+pub use uom::si::u64::{Frequency, Information, InformationRate};
 pub use uom::si::{
-    frequency, information, information_rate, ratio, thermodynamic_temperature, time,
+    angular_velocity, energy, frequency, information, information_rate, ratio,
+    thermodynamic_temperature, time,
 };
+
+#[cfg(feature = "fmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fmt")))]
+pub mod fmt;
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::{information, information_rate, thermodynamic_temperature, time};
+    use super::{Information, InformationRate, ThermodynamicTemperature, Time};
+
+    #[test]
+    fn test_serialize_base_units() {
+        let value = Information::new::<information::kibibyte>(1);
+        assert_eq!("1024", serde_json::to_string(&value).unwrap());
+
+        let value = Time::new::<time::millisecond>(1500.0);
+        assert_eq!("1.5", serde_json::to_string(&value).unwrap());
+
+        let value = ThermodynamicTemperature::new::<thermodynamic_temperature::kelvin>(300.0);
+        assert_eq!("300.0", serde_json::to_string(&value).unwrap());
+
+        let value = InformationRate::new::<information_rate::megabit_per_second>(8);
+        assert_eq!("1000000", serde_json::to_string(&value).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_base_units() {
+        let value: Information = serde_json::from_str("2048").unwrap();
+        assert_eq!(value, Information::new::<information::kibibyte>(2));
+
+        let value: Time = serde_json::from_str("60.0").unwrap();
+        assert_eq!(value, Time::new::<time::minute>(1.0));
+    }
+}
@@ -0,0 +1,123 @@
+//! Human-friendly formatting of the measurement units.
+//!
+//! Available with the `fmt` feature only.
+
+use super::{information, time, Information, Time};
+
+const IEC_PREFIXES: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Formats the information amount with the IEC binary prefixes.
+///
+/// Amounts less than one kibibyte are shown in bytes,
+/// others are rounded to the one decimal place.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::units::{information, Information};
+/// # use heim_common::units::fmt::format_bytes;
+/// assert_eq!("0 B", format_bytes(Information::new::<information::byte>(0)));
+/// assert_eq!("1.5 GiB", format_bytes(Information::new::<information::mebibyte>(1536)));
+/// ```
+pub fn format_bytes(info: Information) -> String {
+    let bytes = info.get::<information::byte>();
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64;
+    let mut prefix = IEC_PREFIXES[0];
+    for next in IEC_PREFIXES.iter() {
+        value /= 1024.0;
+        prefix = next;
+        // Values which would be rounded up to `1024.0` are shown with the next prefix
+        if value < 1023.95 {
+            break;
+        }
+    }
+
+    format!("{:.1} {}", value, prefix)
+}
+
+/// Formats the time as a breakdown into days, hours, minutes and seconds.
+///
+/// Only the two most significant non-zero components are shown
+/// (ex. `3h 12m` or `1m 5s`); times less than one second are shown in milliseconds.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::units::{time, Time};
+/// # use heim_common::units::fmt::format_duration;
+/// assert_eq!("0s", format_duration(Time::new::<time::second>(0.0)));
+/// assert_eq!("250ms", format_duration(Time::new::<time::millisecond>(250.0)));
+/// assert_eq!("3h 12m", format_duration(Time::new::<time::second>(11_545.0)));
+/// ```
+pub fn format_duration(t: Time) -> String {
+    let seconds = t.get::<time::second>();
+    if seconds < 0.0 {
+        return format!("-{}", format_duration(-t));
+    }
+    if seconds == 0.0 {
+        return "0s".to_string();
+    }
+    let millis = t.get::<time::millisecond>().round();
+    if millis < 1_000.0 {
+        return format!("{}ms", millis);
+    }
+
+    let seconds = (millis / 1_000.0) as u64;
+    let components = [
+        (seconds / 86_400, "d"),
+        (seconds % 86_400 / 3_600, "h"),
+        (seconds % 3_600 / 60, "m"),
+        (seconds % 60, "s"),
+    ];
+
+    components
+        .iter()
+        .skip_while(|(value, _)| *value == 0)
+        .take(2)
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, suffix)| format!("{}{}", value, suffix))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{information, time, Information, Time};
+    use super::{format_bytes, format_duration};
+
+    #[test]
+    fn test_format_bytes() {
+        let bytes = |value| format_bytes(Information::new::<information::byte>(value));
+
+        assert_eq!("0 B", bytes(0));
+        assert_eq!("1023 B", bytes(1023));
+        assert_eq!("1.0 KiB", bytes(1024));
+        assert_eq!("1023.9 KiB", bytes(1_048_524));
+        assert_eq!("1.0 MiB", bytes(1_048_525));
+        assert_eq!("1.0 MiB", bytes(1_048_575));
+        assert_eq!("1.5 MiB", bytes(1024 * 1536));
+        assert_eq!("1.0 TiB", bytes(1 << 40));
+        assert_eq!("16.0 EiB", bytes(u64::MAX));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        let seconds = |value| format_duration(Time::new::<time::second>(value));
+
+        assert_eq!("0s", seconds(0.0));
+        assert_eq!("1ms", seconds(0.0012));
+        assert_eq!("999ms", seconds(0.999));
+        assert_eq!("1s", seconds(0.9999));
+        assert_eq!("1s", seconds(1.5));
+        assert_eq!("1m 5s", seconds(65.0));
+        assert_eq!("1h", seconds(3_600.0));
+        assert_eq!("1h", seconds(3_601.0));
+        assert_eq!("1h 1m", seconds(3_661.0));
+        assert_eq!("2d 5h", seconds(2.0 * 86_400.0 + 5.0 * 3_600.0 + 59.0));
+        assert_eq!("-1m 5s", seconds(-65.0));
+    }
+}
@@ -0,0 +1,29 @@
+use heim_common::{Pid, RawPid};
+
+#[test]
+fn test_pid_parse() {
+    assert_eq!(Pid::new(1234), "1234".parse::<Pid>().unwrap());
+    assert!("".parse::<Pid>().is_err());
+    assert!("pid".parse::<Pid>().is_err());
+}
+
+#[test]
+fn test_pid_display() {
+    assert_eq!("1234", Pid::new(1234).to_string());
+}
+
+#[test]
+fn test_pid_raw_conversion() {
+    let pid = Pid::from(42);
+
+    assert_eq!(42, RawPid::from(pid));
+    assert_eq!(42, pid.as_raw());
+    assert_eq!(42, *pid);
+    assert_eq!(pid, 42);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_pid_init() {
+    assert_eq!(1, *Pid::INIT);
+}
@@ -1,10 +1,14 @@
 use std::error;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
 
 use static_assertions::assert_impl_all;
 
-use heim_common::Error;
+use heim_common::{Error, Pid};
 
 #[test]
 fn test_public_api_contract() {
     assert_impl_all!(Error: Send, Sync, error::Error);
+    assert_impl_all!(Pid: Send, Sync, Copy, Hash, Ord, fmt::Display, FromStr);
 }
@@ -1,7 +1,9 @@
 use std::fmt;
+use std::time::Duration;
 
 use heim_common::prelude::*;
 use heim_common::units::Frequency;
+use heim_runtime as rt;
 
 use crate::sys;
 
@@ -54,11 +56,73 @@ impl fmt::Debug for CpuFrequency {
 ///
 /// ## Compatibility
 ///
-/// Per-CPU frequencies retrieval is available for Linux,
-/// see Linux-specific [frequencies] function.
+/// For Linux it is an average of all CPUs frequencies,
+/// for Windows it is the frequency of the first CPU.
+///
+/// See [per_cpu_frequency] for per-CPU frequencies.
 ///
 /// [CpuFrequency]: ./struct.CpuFrequency.html
-/// [frequencies]: ./os/linux/fn.frequencies.html
+/// [per_cpu_frequency]: ./fn.per_cpu_frequency.html
 pub async fn frequency() -> Result<CpuFrequency> {
     sys::frequency().await.map(Into::into)
 }
+
+/// Returns a stream over the [CpuFrequency] for each logical CPU.
+///
+/// Stream is ordered by the logical CPU index.
+///
+/// ## Compatibility
+///
+/// macOS does not provide per-CPU frequencies,
+/// stream yields one element with the system-wide frequency instead.
+///
+/// For Linux CPUs without the frequency scaling information are not included into the stream.
+///
+/// [CpuFrequency]: ./struct.CpuFrequency.html
+pub async fn per_cpu_frequency() -> Result<impl Stream<Item = Result<CpuFrequency>>> {
+    let inner = sys::per_cpu_frequency().await?;
+
+    Ok(inner.map_ok(Into::into))
+}
+
+/// Returns a stream which samples the [CpuFrequency] every `interval`.
+///
+/// First sample is loaded right away, each next one is made after the `interval` delay
+/// with help of the timer from the selected runtime.
+/// Stream is infinite and sampling stops once it is dropped.
+///
+/// ## Compatibility
+///
+/// Same as for [frequency], only Linux reports the real-time
+/// (`scaling_cur_freq`) value, so the values yielded on other platforms are not changing.
+///
+/// [CpuFrequency]: ./struct.CpuFrequency.html
+/// [frequency]: ./fn.frequency.html
+pub fn frequency_updates(interval: Duration) -> impl Stream<Item = Result<CpuFrequency>> {
+    ticks(interval).then(|_| frequency())
+}
+
+/// Returns a stream which samples the [CpuFrequency] for each logical CPU every `interval`.
+///
+/// Each stream element contains frequencies for all logical CPUs,
+/// ordered the same way as the [per_cpu_frequency] stream is.
+///
+/// See [frequency_updates] for the sampling details.
+///
+/// [CpuFrequency]: ./struct.CpuFrequency.html
+/// [per_cpu_frequency]: ./fn.per_cpu_frequency.html
+/// [frequency_updates]: ./fn.frequency_updates.html
+pub fn per_cpu_frequency_updates(
+    interval: Duration,
+) -> impl Stream<Item = Result<Vec<CpuFrequency>>> {
+    ticks(interval).then(|_| async {
+        let frequencies = per_cpu_frequency().await?;
+
+        frequencies.try_collect().await
+    })
+}
+
+/// Yields immediately and then once per `interval`.
+fn ticks(interval: Duration) -> impl Stream<Item = ()> {
+    stream::once(future::ready(())).chain(rt::time::interval(interval))
+}
@@ -2,8 +2,10 @@
 //!
 //! ## Platform-specific functions
 //!
-//! * Average system load fetching is available for *nix systems: [`os::unix::loadavg`]
+//! * Average system load fetching is available for *nix systems: [`loadavg`]
+//!   and [`os::unix::loadavg`]
 //!
+//! [`loadavg`]: ./fn.loadavg.html
 //! [`os::unix::loadavg`]: ./os/unix/fn.loadavg.html
 
 #![doc(html_root_url = "https://docs.rs/heim-cpu/0.1.0-rc.1")]
@@ -35,12 +37,16 @@ mod sys;
 
 mod count;
 mod freq;
+mod loadavg;
+mod percent;
 mod stats;
 mod times;
 mod usage;
 
 pub use self::count::*;
 pub use self::freq::*;
+pub use self::loadavg::*;
+pub use self::percent::*;
 pub use self::stats::*;
 pub use self::times::*;
 pub use self::usage::*;
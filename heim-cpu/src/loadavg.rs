@@ -0,0 +1,15 @@
+use heim_common::prelude::*;
+
+use crate::sys;
+
+/// Returns the average system load over the last 1, 5 and 15 minutes.
+///
+/// The load represents the processes which are in a runnable state,
+/// either using the CPU or waiting to use the CPU (e.g. waiting for disk I/O).
+///
+/// ## Compatibility
+///
+/// Windows has no native load average, an error is returned for this platform.
+pub async fn loadavg() -> Result<(f64, f64, f64)> {
+    sys::loadavg().await
+}
@@ -12,7 +12,15 @@ use crate::sys;
 pub async fn loadavg() -> Result<(Ratio, Ratio, Ratio)> {
     cfg_if::cfg_if! {
         if #[cfg(unix)] {
-            sys::loadavg().await
+            use heim_common::units::ratio::ratio;
+
+            let (one, five, fifteen) = sys::loadavg().await?;
+
+            Ok((
+                Ratio::new::<ratio>(one as f32),
+                Ratio::new::<ratio>(five as f32),
+                Ratio::new::<ratio>(fifteen as f32),
+            ))
         } else {
             unimplemented!("For documentation rendering")
         }
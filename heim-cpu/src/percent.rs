@@ -0,0 +1,258 @@
+use std::time::Duration;
+
+use heim_common::prelude::*;
+use heim_common::units::{time, Time};
+
+use crate::{per_cpu_times, times, CpuTime};
+
+/// Suggested interval between two CPU times measurements used for the utilization calculation.
+///
+/// Shorter intervals are making the result less precise, as the CPU times
+/// are accounted by the kernel in ticks (usually of 10 milliseconds length),
+/// while the longer ones are hiding the utilization spikes.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Stateful sampler of the system CPU utilization.
+///
+/// Collector remembers the CPU times fetched by the previous call
+/// and returns the utilization percentage for the time passed since then.
+/// Stateless [times] and [per_cpu_times] functions are still available
+/// in case if the custom calculation is needed.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::prelude::*;
+/// # use heim_cpu::CpuPercentCollector;
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> Result<()> {
+/// let mut collector = CpuPercentCollector::new();
+/// // Nothing to compare with yet
+/// assert_eq!(0.0, collector.percent().await?);
+///
+/// // Or any other async timer at your choice
+/// futures_timer::Delay::new(heim_cpu::DEFAULT_INTERVAL).await;
+/// println!("CPU usage: {} %", collector.percent().await?);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [times]: ./fn.times.html
+/// [per_cpu_times]: ./fn.per_cpu_times.html
+#[derive(Debug, Default)]
+pub struct CpuPercentCollector {
+    last: Option<CpuTime>,
+    last_per_cpu: Option<Vec<CpuTime>>,
+}
+
+impl CpuPercentCollector {
+    /// Creates a new collector.
+    pub fn new() -> CpuPercentCollector {
+        CpuPercentCollector::default()
+    }
+
+    /// Returns the system-wide CPU utilization as a percentage
+    /// since the previous call of this method.
+    ///
+    /// First call returns `0.0`, as there is nothing to compare with yet.
+    ///
+    /// Same as `psutil` does, I/O waiting time is considered as an idle time.
+    pub async fn percent(&mut self) -> Result<f32> {
+        let current = times().await?;
+        let percent = match self.last.replace(current.clone()) {
+            Some(previous) => busy_percent(current - previous),
+            None => 0.0,
+        };
+
+        Ok(percent)
+    }
+
+    /// Returns the utilization of each logical CPU as a percentage
+    /// since the previous call of this method.
+    ///
+    /// Returned values are ordered in the same way as [per_cpu_times] stream is.
+    /// First call returns zeros for all CPUs, as there is nothing to compare with yet;
+    /// same applies if amount of CPUs had changed since the previous call.
+    ///
+    /// [per_cpu_times]: ./fn.per_cpu_times.html
+    pub async fn per_cpu_percent(&mut self) -> Result<Vec<f32>> {
+        let current = per_cpu_times().await?.try_collect::<Vec<_>>().await?;
+        let percents = match self.last_per_cpu.replace(current.clone()) {
+            Some(previous) if previous.len() == current.len() => current
+                .into_iter()
+                .zip(previous)
+                .map(|(current, previous)| busy_percent(current - previous))
+                .collect(),
+            _ => vec![0.0; current.len()],
+        };
+
+        Ok(percents)
+    }
+}
+
+/// Percentage of the CPU time interval spent in each CPU state.
+///
+/// Returned by the [cpu_times_percent] function.
+///
+/// [cpu_times_percent]: ./fn.cpu_times_percent.html
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CpuTimesPercent {
+    user: f32,
+    nice: f32,
+    system: f32,
+    idle: f32,
+    io_wait: f32,
+    irq: f32,
+    soft_irq: f32,
+    steal: f32,
+}
+
+impl CpuTimesPercent {
+    /// Returns percentage of time spent in user mode.
+    pub fn user(&self) -> f32 {
+        self.user
+    }
+
+    /// Returns percentage of time spent by niced processes in user mode.
+    pub fn nice(&self) -> f32 {
+        self.nice
+    }
+
+    /// Returns percentage of time spent in kernel mode.
+    pub fn system(&self) -> f32 {
+        self.system
+    }
+
+    /// Returns percentage of time spent doing nothing.
+    pub fn idle(&self) -> f32 {
+        self.idle
+    }
+
+    /// Returns percentage of time spent waiting for I/O to complete.
+    pub fn io_wait(&self) -> f32 {
+        self.io_wait
+    }
+
+    /// Returns percentage of time spent for servicing hardware interrupts.
+    pub fn irq(&self) -> f32 {
+        self.irq
+    }
+
+    /// Returns percentage of time spent for servicing software interrupts.
+    pub fn soft_irq(&self) -> f32 {
+        self.soft_irq
+    }
+
+    /// Returns percentage of time spent by other operating systems
+    /// running in a virtualized environment.
+    pub fn steal(&self) -> f32 {
+        self.steal
+    }
+}
+
+/// Breaks the CPU time interval between `earlier` and `later` measurements
+/// into the percentages of time spent in each CPU state.
+///
+/// Percentages are summing up to `100.0` (give or take the float rounding),
+/// except the case when both measurements are equal and the interval length is zero,
+/// all values are zeros then.
+///
+/// ## Compatibility
+///
+/// Guest time is included into the user time and it is not provided separately.
+///
+/// For Windows interrupts time is a part of the system time,
+/// so the `irq` and `soft_irq` values are already included into the `system` one.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::prelude::*;
+/// # use heim_cpu::{cpu_times_percent, times};
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> Result<()> {
+/// let earlier = times().await?;
+/// // Some time later
+/// let later = times().await?;
+///
+/// let percent = cpu_times_percent(&earlier, &later);
+/// println!("CPU is idle for {} % of time", percent.idle());
+/// # Ok(())
+/// # }
+/// ```
+pub fn cpu_times_percent(earlier: &CpuTime, later: &CpuTime) -> CpuTimesPercent {
+    let delta = later.clone() - earlier.clone();
+    let total = interval_total(&delta).get::<time::second>();
+    if total <= 0.0 {
+        return CpuTimesPercent::default();
+    }
+
+    let percent = |value: Time| (value.get::<time::second>() / total * 100.0) as f32;
+    CpuTimesPercent {
+        user: percent(delta.user()),
+        nice: percent(delta.nice()),
+        system: percent(delta.system()),
+        idle: percent(delta.idle()),
+        io_wait: percent(delta.io_wait()),
+        irq: percent(delta.irq()),
+        soft_irq: percent(delta.soft_irq()),
+        steal: percent(delta.steal()),
+    }
+}
+
+/// Calculates total length of the CPU times delta.
+fn interval_total(delta: &CpuTime) -> Time {
+    delta.busy() + delta.idle() + delta.io_wait()
+}
+
+/// Calculates busy time percentage of the CPU times delta.
+fn busy_percent(delta: CpuTime) -> f32 {
+    let total = interval_total(&delta).get::<time::second>();
+    if total <= 0.0 {
+        return 0.0;
+    }
+
+    // Busy time is a part of the total, so the result is always within `0..=100`
+    (delta.busy().get::<time::second>() / total * 100.0) as f32
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{cpu_times_percent, CpuTimesPercent};
+    use crate::{sys, CpuTime};
+
+    fn cpu_time(line: &str) -> CpuTime {
+        sys::CpuTime::from_str(line).unwrap().into()
+    }
+
+    #[test]
+    fn test_cpu_times_percent() {
+        let earlier = cpu_time("cpu 100 0 100 100 0 0 0 0 0 0");
+        let later = cpu_time("cpu 150 10 120 200 10 5 5 0 20 0");
+
+        // Total is 200 ticks, guest time is included into the user one
+        let percent = cpu_times_percent(&earlier, &later);
+        assert_eq!(25.0, percent.user());
+        assert_eq!(5.0, percent.nice());
+        assert_eq!(10.0, percent.system());
+        assert_eq!(50.0, percent.idle());
+        assert_eq!(5.0, percent.io_wait());
+        assert_eq!(2.5, percent.irq());
+        assert_eq!(2.5, percent.soft_irq());
+        assert_eq!(0.0, percent.steal());
+    }
+
+    #[test]
+    fn test_cpu_times_percent_zero_interval() {
+        let times = cpu_time("cpu 100 0 100 100 0 0 0 0 0 0");
+
+        assert_eq!(
+            CpuTimesPercent::default(),
+            cpu_times_percent(&times, &times)
+        );
+    }
+}
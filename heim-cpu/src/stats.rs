@@ -11,6 +11,13 @@ pub struct CpuStats(sys::CpuStats);
 
 wrap!(CpuStats, sys::CpuStats);
 
+heim_common::serde!(CpuStats {
+    ctx_switches,
+    interrupts,
+    soft_interrupts,
+    syscalls
+});
+
 impl CpuStats {
     /// Returns number of context switches (voluntary + involuntary) since system boot.
     pub fn ctx_switches(&self) -> u64 {
@@ -21,6 +28,27 @@ impl CpuStats {
     pub fn interrupts(&self) -> u64 {
         self.as_ref().interrupts()
     }
+
+    /// Returns number of software interrupts since system boot.
+    ///
+    /// ## Compatibility
+    ///
+    /// Windows does not provide this value and `0` is returned,
+    /// see [`os::windows::CpuStatsExt::dpc`] for the similar metric.
+    ///
+    /// [`os::windows::CpuStatsExt::dpc`]: ./os/windows/trait.CpuStatsExt.html#tymethod.dpc
+    pub fn soft_interrupts(&self) -> u64 {
+        self.as_ref().soft_interrupts()
+    }
+
+    /// Returns number of system calls since system boot.
+    ///
+    /// ## Compatibility
+    ///
+    /// Linux does not provide this value and `0` is returned.
+    pub fn syscalls(&self) -> u64 {
+        self.as_ref().syscalls()
+    }
 }
 
 impl fmt::Debug for CpuStats {
@@ -28,6 +56,8 @@ impl fmt::Debug for CpuStats {
         f.debug_struct("CpuStats")
             .field("ctx_switches", &self.ctx_switches())
             .field("interrupts", &self.interrupts())
+            .field("soft_interrupts", &self.soft_interrupts())
+            .field("syscalls", &self.syscalls())
             .finish()
     }
 }
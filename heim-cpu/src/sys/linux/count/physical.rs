@@ -1,24 +1,25 @@
 use std::collections::HashSet;
 use std::fs;
 use std::io::{self, BufRead};
-use std::str;
+use std::path::Path;
 
 use heim_common::prelude::*;
 use heim_runtime as rt;
 
 async fn topology() -> Result<u64> {
     rt::spawn_blocking(|| {
-        let path = rt::linux::sysfs_root().join("devices/system/cpu/cpu[0-9]/topology/core_id");
+        let path = rt::linux::sysfs_root().join("devices/system/cpu/cpu[0-9]*/topology");
         let entries =
             glob::glob(path.display().to_string().as_str()).expect("Invalid glob pattern");
-        let mut acc = HashSet::<u64>::new();
+        // Core ids are unique only in the scope of the physical package (socket)
+        let mut acc = HashSet::<(u64, u64)>::new();
 
         for entry in entries {
             let entry = entry.map_err(|e| e.into_error())?;
-            let contents = fs::read_to_string(entry)?;
+            let package_id = read_id(&entry.join("physical_package_id"))?;
+            let core_id = read_id(&entry.join("core_id"))?;
 
-            let core_id = contents.trim().parse()?;
-            let _ = acc.insert(core_id);
+            let _ = acc.insert((package_id, core_id));
         }
 
         if !acc.is_empty() {
@@ -32,64 +33,54 @@ async fn topology() -> Result<u64> {
     .await
 }
 
-#[derive(Default)]
-struct Collector {
-    physical_id: Option<u64>,
-    group: HashSet<(u64, u64)>,
+fn read_id(path: &Path) -> Result<u64> {
+    let contents = fs::read_to_string(path).map_err(|e| Error::from(e).with_file(path))?;
+
+    contents.trim().parse().map_err(Into::into)
 }
 
 fn parse_line(line: &str) -> Result<u64> {
     line.split(':')
-        .nth(2)
+        .nth(1)
         .map(|value| value.trim())
         .ok_or_else(|| Error::from(io::Error::from(io::ErrorKind::InvalidData)))
         .and_then(|value| value.parse::<u64>().map_err(Error::from))
 }
 
-async fn cpu_info() -> Result<Option<u64>> {
-    rt::spawn_blocking(|| {
-        let mut acc = Collector::default();
-        let f = fs::File::open(rt::linux::procfs_root().join("cpuinfo"))?;
-        let reader = io::BufReader::new(f);
-
-        let lines = reader.lines();
-        for line in lines {
-            match &line? {
-                l if l.starts_with("physical id") => {
-                    let core_id = parse_line(l.as_str())?;
-                    if acc.physical_id.is_none() {
-                        acc.physical_id = Some(core_id)
-                    } else {
-                        // TODO: In general it seems better to return an error
-                        panic!(
-                            "Missed the core id value in the {:?}/cpuinfo, implementation bug",
-                            rt::linux::procfs_root()
-                        );
-                    }
-                }
-                l if l.starts_with("core id") => {
-                    let core_id = parse_line(l.as_str())?;
-                    if acc.physical_id.is_some() {
-                        let physical_id =
-                            acc.physical_id.take().expect("Not expected to be happen");
-                        let _ = acc.group.insert((physical_id, core_id));
-                    } else {
-                        // TODO: In general it seems better to return an error
-                        panic!(
-                            "Missed the physical id value in the {:?}/cpuinfo!",
-                            rt::linux::procfs_root()
-                        );
-                    }
+/// Counts unique `(physical id, core id)` pairs from the `/proc/cpuinfo` contents.
+fn parse_cpuinfo<T: BufRead>(reader: T) -> Result<Option<u64>> {
+    let mut physical_id = None;
+    let mut group = HashSet::<(u64, u64)>::new();
+
+    for line in reader.lines() {
+        match &line? {
+            l if l.starts_with("physical id") => {
+                physical_id = Some(parse_line(l.as_str())?);
+            }
+            l if l.starts_with("core id") => {
+                let core_id = parse_line(l.as_str())?;
+                // Each processor block is expected to have the "physical id" line
+                // before the "core id" one, blocks without it are ignored
+                if let Some(physical_id) = physical_id.take() {
+                    let _ = group.insert((physical_id, core_id));
                 }
-                _ => continue,
             }
+            _ => continue,
         }
+    }
 
-        if !acc.group.is_empty() {
-            Ok(Some(acc.group.len() as u64))
-        } else {
-            Ok(None)
-        }
+    if !group.is_empty() {
+        Ok(Some(group.len() as u64))
+    } else {
+        Ok(None)
+    }
+}
+
+async fn cpu_info() -> Result<Option<u64>> {
+    rt::spawn_blocking(|| {
+        let f = fs::File::open(rt::linux::procfs_root().join("cpuinfo"))?;
+
+        parse_cpuinfo(io::BufReader::new(f))
     })
     .await
 }
@@ -100,3 +91,51 @@ pub async fn physical_count() -> Result<Option<u64>> {
         Err(..) => cpu_info().await,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_cpuinfo, parse_line};
+
+    const CPUINFO: &str = "processor\t: 0
+physical id\t: 0
+siblings\t: 2
+core id\t\t: 0
+cpu cores\t: 1
+
+processor\t: 1
+physical id\t: 0
+siblings\t: 2
+core id\t\t: 0
+cpu cores\t: 1
+
+processor\t: 2
+physical id\t: 1
+siblings\t: 2
+core id\t\t: 0
+cpu cores\t: 1
+
+processor\t: 3
+physical id\t: 1
+siblings\t: 2
+core id\t\t: 0
+cpu cores\t: 1
+";
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(3, parse_line("core id\t\t: 3").unwrap());
+        assert!(parse_line("core id").is_err());
+    }
+
+    #[test]
+    fn test_parse_cpuinfo() {
+        assert_eq!(Some(2), parse_cpuinfo(CPUINFO.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn test_parse_cpuinfo_without_topology() {
+        let cpuinfo = "processor\t: 0\nmodel name\t: ARMv7\n";
+
+        assert_eq!(None, parse_cpuinfo(cpuinfo.as_bytes()).unwrap());
+    }
+}
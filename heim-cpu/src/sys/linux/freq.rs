@@ -50,7 +50,17 @@ impl ops::Add<CpuFrequency> for CpuFrequency {
     }
 }
 
-/// Internal blocking iterator over CPU frequencies.
+/// Extracts logical CPU index from the `/sys/devices/system/cpu/cpu{N}/cpufreq/` path.
+fn cpu_index(path: &Path) -> Option<u64> {
+    path.parent()?
+        .file_name()?
+        .to_str()?
+        .strip_prefix("cpu")?
+        .parse()
+        .ok()
+}
+
+/// Internal blocking iterator over CPU frequencies, ordered by the logical CPU index.
 fn _frequencies() -> impl Iterator<Item = Result<CpuFrequency>> {
     // TODO: psutil looks into `/sys/devices/system/cpu/cpufreq/policy*` at first
     // But at my machine with Linux 5.0 `./cpu/cpu*/cpufreq` are symlinks to the `policy*`,
@@ -58,12 +68,17 @@ fn _frequencies() -> impl Iterator<Item = Result<CpuFrequency>> {
     // later with the thoughts and patches
 
     // TODO: https://github.com/giampaolo/psutil/issues/1269
-    let path = rt::linux::sysfs_root().join("devices/system/cpu/cpu[0-9]/cpufreq/");
+    let path = rt::linux::sysfs_root().join("devices/system/cpu/cpu[0-9]*/cpufreq/");
 
     let entries = glob::glob(path.display().to_string().as_str()).expect("Incorrect glob pattern");
+    let mut paths = entries
+        .map(|try_path| try_path.map_err(|e| e.into_error()))
+        .collect::<Vec<_>>();
+    // `glob` yields paths in the lexicographical order, so `cpu10` goes before `cpu2`
+    paths.sort_by_key(|try_path| try_path.as_ref().ok().and_then(|path| cpu_index(path)));
 
-    entries.map(|try_path| {
-        let path = try_path.map_err(|e| e.into_error())?;
+    paths.into_iter().map(|try_path| {
+        let path = try_path?;
 
         let current = current_freq(&path)?;
         let max = max_freq(&path);
@@ -77,6 +92,12 @@ pub fn frequencies() -> impl Stream<Item = Result<CpuFrequency>> {
     smol::stream::iter(_frequencies())
 }
 
+pub async fn per_cpu_frequency() -> Result<impl Stream<Item = Result<CpuFrequency>>> {
+    let frequencies = rt::spawn_blocking(|| _frequencies().collect::<Vec<_>>()).await;
+
+    Ok(smol::stream::iter(frequencies))
+}
+
 pub async fn frequency() -> Result<CpuFrequency> {
     rt::spawn_blocking(|| {
         let mut acc = CpuFrequency::default();
@@ -111,32 +132,40 @@ fn read_freq(path: PathBuf) -> Result<Frequency> {
 }
 
 fn current_freq(path: &Path) -> Result<Frequency> {
-    read_freq(path.join("scaling_cur_freq"))
-
-    // TODO: Use `try_join` here instead of the code above
-    //    let one = read_freq(path.join("scaling_cur_freq"))
-    //        .into_future().fuse();
-    //    let two = read_freq(path.join("cpuinfo_cur_freq"))
-    //        .into_future().fuse();
-    //
-    //    let result = futures::select! {
-    //        Ok(freq) = one => Ok(freq),
-    //        Ok(freq) = two => Ok(freq),
-    //    };
-    //
-    //    future::ready(result)
+    read_freq(path.join("scaling_cur_freq")).or_else(|_| read_freq(path.join("cpuinfo_cur_freq")))
 }
 
 fn max_freq(path: &Path) -> Option<Frequency> {
-    let value = read_freq(path.join("scaling_max_freq"));
+    let value = read_freq(path.join("scaling_max_freq"))
+        .or_else(|_| read_freq(path.join("cpuinfo_max_freq")));
 
     // Don't care about errors propagation at this point
     value.ok()
 }
 
 fn min_freq(path: &Path) -> Option<Frequency> {
-    let value = read_freq(path.join("scaling_min_freq"));
+    let value = read_freq(path.join("scaling_min_freq"))
+        .or_else(|_| read_freq(path.join("cpuinfo_min_freq")));
 
     // Don't care about errors propagation at this point
     value.ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::cpu_index;
+
+    #[test]
+    fn test_cpu_index() {
+        assert_eq!(
+            Some(12),
+            cpu_index(Path::new("/sys/devices/system/cpu/cpu12/cpufreq/"))
+        );
+        assert_eq!(
+            None,
+            cpu_index(Path::new("/sys/devices/system/cpu/cpufreq/"))
+        );
+    }
+}
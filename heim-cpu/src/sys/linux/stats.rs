@@ -20,6 +20,10 @@ impl CpuStats {
     pub fn soft_interrupts(&self) -> u64 {
         self.soft_interrupts
     }
+    pub fn syscalls(&self) -> u64 {
+        // Linux does not count system calls
+        0
+    }
 }
 
 impl FromStr for CpuStats {
@@ -64,3 +68,31 @@ impl FromStr for CpuStats {
 pub async fn stats() -> Result<CpuStats> {
     rt::fs::read_into(rt::linux::procfs_root().join("stat")).await
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::CpuStats;
+
+    #[test]
+    fn test_parse_stat() {
+        const STAT: &str = "cpu  465552918 3813058 111153634 9065060137 5821166 0 4346876 0 0 0
+cpu0 317865 456 71065 3101075 8645 14938 10567 0 0 0
+intr 1317584402 9 0 0 0 0 0 0 0 1 0
+ctxt 2415406134
+btime 1577870126
+processes 6033000
+procs_running 1
+procs_blocked 0
+softirq 588549557 0 164729268 126640 72842960 2066749 0 266948 183447185 0 165070007
+";
+
+        let stats = CpuStats::from_str(STAT).unwrap();
+
+        assert_eq!(2_415_406_134, stats.ctx_switches());
+        assert_eq!(1_317_584_402, stats.interrupts());
+        assert_eq!(588_549_557, stats.soft_interrupts());
+        assert_eq!(0, stats.syscalls());
+    }
+}
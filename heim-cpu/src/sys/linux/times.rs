@@ -1,3 +1,4 @@
+use std::ops;
 use std::str::{self, FromStr};
 
 use heim_common::prelude::*;
@@ -5,6 +6,8 @@ use heim_common::sys::unix::CLOCK_TICKS;
 use heim_common::units::{time, Time};
 use heim_runtime as rt;
 
+use crate::sys::saturating_sub;
+
 #[derive(Debug, Default, Clone)]
 pub struct CpuTime {
     user: Time,
@@ -44,14 +47,39 @@ impl CpuTime {
     pub fn steal(&self) -> Time {
         self.steal
     }
-    pub fn guest(&self) -> Option<Time> {
-        self.guest
+    pub fn guest(&self) -> Time {
+        self.guest.unwrap_or_else(|| Time::new::<time::second>(0.0))
     }
     pub fn guest_nice(&self) -> Option<Time> {
         self.guest_nice
     }
 }
 
+impl ops::Sub for CpuTime {
+    type Output = CpuTime;
+
+    fn sub(self, rhs: CpuTime) -> CpuTime {
+        CpuTime {
+            user: saturating_sub(self.user, rhs.user),
+            nice: saturating_sub(self.nice, rhs.nice),
+            system: saturating_sub(self.system, rhs.system),
+            idle: saturating_sub(self.idle, rhs.idle),
+            io_wait: saturating_sub(self.io_wait, rhs.io_wait),
+            irq: saturating_sub(self.irq, rhs.irq),
+            soft_irq: saturating_sub(self.soft_irq, rhs.soft_irq),
+            steal: saturating_sub(self.steal, rhs.steal),
+            guest: match (self.guest, rhs.guest) {
+                (Some(lhs), Some(rhs)) => Some(saturating_sub(lhs, rhs)),
+                _ => None,
+            },
+            guest_nice: match (self.guest_nice, rhs.guest_nice) {
+                (Some(lhs), Some(rhs)) => Some(saturating_sub(lhs, rhs)),
+                _ => None,
+            },
+        }
+    }
+}
+
 impl FromStr for CpuTime {
     type Err = Error;
 
@@ -88,7 +116,7 @@ impl FromStr for CpuTime {
     }
 }
 
-pub async fn time() -> Result<CpuTime> {
+pub async fn times() -> Result<CpuTime> {
     // cumulative time is always the first line
     let lines =
         rt::fs::read_lines_into::<_, CpuTime, _>(rt::linux::procfs_root().join("stat")).await?;
@@ -102,7 +130,7 @@ pub async fn time() -> Result<CpuTime> {
     }
 }
 
-pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
+pub async fn per_cpu_times() -> Result<impl Stream<Item = Result<CpuTime>>> {
     let lines = rt::fs::read_lines(rt::linux::procfs_root().join("stat")).await?;
 
     let stream = lines.skip(1).filter_map(|try_line| async move {
@@ -120,6 +148,9 @@ pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
 mod tests {
     use std::str::FromStr;
 
+    use heim_common::sys::unix::CLOCK_TICKS;
+    use heim_common::units::{time, Time};
+
     use super::CpuTime;
 
     #[test]
@@ -128,4 +159,40 @@ mod tests {
 
         let _ = CpuTime::from_str(LINE).unwrap();
     }
+
+    #[test]
+    fn test_sub() {
+        let earlier = CpuTime::from_str("cpu 100 20 300 4000 50 6 7 8 9 10").unwrap();
+        let later = CpuTime::from_str("cpu 150 20 330 4100 40 6 7 8 9 10").unwrap();
+
+        let delta = later - earlier;
+        let ticks = *CLOCK_TICKS as f64;
+        let to_ticks = |value: Time| (value.get::<time::second>() * ticks).round();
+        assert_eq!(to_ticks(delta.user()), 50.0);
+        assert_eq!(to_ticks(delta.nice()), 0.0);
+        assert_eq!(to_ticks(delta.system()), 30.0);
+        assert_eq!(to_ticks(delta.idle()), 100.0);
+        // Counter was reset
+        assert_eq!(to_ticks(delta.io_wait()), 0.0);
+        assert_eq!(to_ticks(delta.guest()), 0.0);
+        assert_eq!(delta.guest_nice().map(to_ticks), Some(0.0));
+    }
+
+    #[test]
+    fn test_columns() {
+        let times = CpuTime::from_str("cpu 1 2 3 4 5 6 7 8 9 10").unwrap();
+        let ticks = *CLOCK_TICKS as f64;
+        let to_ticks = |value: Time| (value.get::<time::second>() * ticks).round();
+
+        assert_eq!(to_ticks(times.io_wait()), 5.0);
+        assert_eq!(to_ticks(times.irq()), 6.0);
+        assert_eq!(to_ticks(times.soft_irq()), 7.0);
+        assert_eq!(to_ticks(times.steal()), 8.0);
+        assert_eq!(to_ticks(times.guest()), 9.0);
+
+        // Older kernels have no guest columns
+        let times = CpuTime::from_str("cpu 1 2 3 4 5 6 7 8").unwrap();
+        assert_eq!(to_ticks(times.guest()), 0.0);
+        assert_eq!(times.guest_nice(), None);
+    }
 }
@@ -6,8 +6,8 @@ use super::bindings;
 #[derive(Debug)]
 pub struct CpuFrequency {
     current: Frequency,
-    min: Frequency,
-    max: Frequency,
+    min: Option<Frequency>,
+    max: Option<Frequency>,
 }
 
 impl CpuFrequency {
@@ -16,22 +16,30 @@ impl CpuFrequency {
     }
 
     pub fn min(&self) -> Option<Frequency> {
-        Some(self.min)
+        self.min
     }
 
     pub fn max(&self) -> Option<Frequency> {
-        Some(self.max)
+        self.max
     }
 }
 
 pub async fn frequency() -> Result<CpuFrequency> {
     let current = bindings::cpu_frequency()?;
-    let min = bindings::cpu_frequency_min()?;
-    let max = bindings::cpu_frequency_max()?;
+    // Min and max values are not available for some hardware, Apple M1 for example
+    let min = bindings::cpu_frequency_min().ok();
+    let max = bindings::cpu_frequency_max().ok();
 
     Ok(CpuFrequency {
         current: Frequency::new::<frequency::hertz>(current),
-        min: Frequency::new::<frequency::hertz>(min),
-        max: Frequency::new::<frequency::hertz>(max),
+        min: min.map(Frequency::new::<frequency::hertz>),
+        max: max.map(Frequency::new::<frequency::hertz>),
     })
 }
+
+pub async fn per_cpu_frequency() -> Result<impl Stream<Item = Result<CpuFrequency>>> {
+    // macOS does not provide per-CPU frequencies
+    let frequency = frequency().await?;
+
+    Ok(stream::once(future::ok(frequency)))
+}
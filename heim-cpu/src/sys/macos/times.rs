@@ -1,8 +1,11 @@
+use std::ops;
+
 use heim_common::prelude::*;
 use heim_common::sys::unix::CLOCK_TICKS;
 use heim_common::units::{time, Time};
 
 use super::bindings;
+use crate::sys::saturating_sub;
 
 #[derive(Debug, Clone)]
 pub struct CpuTime {
@@ -28,6 +31,39 @@ impl CpuTime {
     pub fn idle(&self) -> Time {
         self.idle
     }
+
+    pub fn io_wait(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+
+    pub fn irq(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+
+    pub fn soft_irq(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+
+    pub fn steal(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+
+    pub fn guest(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+}
+
+impl ops::Sub for CpuTime {
+    type Output = CpuTime;
+
+    fn sub(self, rhs: CpuTime) -> CpuTime {
+        CpuTime {
+            user: saturating_sub(self.user, rhs.user),
+            nice: saturating_sub(self.nice, rhs.nice),
+            system: saturating_sub(self.system, rhs.system),
+            idle: saturating_sub(self.idle, rhs.idle),
+        }
+    }
 }
 
 impl From<bindings::host_cpu_load_info> for CpuTime {
@@ -56,11 +92,11 @@ impl From<bindings::processor_cpu_load_info> for CpuTime {
     }
 }
 
-pub async fn time() -> Result<CpuTime> {
+pub async fn times() -> Result<CpuTime> {
     bindings::cpu_load_info().map(Into::into)
 }
 
-pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
+pub async fn per_cpu_times() -> Result<impl Stream<Item = Result<CpuTime>>> {
     let processors = bindings::processor_load_info()?;
 
     let stream = stream::iter(processors).map(|proc_info| Ok(CpuTime::from(proc_info)));
@@ -1,3 +1,5 @@
+use heim_common::units::{time, Time};
+
 #[cfg(unix)]
 mod unix;
 #[cfg(unix)]
@@ -20,3 +22,15 @@ cfg_if::cfg_if! {
         compile_error!("Unsupported target OS");
     }
 }
+
+/// Subtracts `rhs` from `lhs`, saturating at zero.
+///
+/// CPU time counters might be reset (ex. after suspend and resume),
+/// which makes the later measurement less than the previous one.
+pub(crate) fn saturating_sub(lhs: Time, rhs: Time) -> Time {
+    if lhs > rhs {
+        lhs - rhs
+    } else {
+        Time::new::<time::second>(0.0)
+    }
+}
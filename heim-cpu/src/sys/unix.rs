@@ -1,16 +1,11 @@
-use heim_common::units::{ratio::ratio, Ratio};
 use heim_common::{Error, Result};
 
-pub async fn loadavg() -> Result<(Ratio, Ratio, Ratio)> {
+pub async fn loadavg() -> Result<(f64, f64, f64)> {
     let mut data: [libc::c_double; 3] = [0.0, 0.0, 0.0];
     let result = unsafe { libc::getloadavg(data.as_mut_ptr(), 3) };
 
     if result == 3 {
-        Ok((
-            Ratio::new::<ratio>(data[0] as f32),
-            Ratio::new::<ratio>(data[1] as f32),
-            Ratio::new::<ratio>(data[2] as f32),
-        ))
+        Ok((data[0], data[1], data[2]))
     } else {
         Err(Error::last_os_error().with_ffi("getloadavg"))
     }
@@ -72,3 +72,9 @@ pub async fn frequency() -> Result<CpuFrequency> {
             Error::from(inner).with_message("No processors were found")
         })
 }
+
+pub async fn per_cpu_frequency() -> Result<impl Stream<Item = Result<CpuFrequency>>> {
+    let processors = get_processors()?;
+
+    Ok(stream::iter(processors).map(|info| Ok(CpuFrequency(info))))
+}
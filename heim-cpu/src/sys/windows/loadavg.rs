@@ -0,0 +1,9 @@
+use std::io;
+
+use heim_common::prelude::*;
+
+pub async fn loadavg() -> Result<(f64, f64, f64)> {
+    let inner = io::Error::from(io::ErrorKind::Other);
+
+    Err(Error::from(inner).with_message("Load average is not supported on Windows"))
+}
@@ -1,5 +1,6 @@
 mod count;
 mod freq;
+mod loadavg;
 mod stats;
 mod times;
 
@@ -8,5 +9,6 @@ mod wrappers;
 
 pub use self::count::*;
 pub use self::freq::*;
+pub use self::loadavg::*;
 pub use self::stats::*;
 pub use self::times::*;
@@ -19,6 +19,11 @@ impl CpuStats {
         self.interrupts
     }
 
+    pub fn soft_interrupts(&self) -> u64 {
+        // Same as `psutil`, there is no such thing for Windows
+        0
+    }
+
     pub fn dpc(&self) -> u64 {
         self.dpc_count
     }
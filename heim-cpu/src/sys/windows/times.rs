@@ -1,16 +1,21 @@
+use std::ops;
+
 use winapi::shared::minwindef;
 use winapi::um::processthreadsapi;
 
 use super::bindings::winternl;
+use crate::sys::saturating_sub;
 use heim_common::prelude::*;
 use heim_common::sys::IntoTime as _;
-use heim_common::units::Time;
+use heim_common::units::{time, Time};
 
 #[derive(Debug, Clone)]
 pub struct CpuTime {
     user: Time,
     system: Time,
     idle: Time,
+    irq: Time,
+    soft_irq: Time,
 }
 
 impl CpuTime {
@@ -25,10 +30,48 @@ impl CpuTime {
     pub fn idle(&self) -> Time {
         self.idle
     }
+
+    pub fn nice(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+
+    pub fn io_wait(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+
+    pub fn irq(&self) -> Time {
+        self.irq
+    }
+
+    pub fn soft_irq(&self) -> Time {
+        self.soft_irq
+    }
+
+    pub fn steal(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+
+    pub fn guest(&self) -> Time {
+        Time::new::<time::second>(0.0)
+    }
+}
+
+impl ops::Sub for CpuTime {
+    type Output = CpuTime;
+
+    fn sub(self, rhs: CpuTime) -> CpuTime {
+        CpuTime {
+            user: saturating_sub(self.user, rhs.user),
+            system: saturating_sub(self.system, rhs.system),
+            idle: saturating_sub(self.idle, rhs.idle),
+            irq: saturating_sub(self.irq, rhs.irq),
+            soft_irq: saturating_sub(self.soft_irq, rhs.soft_irq),
+        }
+    }
 }
 
 // https://docs.microsoft.com/en-us/windows/desktop/api/processthreadsapi/nf-processthreadsapi-getsystemtimes
-pub async fn time() -> Result<CpuTime> {
+pub async fn times() -> Result<CpuTime> {
     let mut user = minwindef::FILETIME::default();
     let mut kernel = minwindef::FILETIME::default();
     let mut idle = minwindef::FILETIME::default();
@@ -44,11 +87,32 @@ pub async fn time() -> Result<CpuTime> {
         // and leaving only busy kernel time
         let system = kernel.into_time() - idle;
 
-        Ok(CpuTime { user, system, idle })
+        // `GetSystemTimes` does not provide the interrupts times,
+        // so they are summed from the per-CPU times. Source: psutil
+        let processors: Vec<winternl::SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION> =
+            winternl::query_system_information()?;
+        let zero = Time::new::<time::second>(0.0);
+        let (irq, soft_irq) =
+            processors
+                .into_iter()
+                .fold((zero, zero), |(irq, soft_irq), proc_info| {
+                    (
+                        irq + proc_info.InterruptTime.into_time(),
+                        soft_irq + proc_info.DpcTime.into_time(),
+                    )
+                });
+
+        Ok(CpuTime {
+            user,
+            system,
+            idle,
+            irq,
+            soft_irq,
+        })
     }
 }
 
-pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
+pub async fn per_cpu_times() -> Result<impl Stream<Item = Result<CpuTime>>> {
     let processors: Vec<winternl::SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION> =
         winternl::query_system_information()?;
 
@@ -56,8 +120,16 @@ pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
         let user = proc_info.UserTime.into_time();
         let idle = proc_info.IdleTime.into_time();
         let system = proc_info.KernelTime.into_time() - idle;
+        let irq = proc_info.InterruptTime.into_time();
+        let soft_irq = proc_info.DpcTime.into_time();
 
-        Ok(CpuTime { user, system, idle })
+        Ok(CpuTime {
+            user,
+            system,
+            idle,
+            irq,
+            soft_irq,
+        })
     });
 
     Ok(stream)
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::ops;
 
 use heim_common::prelude::*;
 use heim_common::units::Time;
@@ -7,6 +8,8 @@ use crate::sys;
 
 /// System CPU time.
 ///
+/// Times which are not provided by the platform are equal to zero.
+///
 /// ## Compatibility
 ///
 /// For Linux additional information can be retrieved with [CpuTimeExt] extension trait.
@@ -17,6 +20,18 @@ pub struct CpuTime(sys::CpuTime);
 
 wrap!(CpuTime, sys::CpuTime);
 
+heim_common::serde!(CpuTime {
+    user,
+    system,
+    idle,
+    nice,
+    io_wait,
+    irq,
+    soft_irq,
+    steal,
+    guest
+});
+
 impl CpuTime {
     /// Returns time spent by normal processes executing in user mode.
     ///
@@ -36,6 +51,140 @@ impl CpuTime {
     pub fn idle(&self) -> Time {
         self.as_ref().idle()
     }
+
+    /// Returns time spent by niced (prioritized) processes executing in user mode.
+    ///
+    /// ## Compatibility
+    ///
+    ///  * on Linux this also includes guest nice time
+    ///  * not available for Windows
+    pub fn nice(&self) -> Time {
+        self.as_ref().nice()
+    }
+
+    /// Returns time spent waiting for I/O to complete.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux only.
+    pub fn io_wait(&self) -> Time {
+        self.as_ref().io_wait()
+    }
+
+    /// Returns time spent for servicing hardware interrupts.
+    ///
+    /// ## Compatibility
+    ///
+    ///  * on Windows this is the `InterruptTime` value
+    ///  * not available for macOS
+    pub fn irq(&self) -> Time {
+        self.as_ref().irq()
+    }
+
+    /// Returns time spent for servicing software interrupts.
+    ///
+    /// ## Compatibility
+    ///
+    ///  * on Windows this is the deferred procedure calls (`DpcTime`) time
+    ///  * not available for macOS
+    pub fn soft_irq(&self) -> Time {
+        self.as_ref().soft_irq()
+    }
+
+    /// Returns time stolen by the hypervisor for other virtual machines
+    /// while this virtual CPU was waiting for the physical one.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux only.
+    pub fn steal(&self) -> Time {
+        self.as_ref().steal()
+    }
+
+    /// Returns time spent running a virtual CPU for guest operating systems.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux 2.6.24+ only, this time is included into the [user] time too.
+    ///
+    /// [user]: #method.user
+    pub fn guest(&self) -> Time {
+        self.as_ref().guest()
+    }
+
+    /// Returns sum of the [user], [system] and [idle] times.
+    ///
+    /// ## Compatibility
+    ///
+    /// Other times (except the guest time, which is included into the [user] time)
+    /// are not included into this sum.
+    ///
+    /// [user]: #method.user
+    /// [system]: #method.system
+    /// [idle]: #method.idle
+    pub fn total(&self) -> Time {
+        self.user() + self.system() + self.idle()
+    }
+
+    /// Returns time spent doing any work, i.e. everything except the [idle] and [I/O wait] times.
+    ///
+    /// It is a sum of the [user], [nice], [system], [irq], [soft_irq] and [steal] times;
+    /// steal time is included, as the virtual CPU was not idle while waiting for the physical one.
+    ///
+    /// ## Compatibility
+    ///
+    /// On Windows [irq] and [soft_irq] times are already a part of the [system] time,
+    /// so they are not added twice.
+    ///
+    /// [idle]: #method.idle
+    /// [I/O wait]: #method.io_wait
+    /// [user]: #method.user
+    /// [nice]: #method.nice
+    /// [system]: #method.system
+    /// [irq]: #method.irq
+    /// [soft_irq]: #method.soft_irq
+    /// [steal]: #method.steal
+    pub fn busy(&self) -> Time {
+        // Guest time is already included into the user time
+        let busy = self.user() + self.nice() + self.system() + self.steal();
+
+        if cfg!(target_os = "windows") {
+            busy
+        } else {
+            busy + self.irq() + self.soft_irq()
+        }
+    }
+}
+
+/// Calculates the difference between two CPU times measurements.
+///
+/// Each time is subtracted independently and saturates at zero,
+/// as the CPU counters might be reset (ex. after suspend and resume).
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::prelude::*;
+/// # use heim_common::units::time;
+/// # use heim_cpu::times;
+/// # fn main() -> Result<()> {
+/// # smol::block_on(async {
+/// let earlier = times().await?;
+/// // Some time later
+/// let later = times().await?;
+///
+/// let interval = (later - earlier).total();
+/// assert!(interval.get::<time::second>() >= 0.0);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+impl ops::Sub for CpuTime {
+    type Output = CpuTime;
+
+    fn sub(self, rhs: CpuTime) -> CpuTime {
+        CpuTime(self.0 - rhs.0)
+    }
 }
 
 impl fmt::Debug for CpuTime {
@@ -44,6 +193,12 @@ impl fmt::Debug for CpuTime {
             .field("user", &self.user())
             .field("system", &self.system())
             .field("idle", &self.idle())
+            .field("nice", &self.nice())
+            .field("io_wait", &self.io_wait())
+            .field("irq", &self.irq())
+            .field("soft_irq", &self.soft_irq())
+            .field("steal", &self.steal())
+            .field("guest", &self.guest())
             .finish()
     }
 }
@@ -51,15 +206,43 @@ impl fmt::Debug for CpuTime {
 /// Returns cumulative value of all [CPU times].
 ///
 /// [CPU times]: struct.CpuTime.html
-pub async fn time() -> Result<CpuTime> {
-    sys::time().await.map(Into::into)
+pub async fn times() -> Result<CpuTime> {
+    sys::times().await.map(Into::into)
 }
 
-/// Returns a stream over the [CPU time] for each CPU core.
+/// Returns a stream over the [CPU time] for each logical CPU.
+///
+/// Stream is ordered by the logical CPU index, first element is the CPU time
+/// for the CPU #0, second one is for the CPU #1 and so on.
+///
+/// ## Compatibility
+///
+/// For Linux offline CPUs are not included into the stream.
 ///
 /// [CPU time]: struct.CpuTime.html
-pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
-    let inner = sys::times().await?;
+pub async fn per_cpu_times() -> Result<impl Stream<Item = Result<CpuTime>>> {
+    let inner = sys::per_cpu_times().await?;
 
     Ok(inner.map_ok(Into::into))
 }
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::units::time;
+
+    use crate::{sys, CpuTime};
+
+    #[test]
+    fn test_busy() {
+        // user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice
+        let cpu_time: CpuTime = sys::CpuTime::from_str("cpu 100 10 50 1000 200 5 3 7 20 0")
+            .unwrap()
+            .into();
+
+        let ticks = cpu_time.user().get::<time::second>() / 100.0;
+        let busy = cpu_time.busy().get::<time::second>() / ticks;
+        assert!((busy - 175.0).abs() < 1e-6, "{}", busy);
+    }
+}
@@ -4,7 +4,7 @@ use std::time::Instant;
 use heim_common::prelude::*;
 use heim_common::units::{ratio, time, Ratio};
 
-use super::{logical_count, time, CpuTime};
+use super::{logical_count, times, CpuTime};
 
 /// System CPU usage measurement.
 ///
@@ -75,7 +75,7 @@ impl ops::Sub<CpuUsage> for CpuUsage {
 /// [`CpuUsage`]: ./struct.CpuUsage.html
 pub async fn usage() -> Result<CpuUsage> {
     let (cpu_time, cpu_count) =
-        future::try_join(time(), logical_count().map_err(Into::into)).await?;
+        future::try_join(times(), logical_count().map_err(Into::into)).await?;
 
     Ok(CpuUsage {
         cpu_count,
@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use heim_common::prelude::*;
+use heim_cpu as cpu;
+
+#[heim_derive::test]
+async fn test_percent() -> Result<()> {
+    let mut collector = cpu::CpuPercentCollector::new();
+
+    assert_eq!(0.0, collector.percent().await?);
+
+    futures_timer::Delay::new(Duration::from_millis(50)).await;
+    let percent = collector.percent().await?;
+    assert!(percent >= 0.0);
+    assert!(percent <= 100.0);
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_per_cpu_percent() -> Result<()> {
+    let mut collector = cpu::CpuPercentCollector::new();
+    let count = cpu::per_cpu_times()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?
+        .len();
+
+    let percents = collector.per_cpu_percent().await?;
+    assert_eq!(count, percents.len());
+    assert!(percents.iter().all(|percent| *percent == 0.0));
+
+    futures_timer::Delay::new(Duration::from_millis(50)).await;
+    let percents = collector.per_cpu_percent().await?;
+    assert_eq!(count, percents.len());
+    for percent in percents {
+        assert!(percent >= 0.0);
+        assert!(percent <= 100.0);
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_cpu_times_percent() -> Result<()> {
+    let earlier = cpu::times().await?;
+    futures_timer::Delay::new(Duration::from_millis(50)).await;
+    let later = cpu::times().await?;
+
+    let percent = cpu::cpu_times_percent(&earlier, &later);
+    let mut sum = percent.user()
+        + percent.nice()
+        + percent.system()
+        + percent.idle()
+        + percent.io_wait()
+        + percent.steal();
+    if cfg!(not(target_os = "windows")) {
+        sum += percent.irq() + percent.soft_irq();
+    }
+    // Interval might be too short for counters to change
+    if sum > 0.0 {
+        assert!((sum - 100.0).abs() < 0.1, "Percentages sum is {}", sum);
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_cpu_times_percent_zero_interval() -> Result<()> {
+    let times = cpu::times().await?;
+
+    let percent = cpu::cpu_times_percent(&times, &times);
+    assert_eq!(cpu::CpuTimesPercent::default(), percent);
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "serde")]
+
+use heim_common::units::time;
+use heim_cpu as cpu;
+
+#[heim_derive::test]
+async fn serde_time() {
+    let cpu_time = cpu::times().await.unwrap();
+
+    let value = serde_json::to_value(&cpu_time).unwrap();
+
+    assert_eq!(
+        value["user"].as_f64(),
+        Some(cpu_time.user().get::<time::second>())
+    );
+    assert_eq!(
+        value["system"].as_f64(),
+        Some(cpu_time.system().get::<time::second>())
+    );
+    assert_eq!(
+        value["idle"].as_f64(),
+        Some(cpu_time.idle().get::<time::second>())
+    );
+    for field in &["nice", "io_wait", "irq", "soft_irq", "steal", "guest"] {
+        assert!(value[field].is_number(), "{} field is missing", field);
+    }
+}
+
+#[heim_derive::test]
+async fn serde_stats() {
+    let stats = cpu::stats().await.unwrap();
+
+    let value = serde_json::to_value(&stats).unwrap();
+
+    assert_eq!(value["ctx_switches"].as_u64(), Some(stats.ctx_switches()));
+    assert_eq!(value["interrupts"].as_u64(), Some(stats.interrupts()));
+    assert_eq!(
+        value["soft_interrupts"].as_u64(),
+        Some(stats.soft_interrupts())
+    );
+    assert_eq!(value["syscalls"].as_u64(), Some(stats.syscalls()));
+}
@@ -1,6 +1,8 @@
 //#![feature(test)]
 
 //use heim_common::prelude::*;
+use std::time::Duration;
+
 use futures::StreamExt;
 use heim_common::units::frequency;
 use heim_cpu as cpu;
@@ -15,6 +17,19 @@ async fn smoke_frequency() {
     let _ = freq.max();
 }
 
+#[heim_derive::test]
+async fn smoke_per_cpu_frequency() {
+    let frequencies = cpu::per_cpu_frequency().await.unwrap();
+    futures::pin_mut!(frequencies);
+    while let Some(freq) = frequencies.next().await {
+        let f = freq.unwrap();
+
+        assert!(f.current().get::<frequency::hertz>() > 0);
+        let _ = f.min();
+        let _ = f.max();
+    }
+}
+
 #[heim_derive::test]
 #[cfg(target_os = "linux")]
 async fn smoke_frequencies() {
@@ -35,20 +50,22 @@ async fn smoke_stats() {
 
     let _ = stats.ctx_switches();
     let _ = stats.interrupts();
+    let _ = stats.soft_interrupts();
+    let _ = stats.syscalls();
 
     #[cfg(target_os = "linux")]
     {
         use heim_cpu::os::linux::CpuStatsExt;
 
-        let _ = stats.soft_interrupts();
+        let _ = CpuStatsExt::soft_interrupts(&stats);
     }
 
     #[cfg(target_os = "macos")]
     {
         use heim_cpu::os::macos::CpuStatsExt;
 
-        let _ = stats.soft_interrupts();
-        let _ = stats.syscalls();
+        let _ = CpuStatsExt::soft_interrupts(&stats);
+        let _ = CpuStatsExt::syscalls(&stats);
     }
 
     #[cfg(target_os = "windows")]
@@ -61,31 +78,47 @@ async fn smoke_stats() {
 }
 
 #[heim_derive::test]
-async fn smoke_time() {
-    let time = cpu::time().await;
+#[cfg(unix)]
+async fn smoke_loadavg() {
+    let (one, five, fifteen) = cpu::loadavg().await.unwrap();
+
+    assert!(one >= 0.0);
+    assert!(five >= 0.0);
+    assert!(fifteen >= 0.0);
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "windows")]
+async fn smoke_loadavg() {
+    assert!(cpu::loadavg().await.is_err());
+}
+
+#[heim_derive::test]
+async fn smoke_times() {
+    let time = cpu::times().await;
     let time = time.unwrap();
 
     let _ = time.system();
     let _ = time.user();
     let _ = time.idle();
+    let _ = time.nice();
+    let _ = time.io_wait();
+    let _ = time.irq();
+    let _ = time.soft_irq();
+    let _ = time.steal();
+    let _ = time.guest();
 
     #[cfg(target_os = "linux")]
     {
         use heim_cpu::os::linux::CpuTimeExt;
 
-        let _ = time.nice();
-        let _ = time.io_wait();
-        let _ = time.irq();
-        let _ = time.soft_irq();
-        let _ = time.steal();
-        let _ = time.guest();
         let _ = time.guest_nice();
     }
 }
 
 #[heim_derive::test]
-async fn smoke_times() {
-    let times = cpu::times().await.unwrap();
+async fn smoke_per_cpu_times() {
+    let times = cpu::per_cpu_times().await.unwrap();
     futures::pin_mut!(times);
     while let Some(time) = times.next().await {
         let time = time.unwrap();
@@ -93,17 +126,17 @@ async fn smoke_times() {
         let _ = time.system();
         let _ = time.user();
         let _ = time.idle();
+        let _ = time.nice();
+        let _ = time.io_wait();
+        let _ = time.irq();
+        let _ = time.soft_irq();
+        let _ = time.steal();
+        let _ = time.guest();
 
         #[cfg(target_os = "linux")]
         {
             use heim_cpu::os::linux::CpuTimeExt;
 
-            let _ = time.nice();
-            let _ = time.io_wait();
-            let _ = time.irq();
-            let _ = time.soft_irq();
-            let _ = time.steal();
-            let _ = time.guest();
             let _ = time.guest_nice();
         }
     }
@@ -135,3 +168,24 @@ async fn smoke_cpu_physical_count() {
 async fn smoke_cpu_usage() {
     let _measurement = cpu::usage().await.unwrap();
 }
+
+#[heim_derive::skip_ci(target_os = "linux")]
+#[heim_derive::test]
+async fn smoke_frequency_updates() {
+    let updates = cpu::frequency_updates(Duration::from_millis(10)).take(2);
+    futures::pin_mut!(updates);
+    while let Some(freq) = updates.next().await {
+        let _ = freq.unwrap().current();
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_per_cpu_frequency_updates() {
+    let updates = cpu::per_cpu_frequency_updates(Duration::from_millis(10)).take(2);
+    futures::pin_mut!(updates);
+    while let Some(frequencies) = updates.next().await {
+        for freq in frequencies.unwrap() {
+            assert!(freq.current().get::<frequency::hertz>() > 0);
+        }
+    }
+}
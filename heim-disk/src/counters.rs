@@ -2,7 +2,7 @@ use std::ffi::OsStr;
 use std::fmt;
 
 use heim_common::prelude::*;
-use heim_common::units::Information;
+use heim_common::units::{Information, Time};
 
 use crate::sys;
 
@@ -21,6 +21,15 @@ pub struct IoCounters(sys::IoCounters);
 
 wrap!(IoCounters, sys::IoCounters);
 
+heim_common::serde!(IoCounters {
+    device_name => |counters| counters.device_name().to_string_lossy(),
+    read_count,
+    write_count,
+    read_bytes,
+    write_bytes,
+    busy_time,
+});
+
 impl IoCounters {
     /// Returns disk device name.
     pub fn device_name(&self) -> &OsStr {
@@ -46,6 +55,15 @@ impl IoCounters {
     pub fn write_bytes(&self) -> Information {
         self.as_ref().write_bytes()
     }
+
+    /// Returns time spent doing actual I/O operations.
+    ///
+    /// ## Compatibility
+    ///
+    /// For macOS and Windows it is a sum of the time spent on reads and writes.
+    pub fn busy_time(&self) -> Time {
+        self.as_ref().busy_time()
+    }
 }
 
 impl fmt::Debug for IoCounters {
@@ -56,11 +74,15 @@ impl fmt::Debug for IoCounters {
             .field("write_count", &self.write_count())
             .field("read_bytes", &self.read_bytes())
             .field("write_bytes", &self.write_bytes())
+            .field("busy_time", &self.busy_time())
             .finish()
     }
 }
 
-/// Returns a stream over [IO counters] for all disks available in system.
+/// Returns a stream over [IO counters] for all whole disk devices available in system.
+///
+/// Disk partitions are not included in order to avoid double-counting,
+/// see [io_counters_per_partition] for them.
 ///
 /// ## Compatibility
 ///
@@ -68,12 +90,45 @@ impl fmt::Debug for IoCounters {
 /// from `cmd.exe` first in order to enable IO counters.
 ///
 /// [IO counters]: struct.IoCounters.html
+/// [io_counters_per_partition]: fn.io_counters_per_partition.html
 pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
     let inner = sys::io_counters().await?;
 
     Ok(inner.map_ok(Into::into))
 }
 
+/// Returns a stream over [IO counters] for all disks and their partitions available in system.
+///
+/// ## Compatibility
+///
+/// macOS provides statistics for the whole disks only and Windows for the volumes only,
+/// so this function returns the same values as [io_counters] for these platforms.
+///
+/// [IO counters]: struct.IoCounters.html
+/// [io_counters]: fn.io_counters.html
+pub async fn io_counters_per_partition() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    let inner = sys::io_counters_per_partition().await?;
+
+    Ok(inner.map_ok(Into::into))
+}
+
+/// Returns [IO counters] summed up for all whole disk devices returned by [io_counters].
+///
+/// Device name for the returned counters is an empty string.
+///
+/// [IO counters]: struct.IoCounters.html
+/// [io_counters]: fn.io_counters.html
+pub async fn io_counters_total() -> Result<IoCounters> {
+    let inner = sys::io_counters().await?;
+    let total = inner
+        .try_fold(sys::IoCounters::default(), |acc, counters| {
+            future::ok(acc + counters)
+        })
+        .await?;
+
+    Ok(total.into())
+}
+
 /// Returns a stream over [IO counters] for each physical disk installed on the system.
 ///
 /// [IO counters]: struct.IoCounters.html
@@ -2,93 +2,197 @@ use heim_common::prelude::*;
 
 use std::str::FromStr;
 
-/// Known filesystems.
-///
-/// All physical filesystems should have their own enum element
-/// and all virtual filesystems will go into the `Other` element.
-#[derive(Debug, Eq, PartialEq, Hash, Clone)]
-#[non_exhaustive]
-pub enum FileSystem {
-    /// ext2 (https://en.wikipedia.org/wiki/Ext2)
-    Ext2,
+// Names of the less common virtual, pseudo and network filesystems without their own
+// `FileSystem` variant, all of them are considered as a non-physical ones by `FileSystem::is_virtual`.
+const VIRTUAL_FILESYSTEMS: &[&str] = &[
+    "9p",
+    "afpfs",
+    "autofs",
+    "binfmt_misc",
+    "bpf",
+    "cgroup",
+    "cgroup2",
+    "configfs",
+    "debugfs",
+    "devpts",
+    "efivarfs",
+    "fdescfs",
+    "fuse",
+    "fusectl",
+    "hugetlbfs",
+    "mqueue",
+    "mtmfs",
+    "nfsd",
+    "nsfs",
+    "nullfs",
+    "procfs",
+    "pstore",
+    "ramfs",
+    "rpc_pipefs",
+    "securityfs",
+    "selinuxfs",
+    "tracefs",
+    "webdav",
+];
 
-    /// ext3 (https://en.wikipedia.org/wiki/Ext3)
-    Ext3,
+heim_common::serde! {
+    /// Known filesystems.
+    ///
+    /// All well-known physical filesystems and the most common virtual ones
+    /// have their own enum element, other filesystems will go into the `Other` element.
+    ///
+    /// Filesystem names are normalized while parsing, so the platform-specific aliases
+    /// are resolved into the same element, ex. both `msdos` (macOS) and `vfat` (Linux)
+    /// are parsed as a [`FileSystem::VFat`].
+    ///
+    /// [`FileSystem::VFat`]: #variant.VFat
+    #[derive(Debug, Eq, PartialEq, Hash, Clone)]
+    #[non_exhaustive]
+    pub enum FileSystem {
+        /// ext2 (https://en.wikipedia.org/wiki/Ext2)
+        Ext2,
 
-    /// ext4 (https://en.wikipedia.org/wiki/Ext4)
-    Ext4,
+        /// ext3 (https://en.wikipedia.org/wiki/Ext3)
+        Ext3,
 
-    /// FAT (https://en.wikipedia.org/wiki/File_Allocation_Table)
-    VFat,
+        /// ext4 (https://en.wikipedia.org/wiki/Ext4)
+        Ext4,
 
-    /// exFAT (https://en.wikipedia.org/wiki/ExFAT)
-    ExFat,
+        /// FAT (https://en.wikipedia.org/wiki/File_Allocation_Table)
+        VFat,
 
-    /// F2FS (https://en.wikipedia.org/wiki/F2FS)
-    F2fs,
+        /// exFAT (https://en.wikipedia.org/wiki/ExFAT)
+        ExFat,
 
-    /// NTFS (https://en.wikipedia.org/wiki/NTFS)
-    Ntfs,
+        /// F2FS (https://en.wikipedia.org/wiki/F2FS)
+        F2fs,
 
-    /// ZFS (https://en.wikipedia.org/wiki/ZFS)
-    Zfs,
+        /// NTFS (https://en.wikipedia.org/wiki/NTFS)
+        Ntfs,
 
-    /// HFS (https://en.wikipedia.org/wiki/Hierarchical_File_System)
-    Hfs,
+        /// ZFS (https://en.wikipedia.org/wiki/ZFS)
+        Zfs,
 
-    /// HFS+ (https://en.wikipedia.org/wiki/HFS_Plus)
-    HfsPlus,
+        /// HFS (https://en.wikipedia.org/wiki/Hierarchical_File_System)
+        Hfs,
 
-    /// JFS (https://en.wikipedia.org/wiki/JFS_(file_system))
-    Jfs,
+        /// HFS+ (https://en.wikipedia.org/wiki/HFS_Plus)
+        HfsPlus,
 
-    /// ReiserFS 3 (https://en.wikipedia.org/wiki/ReiserFS)
-    Reiser3,
+        /// JFS (https://en.wikipedia.org/wiki/JFS_(file_system))
+        Jfs,
 
-    /// ReiserFS 4 (https://en.wikipedia.org/wiki/Reiser4)
-    Reiser4,
+        /// ReiserFS 3 (https://en.wikipedia.org/wiki/ReiserFS)
+        Reiser3,
 
-    /// Btrfs (https://en.wikipedia.org/wiki/Btrfs)
-    Btrfs,
+        /// ReiserFS 4 (https://en.wikipedia.org/wiki/Reiser4)
+        Reiser4,
 
-    /// MINIX FS (https://en.wikipedia.org/wiki/MINIX_file_system)
-    Minix,
+        /// Btrfs (https://en.wikipedia.org/wiki/Btrfs)
+        Btrfs,
 
-    /// NILFS (https://en.wikipedia.org/wiki/NILFS)
-    Nilfs,
+        /// MINIX FS (https://en.wikipedia.org/wiki/MINIX_file_system)
+        Minix,
 
-    /// XFS (https://en.wikipedia.org/wiki/XFS)
-    Xfs,
+        /// NILFS (https://en.wikipedia.org/wiki/NILFS)
+        Nilfs,
 
-    /// APFS (https://en.wikipedia.org/wiki/Apple_File_System)
-    Apfs,
+        /// XFS (https://en.wikipedia.org/wiki/XFS)
+        Xfs,
 
-    // TODO: Should it be considered as a physical FS?
-    /// FUSE (https://en.wikipedia.org/wiki/Filesystem_in_Userspace)
-    FuseBlk,
+        /// APFS (https://en.wikipedia.org/wiki/Apple_File_System)
+        Apfs,
 
-    // TODO: Extend list
-    /// Some unspecified filesystem.
-    Other(String),
+        /// ISO 9660 (https://en.wikipedia.org/wiki/ISO_9660)
+        Iso9660,
+
+        /// UDF (https://en.wikipedia.org/wiki/Universal_Disk_Format)
+        Udf,
+
+        /// SquashFS (https://en.wikipedia.org/wiki/SquashFS)
+        Squashfs,
+
+        /// tmpfs (https://en.wikipedia.org/wiki/Tmpfs)
+        Tmpfs,
+
+        /// OverlayFS (https://en.wikipedia.org/wiki/OverlayFS)
+        Overlay,
+
+        /// NFS (https://en.wikipedia.org/wiki/Network_File_System)
+        Nfs,
+
+        /// NFS version 4 (https://en.wikipedia.org/wiki/Network_File_System#NFSv4)
+        Nfs4,
+
+        /// CIFS (https://en.wikipedia.org/wiki/Server_Message_Block)
+        Cifs,
+
+        /// SMB (https://en.wikipedia.org/wiki/Server_Message_Block)
+        Smbfs,
+
+        /// procfs (https://en.wikipedia.org/wiki/Procfs)
+        Proc,
+
+        /// sysfs (https://en.wikipedia.org/wiki/Sysfs)
+        Sysfs,
+
+        /// devtmpfs (https://en.wikipedia.org/wiki/Device_file#devtmpfs)
+        Devtmpfs,
+
+        /// devfs (https://en.wikipedia.org/wiki/Device_file#devfs)
+        Devfs,
+
+        // TODO: Should it be considered as a physical FS?
+        /// FUSE (https://en.wikipedia.org/wiki/Filesystem_in_Userspace)
+        FuseBlk,
+
+        // TODO: Extend list
+        /// Some unspecified filesystem.
+        Other(String),
+    }
 }
 
 impl FileSystem {
-    /// Checks if filesystem is used for a physical devices
-    // TODO: Revisit clippy lint later, `match!` should be in MSRV now?
-    #[allow(clippy::match_like_matches_macro)]
+    /// Checks if filesystem is used for a physical devices.
+    ///
+    /// Filesystem is considered as a physical one
+    /// if it is not in the list of known virtual filesystems,
+    /// see [`is_virtual`](#method.is_virtual) method.
     pub fn is_physical(&self) -> bool {
-        match self {
-            FileSystem::Other(..) => false,
-            _ => true,
-        }
+        !self.is_virtual()
     }
 
-    /// Checks if filesystem is used for a virtual devices (such as `tmpfs` or `smb` mounts)
+    /// Checks if filesystem is used for a virtual devices (such as `tmpfs` or `smb` mounts).
+    ///
+    /// Pseudo filesystems (`proc`, `sysfs`, `devfs`, etc.), memory-backed filesystems
+    /// (`tmpfs`, `ramfs`), network filesystems (`nfs`, `cifs`, `smbfs`)
+    /// and FUSE mounts (`fuse.*`) are considered as a virtual ones.
     pub fn is_virtual(&self) -> bool {
-        !self.is_physical()
+        match self {
+            FileSystem::Tmpfs
+            | FileSystem::Overlay
+            | FileSystem::Nfs
+            | FileSystem::Nfs4
+            | FileSystem::Cifs
+            | FileSystem::Smbfs
+            | FileSystem::Proc
+            | FileSystem::Sysfs
+            | FileSystem::Devtmpfs
+            | FileSystem::Devfs => true,
+            FileSystem::Other(name) => {
+                name.starts_with("fuse.")
+                    || VIRTUAL_FILESYSTEMS
+                        .iter()
+                        .any(|known| name.eq_ignore_ascii_case(known))
+            }
+            _ => false,
+        }
     }
 
     /// Returns a string identifying this filesystem.
+    ///
+    /// Canonical Linux name is returned for the known filesystems,
+    /// no matter which alias was parsed.
     pub fn as_str(&self) -> &str {
         match self {
             FileSystem::Ext2 => "ext2",
@@ -110,6 +214,19 @@ impl FileSystem {
             FileSystem::Nilfs => "nilfs",
             FileSystem::Xfs => "xfs",
             FileSystem::Apfs => "apfs",
+            FileSystem::Iso9660 => "iso9660",
+            FileSystem::Udf => "udf",
+            FileSystem::Squashfs => "squashfs",
+            FileSystem::Tmpfs => "tmpfs",
+            FileSystem::Overlay => "overlay",
+            FileSystem::Nfs => "nfs",
+            FileSystem::Nfs4 => "nfs4",
+            FileSystem::Cifs => "cifs",
+            FileSystem::Smbfs => "smbfs",
+            FileSystem::Proc => "proc",
+            FileSystem::Sysfs => "sysfs",
+            FileSystem::Devtmpfs => "devtmpfs",
+            FileSystem::Devfs => "devfs",
             FileSystem::Other(string) => string.as_str(),
         }
     }
@@ -124,6 +241,7 @@ impl FromStr for FileSystem {
             _ if s.eq_ignore_ascii_case("ext3") => Ok(FileSystem::Ext3),
             _ if s.eq_ignore_ascii_case("ext4") => Ok(FileSystem::Ext4),
             _ if s.eq_ignore_ascii_case("vfat") => Ok(FileSystem::VFat),
+            _ if s.eq_ignore_ascii_case("msdos") => Ok(FileSystem::VFat),
             _ if s.eq_ignore_ascii_case("ntfs") => Ok(FileSystem::Ntfs),
             _ if s.eq_ignore_ascii_case("zfs") => Ok(FileSystem::Zfs),
             _ if s.eq_ignore_ascii_case("hfs") => Ok(FileSystem::Hfs),
@@ -132,12 +250,27 @@ impl FromStr for FileSystem {
             _ if s.eq_ignore_ascii_case("exfat") => Ok(FileSystem::ExFat),
             _ if s.eq_ignore_ascii_case("f2fs") => Ok(FileSystem::F2fs),
             _ if s.eq_ignore_ascii_case("hfsplus") => Ok(FileSystem::HfsPlus),
+            _ if s.eq_ignore_ascii_case("hfs+") => Ok(FileSystem::HfsPlus),
             _ if s.eq_ignore_ascii_case("jfs") => Ok(FileSystem::Jfs),
             _ if s.eq_ignore_ascii_case("btrfs") => Ok(FileSystem::Btrfs),
             _ if s.eq_ignore_ascii_case("minix") => Ok(FileSystem::Minix),
             _ if s.eq_ignore_ascii_case("nilfs") => Ok(FileSystem::Nilfs),
             _ if s.eq_ignore_ascii_case("xfs") => Ok(FileSystem::Xfs),
             _ if s.eq_ignore_ascii_case("apfs") => Ok(FileSystem::Apfs),
+            _ if s.eq_ignore_ascii_case("iso9660") => Ok(FileSystem::Iso9660),
+            _ if s.eq_ignore_ascii_case("cd9660") => Ok(FileSystem::Iso9660),
+            _ if s.eq_ignore_ascii_case("udf") => Ok(FileSystem::Udf),
+            _ if s.eq_ignore_ascii_case("squashfs") => Ok(FileSystem::Squashfs),
+            _ if s.eq_ignore_ascii_case("tmpfs") => Ok(FileSystem::Tmpfs),
+            _ if s.eq_ignore_ascii_case("overlay") => Ok(FileSystem::Overlay),
+            _ if s.eq_ignore_ascii_case("nfs") => Ok(FileSystem::Nfs),
+            _ if s.eq_ignore_ascii_case("nfs4") => Ok(FileSystem::Nfs4),
+            _ if s.eq_ignore_ascii_case("cifs") => Ok(FileSystem::Cifs),
+            _ if s.eq_ignore_ascii_case("smbfs") => Ok(FileSystem::Smbfs),
+            _ if s.eq_ignore_ascii_case("proc") => Ok(FileSystem::Proc),
+            _ if s.eq_ignore_ascii_case("sysfs") => Ok(FileSystem::Sysfs),
+            _ if s.eq_ignore_ascii_case("devtmpfs") => Ok(FileSystem::Devtmpfs),
+            _ if s.eq_ignore_ascii_case("devfs") => Ok(FileSystem::Devfs),
 
             _ if s.eq_ignore_ascii_case("fuseblk") => Ok(FileSystem::FuseBlk),
             _ => Ok(FileSystem::Other(s.to_string())),
@@ -0,0 +1,5 @@
+//! Linux-specific extensions.
+
+mod partitions;
+
+pub use self::partitions::*;
@@ -0,0 +1,16 @@
+/// Linux-specific extension for [Partition] struct.
+///
+/// [Partition]: ../../struct.Partition.html
+pub trait PartitionExt {
+    /// Returns comma-separated mount options, as listed in the `/proc/mounts` file.
+    ///
+    /// Example: `rw,relatime,data=ordered`
+    fn options(&self) -> &str;
+}
+
+#[cfg(target_os = "linux")]
+impl PartitionExt for crate::Partition {
+    fn options(&self) -> &str {
+        self.as_ref().options()
+    }
+}
@@ -1,21 +1,23 @@
 use winapi::shared::minwindef::DWORD;
 use winapi::um::winnt;
 
-/// Windows-specific drive type.
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-pub enum DriveType {
-    /// CD-ROM drive
-    CdRom,
-    /// Drive is fixed media; for example, a hard disk drive or a flash drive
-    Fixed,
-    /// The root path is invalid; for example, there is no volume mounted at the specified path.
-    NoRootDir,
-    /// RAM disk
-    RamDisk,
-    /// Drive is a remote (network) disk
-    Remote,
-    /// Drive has removable media; for example, a floppy drive, thumb drive, or flash card reader.
-    Removable,
+heim_common::serde! {
+    /// Windows-specific drive type.
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub enum DriveType {
+        /// CD-ROM drive
+        CdRom,
+        /// Drive is fixed media; for example, a hard disk drive or a flash drive
+        Fixed,
+        /// The root path is invalid; for example, there is no volume mounted at the specified path.
+        NoRootDir,
+        /// RAM disk
+        RamDisk,
+        /// Drive is a remote (network) disk
+        Remote,
+        /// Drive has removable media; for example, a floppy drive, thumb drive, or flash card reader.
+        Removable,
+    }
 }
 
 bitflags::bitflags! {
@@ -17,6 +17,12 @@ pub struct Partition(sys::Partition);
 
 wrap!(Partition, sys::Partition);
 
+heim_common::serde!(Partition {
+    device => |part| part.device().map(OsStr::to_string_lossy),
+    mount_point => |part| part.mount_point().to_string_lossy(),
+    file_system,
+});
+
 impl Partition {
     /// Returns partition device name if available.
     pub fn device(&self) -> Option<&OsStr> {
@@ -1,4 +1,5 @@
 use std::ffi::{CString, OsStr};
+use std::ops;
 use std::str::FromStr;
 
 use heim_common::prelude::*;
@@ -73,6 +74,23 @@ impl IoCounters {
     }
 }
 
+impl ops::Add for IoCounters {
+    type Output = IoCounters;
+
+    fn add(self, rhs: IoCounters) -> IoCounters {
+        IoCounters {
+            name: String::new(),
+            read_count: self.read_count + rhs.read_count,
+            write_count: self.write_count + rhs.write_count,
+            read_bytes: self.read_bytes + rhs.read_bytes,
+            write_bytes: self.write_bytes + rhs.write_bytes,
+            busy_time: self.busy_time + rhs.busy_time,
+            read_merged_count: self.read_merged_count + rhs.read_merged_count,
+            write_merged_count: self.write_merged_count + rhs.write_merged_count,
+        }
+    }
+}
+
 impl FromStr for IoCounters {
     type Err = Error;
 
@@ -99,7 +117,7 @@ impl FromStr for IoCounters {
         let mut parts = parts.skip(2);
         let busy_time = parts
             .try_parse_next()
-            .map(|seconds: u64| Time::new::<time::second>(seconds as f64))?;
+            .map(|ms: u64| Time::new::<time::millisecond>(ms as f64))?;
 
         Ok(IoCounters {
             name,
@@ -114,15 +132,17 @@ impl FromStr for IoCounters {
     }
 }
 
-pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
+pub async fn io_counters_per_partition() -> Result<impl Stream<Item = Result<IoCounters>>> {
     let stream =
         rt::fs::read_lines_into::<_, _, Error>(rt::linux::procfs_root().join("diskstats")).await?;
 
     Ok(stream)
 }
 
-pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounters>>> {
-    let counters = io_counters().await?;
+// Partitions are not listed at the `/sys/block` directory,
+// so only the whole devices are left after this filter
+pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    let counters = io_counters_per_partition().await?;
     let stream = counters.try_filter_map(|device| async move {
         if device.is_storage_device().await? {
             Ok(Some(device))
@@ -133,3 +153,35 @@ pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounte
 
     Ok(stream)
 }
+
+pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    io_counters().await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::units::{information, time};
+
+    use super::IoCounters;
+
+    #[test]
+    fn test_parse_diskstats_line() {
+        let line = "   8       0 sda 1159 384 94506 553 6127 3394 211576 4233 0 3740 4787 0 0 0 0";
+        let counters = IoCounters::from_str(line).unwrap();
+
+        assert_eq!("sda", counters.device_name());
+        assert_eq!(1159, counters.read_count());
+        assert_eq!(6127, counters.write_count());
+        assert_eq!(
+            94506 * 512,
+            counters.read_bytes().get::<information::byte>()
+        );
+        assert_eq!(
+            211_576 * 512,
+            counters.write_bytes().get::<information::byte>()
+        );
+        assert_eq!(3740, counters.busy_time().get::<time::millisecond>() as u64);
+    }
+}
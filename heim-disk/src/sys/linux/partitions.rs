@@ -1,7 +1,4 @@
-use std::collections::HashSet;
 use std::ffi::OsStr;
-use std::fs;
-use std::io::{self, BufRead};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
@@ -92,37 +89,6 @@ impl FromStr for Partition {
     }
 }
 
-// Returns stream with known physical (only!) partitions
-async fn known_filesystems() -> Result<HashSet<FileSystem>> {
-    rt::spawn_blocking(|| {
-        let file = fs::File::open(rt::linux::procfs_root().join("filesystems"))?;
-        let reader = io::BufReader::new(file);
-        let mut acc = HashSet::with_capacity(4);
-
-        for line in reader.lines() {
-            let line = line?;
-            let mut parts = line.splitn(2, '\t');
-
-            #[allow(clippy::match_like_matches_macro)] // >= 1.42.0
-            let nodev = match parts.next() {
-                Some("nodev") => true,
-                _ => false,
-            };
-
-            let fs = match parts.next() {
-                Some("zfs") if nodev => FileSystem::from_str("zfs"),
-                Some(filesystem) if !nodev => FileSystem::from_str(filesystem),
-                _ => continue,
-            }?;
-
-            let _ = acc.insert(fs);
-        }
-
-        Ok(acc)
-    })
-    .await
-}
-
 pub async fn partitions() -> Result<impl Stream<Item = Result<Partition>>> {
     let lines = rt::fs::read_lines(rt::linux::procfs_root().join("mounts")).await?;
     let stream = lines
@@ -137,12 +103,11 @@ pub async fn partitions() -> Result<impl Stream<Item = Result<Partition>>> {
 }
 
 pub async fn partitions_physical() -> Result<impl Stream<Item = Result<Partition>>> {
-    let filesystems = known_filesystems().await?;
     let stream = partitions().await?;
 
-    let stream = stream.try_filter_map(move |part| match part {
+    let stream = stream.try_filter_map(|part| match part {
         Partition { device: None, .. } => future::ok(None),
-        Partition { ref fs_type, .. } if !filesystems.contains(fs_type) => future::ok(None),
+        Partition { ref fs_type, .. } if fs_type.is_virtual() => future::ok(None),
         partition => future::ok(Some(partition)),
     });
 
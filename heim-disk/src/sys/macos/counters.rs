@@ -1,10 +1,11 @@
 use std::ffi::OsStr;
+use std::ops;
 
 use heim_common::prelude::*;
 use heim_common::sys::macos::iokit::{self, DictionaryProps};
 use heim_common::units::{information, time, Information, Time};
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct IoCounters {
     device: String,
     removable: bool,
@@ -44,6 +45,27 @@ impl IoCounters {
     pub fn write_time(&self) -> Time {
         self.write_time
     }
+
+    pub fn busy_time(&self) -> Time {
+        self.read_time + self.write_time
+    }
+}
+
+impl ops::Add for IoCounters {
+    type Output = IoCounters;
+
+    fn add(self, rhs: IoCounters) -> IoCounters {
+        IoCounters {
+            device: String::new(),
+            removable: self.removable && rhs.removable,
+            reads: self.reads + rhs.reads,
+            writes: self.writes + rhs.writes,
+            read_bytes: self.read_bytes + rhs.read_bytes,
+            write_bytes: self.write_bytes + rhs.write_bytes,
+            read_time: self.read_time + rhs.read_time,
+            write_time: self.write_time + rhs.write_time,
+        }
+    }
 }
 
 fn filter_map_block_devices(device: iokit::IoObject) -> Result<Option<IoCounters>> {
@@ -86,6 +108,11 @@ pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
     Ok(stream::iter(iter))
 }
 
+// IOKit provides statistics for the whole block devices only
+pub async fn io_counters_per_partition() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    io_counters().await
+}
+
 pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounters>>> {
     let inner = io_counters().await?;
 
@@ -2,6 +2,7 @@ use std::ffi::CString;
 use std::fmt;
 use std::io;
 use std::mem;
+use std::os::unix::ffi::OsStrExt;
 use std::path::Path;
 
 use heim_common::prelude::*;
@@ -49,22 +50,22 @@ impl Usage {
     }
 }
 
-// TODO: Stub
 impl fmt::Debug for Usage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("Usage").finish()
+        f.debug_struct("Usage")
+            .field("total", &self.total())
+            .field("used", &self.used())
+            .field("free", &self.free())
+            .field("ratio", &self.ratio())
+            .field("flags", &self.flags())
+            .finish()
     }
 }
 
 // TODO: It is an internal function, we could monomorphize it and accept `path: &Path`
 pub async fn usage<T: AsRef<Path>>(path: T) -> Result<Usage> {
-    let path = path
-        .as_ref()
-        .to_str()
-        .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))
-        .and_then(|string| {
-            CString::new(string).map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))
-        })
+    let path = CString::new(path.as_ref().as_os_str().as_bytes())
+        .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))
         .map_err(|e| Error::from(e).with_message("Invalid path"))?;
 
     let mut vfs = mem::MaybeUninit::<libc::statvfs>::uninit();
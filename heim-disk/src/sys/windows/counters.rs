@@ -1,4 +1,5 @@
 use std::ffi::OsStr;
+use std::ops;
 use std::path::{Path, PathBuf};
 
 use heim_common::prelude::*;
@@ -6,7 +7,7 @@ use heim_common::units::{information, time, Information, Time};
 
 use super::bindings;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct IoCounters {
     volume_path: PathBuf,
     read_count: u64,
@@ -45,6 +46,26 @@ impl IoCounters {
     pub fn write_time(&self) -> Time {
         self.write_time
     }
+
+    pub fn busy_time(&self) -> Time {
+        self.read_time + self.write_time
+    }
+}
+
+impl ops::Add for IoCounters {
+    type Output = IoCounters;
+
+    fn add(self, rhs: IoCounters) -> IoCounters {
+        IoCounters {
+            volume_path: PathBuf::new(),
+            read_count: self.read_count + rhs.read_count,
+            write_count: self.write_count + rhs.write_count,
+            read_bytes: self.read_bytes + rhs.read_bytes,
+            write_bytes: self.write_bytes + rhs.write_bytes,
+            read_time: self.read_time + rhs.read_time,
+            write_time: self.write_time + rhs.write_time,
+        }
+    }
 }
 
 fn inner_stream<F>(mut filter: F) -> impl Iterator<Item = Result<IoCounters>>
@@ -89,6 +110,11 @@ pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
     Ok(stream::iter(inner_stream(|_| true)))
 }
 
+// Performance counters are fetched per volume already
+pub async fn io_counters_per_partition() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    io_counters().await
+}
+
 pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounters>>> {
     let iter = inner_stream(|path: &Path| {
         bindings::DriveType::from_path(path) == Some(bindings::DriveType::Fixed)
@@ -8,6 +8,10 @@ use crate::sys;
 
 /// Disk usage statistics.
 ///
+/// Note that `used` and `free` values do not add up to `total` on *nix systems,
+/// because some amount of blocks is usually reserved for the privileged users;
+/// `used` includes these reserved blocks and `free` excludes them.
+///
 /// ## Compatibility
 ///
 /// See [os]-specific extension traits also.
@@ -23,17 +27,24 @@ impl Usage {
         self.as_ref().total()
     }
 
-    /// Returns used information amount used in partition.
+    /// Returns information amount used in partition.
+    ///
+    /// It is calculated as `total - free`, where `free` is the amount of free blocks
+    /// including the ones reserved for the privileged users.
     pub fn used(&self) -> Information {
         self.as_ref().used()
     }
 
-    /// Returns free information about used in partition.
+    /// Returns free information amount available in partition for the unprivileged users.
+    ///
+    /// Blocks reserved for the privileged users are not included into this value.
     pub fn free(&self) -> Information {
         self.as_ref().free()
     }
 
-    /// Returns the ratio between used and free information amount in partition.
+    /// Returns the usage ratio of the partition from the unprivileged user point of view.
+    ///
+    /// It is calculated as `used / (used + free)`, same as `psutil` does.
     pub fn ratio(&self) -> Ratio {
         self.as_ref().ratio()
     }
@@ -52,6 +63,9 @@ impl fmt::Debug for Usage {
 
 /// Returns disk [Usage] statistics about the partition which contains the given `path`.
 ///
+/// `path` is not required to be a mount point, any path inside of the mounted filesystem
+/// will resolve to that filesystem statistics.
+///
 /// [Usage]: ./struct.Usage.html
 pub async fn usage<T>(path: T) -> Result<Usage>
 where
@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+use heim_disk::FileSystem;
+
+#[test]
+fn test_known_filesystems() {
+    let known = [
+        ("ext4", FileSystem::Ext4),
+        ("xfs", FileSystem::Xfs),
+        ("btrfs", FileSystem::Btrfs),
+        ("apfs", FileSystem::Apfs),
+        ("hfs+", FileSystem::HfsPlus),
+        ("iso9660", FileSystem::Iso9660),
+        ("squashfs", FileSystem::Squashfs),
+        ("tmpfs", FileSystem::Tmpfs),
+        ("overlay", FileSystem::Overlay),
+        ("nfs", FileSystem::Nfs),
+        ("nfs4", FileSystem::Nfs4),
+        ("cifs", FileSystem::Cifs),
+        ("proc", FileSystem::Proc),
+        ("sysfs", FileSystem::Sysfs),
+        ("devtmpfs", FileSystem::Devtmpfs),
+    ];
+
+    for (name, expected) in known.iter() {
+        let fs = FileSystem::from_str(name).unwrap();
+        assert_eq!(expected, &fs);
+        assert_eq!(*name, fs.as_str());
+    }
+}
+
+#[test]
+fn test_aliases() {
+    assert_eq!(FileSystem::VFat, FileSystem::from_str("msdos").unwrap());
+    assert_eq!(FileSystem::Iso9660, FileSystem::from_str("cd9660").unwrap());
+    assert_eq!(
+        FileSystem::HfsPlus,
+        FileSystem::from_str("hfsplus").unwrap()
+    );
+    assert_eq!(FileSystem::Ext4, FileSystem::from_str("EXT4").unwrap());
+}
+
+#[test]
+fn test_other() {
+    let fs = FileSystem::from_str("fuse.sshfs").unwrap();
+
+    assert_eq!(FileSystem::Other("fuse.sshfs".to_string()), fs);
+    assert_eq!("fuse.sshfs", fs.as_str());
+}
+
+#[test]
+fn test_is_physical() {
+    for name in &[
+        "ext4", "xfs", "btrfs", "apfs", "hfs", "vfat", "ntfs", "iso9660",
+    ] {
+        assert!(
+            FileSystem::from_str(name).unwrap().is_physical(),
+            "{}",
+            name
+        );
+    }
+    for name in &[
+        "tmpfs",
+        "overlay",
+        "nfs",
+        "nfs4",
+        "cifs",
+        "proc",
+        "sysfs",
+        "devfs",
+        "cgroup2",
+        "fuse.sshfs",
+    ] {
+        assert!(FileSystem::from_str(name).unwrap().is_virtual(), "{}", name);
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "serde")]
+
+use futures::prelude::*;
+use heim_common::units::information;
+use heim_disk as disk;
+
+#[heim_derive::test]
+async fn serde_partitions() {
+    let partitions = disk::partitions().await.unwrap();
+    futures::pin_mut!(partitions);
+    while let Some(part) = partitions.next().await {
+        let part = part.unwrap();
+
+        let value = serde_json::to_value(&part).unwrap();
+
+        assert_eq!(
+            value["device"].as_str().map(String::from),
+            part.device()
+                .map(|device| device.to_string_lossy().into_owned())
+        );
+        assert_eq!(
+            value["mount_point"].as_str(),
+            Some(part.mount_point().to_string_lossy().as_ref())
+        );
+        let file_system: disk::FileSystem =
+            serde_json::from_value(value["file_system"].clone()).unwrap();
+        assert_eq!(&file_system, part.file_system());
+    }
+}
+
+#[heim_derive::test]
+async fn serde_io_counters() {
+    let counters = disk::io_counters().await.unwrap();
+    futures::pin_mut!(counters);
+    while let Some(count) = counters.next().await {
+        let count = count.unwrap();
+
+        let value = serde_json::to_value(&count).unwrap();
+
+        assert_eq!(
+            value["device_name"].as_str(),
+            Some(count.device_name().to_string_lossy().as_ref())
+        );
+        assert_eq!(value["read_count"].as_u64(), Some(count.read_count()));
+        assert_eq!(value["write_count"].as_u64(), Some(count.write_count()));
+        assert_eq!(
+            value["read_bytes"].as_u64(),
+            Some(count.read_bytes().get::<information::byte>())
+        );
+        assert_eq!(
+            value["write_bytes"].as_u64(),
+            Some(count.write_bytes().get::<information::byte>())
+        );
+        assert!(value["busy_time"].is_number());
+    }
+}
@@ -2,6 +2,7 @@ use std::error::Error;
 
 use futures::StreamExt;
 
+use heim_common::units::information;
 use heim_disk as disk;
 
 #[heim_derive::test]
@@ -15,6 +16,13 @@ async fn smoke_partitions() -> Result<(), Box<dyn Error>> {
         let _ = part.mount_point();
         let _ = part.file_system();
 
+        #[cfg(target_os = "linux")]
+        {
+            use heim_disk::os::linux::PartitionExt;
+
+            let _ = part.options();
+        }
+
         #[cfg(target_os = "macos")]
         {
             use heim_disk::os::macos::PartitionExt;
@@ -45,6 +53,13 @@ async fn smoke_partitions_physical() -> Result<(), Box<dyn Error>> {
         let _ = part.mount_point();
         let _ = part.file_system();
 
+        #[cfg(target_os = "linux")]
+        {
+            use heim_disk::os::linux::PartitionExt;
+
+            let _ = part.options();
+        }
+
         #[cfg(target_os = "macos")]
         {
             use heim_disk::os::macos::PartitionExt;
@@ -83,6 +98,22 @@ async fn smoke_usage() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[heim_derive::test]
+async fn smoke_usage_nested_path() -> Result<(), Box<dyn Error>> {
+    let path = std::env::temp_dir().join(format!("heim-disk-usage-{}", std::process::id()));
+    std::fs::create_dir_all(&path)?;
+
+    let nested = disk::usage(&path).await;
+    std::fs::remove_dir(&path)?;
+    let nested = nested?;
+
+    assert!(nested.total().get::<information::byte>() > 0);
+    assert!(nested.used() <= nested.total());
+    assert!(nested.free() <= nested.total());
+
+    Ok(())
+}
+
 #[heim_derive::test]
 async fn smoke_io_counters() -> Result<(), Box<dyn Error>> {
     #[cfg(target_os = "windows")]
@@ -102,6 +133,7 @@ async fn smoke_io_counters() -> Result<(), Box<dyn Error>> {
         let _ = count.write_count();
         let _ = count.read_bytes();
         let _ = count.write_bytes();
+        let _ = count.busy_time();
     }
 
     Ok(())
@@ -126,7 +158,53 @@ async fn smoke_io_counters_physical() -> Result<(), Box<dyn Error>> {
         let _ = count.write_count();
         let _ = count.read_bytes();
         let _ = count.write_bytes();
+        let _ = count.busy_time();
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_io_counters_per_partition() -> Result<(), Box<dyn Error>> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::process::Command;
+
+        let _ = Command::new("diskperf").arg("-y").status();
+    }
+
+    let counters = disk::io_counters_per_partition().await?;
+    futures::pin_mut!(counters);
+    while let Some(count) = counters.next().await {
+        let count = count?;
+
+        let _ = count.device_name();
+        let _ = count.read_count();
+        let _ = count.write_count();
+        let _ = count.read_bytes();
+        let _ = count.write_bytes();
+        let _ = count.busy_time();
     }
 
     Ok(())
 }
+
+#[heim_derive::test]
+async fn smoke_io_counters_total() -> Result<(), Box<dyn Error>> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::process::Command;
+
+        let _ = Command::new("diskperf").arg("-y").status();
+    }
+
+    let total = disk::io_counters_total().await?;
+
+    let _ = total.read_count();
+    let _ = total.write_count();
+    let _ = total.read_bytes();
+    let _ = total.write_bytes();
+    let _ = total.busy_time();
+
+    Ok(())
+}
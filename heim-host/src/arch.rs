@@ -0,0 +1,42 @@
+use heim_common::prelude::*;
+
+use crate::{sys, Arch};
+
+/// Returns the CPU architecture of the running OS kernel.
+///
+/// It might differ from the architecture this program was compiled for
+/// (see [compiled_architecture]), ex. when a 32-bit binary runs on a 64-bit kernel
+/// or `x86_64` binary is translated by Rosetta 2 on Apple Silicon.
+///
+/// Various machine names reported by `uname(2)`, ex. `amd64`, `i686` or `arm64`,
+/// are normalized into the [Arch] variants.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::prelude::*;
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> Result<()> {
+/// let native = heim_host::architecture().await?;
+/// if native != heim_host::compiled_architecture() {
+///     println!("Running under translation layer, native arch is {}", native);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Arch]: ./enum.Arch.html
+/// [compiled_architecture]: ./fn.compiled_architecture.html
+pub async fn architecture() -> Result<Arch> {
+    sys::architecture().await
+}
+
+/// Returns the CPU architecture this program was compiled for.
+///
+/// Same to the `cfg!(target_arch)`, but represented as an [Arch] variant.
+///
+/// [Arch]: ./enum.Arch.html
+pub fn compiled_architecture() -> Arch {
+    platforms::target::TARGET_ARCH
+}
@@ -1,10 +1,28 @@
+use once_cell::sync::OnceCell;
+
 use heim_common::prelude::*;
 
 use crate::{sys, Time};
 
+// Boot time does not change while the system is running,
+// so it is loaded from the OS only once per process
+static BOOT_TIME: OnceCell<Time> = OnceCell::new();
+
 /// Returns system boot [Time] since the UNIX epoch.
 ///
+/// Value is loaded from the OS by the first successful call only
+/// and cached for the rest of the process lifetime,
+/// so the repeated calls are essentially free.
+///
 /// [Time]: ./struct.Time.html
 pub async fn boot_time() -> Result<Time> {
-    sys::boot_time().await
+    if let Some(boot_time) = BOOT_TIME.get() {
+        return Ok(*boot_time);
+    }
+
+    let boot_time = sys::boot_time().await?;
+
+    // Concurrent first calls might load it simultaneously,
+    // but the first stored value is the one returned to all of them
+    Ok(*BOOT_TIME.get_or_init(|| boot_time))
 }
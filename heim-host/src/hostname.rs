@@ -0,0 +1,27 @@
+use std::io;
+
+use heim_common::prelude::*;
+
+use crate::sys;
+
+/// Returns the system hostname.
+///
+/// Unlike the [platform] function, only the hostname is loaded,
+/// with the `gethostname(2)` call for *nix systems
+/// and `GetComputerNameExW` for Windows.
+///
+/// Hostname is never truncated, longer names are loaded with a larger buffer.
+/// Error is returned if hostname is not set.
+///
+/// [platform]: ./fn.platform.html
+pub async fn hostname() -> Result<String> {
+    let hostname = sys::hostname().await?;
+
+    if hostname.is_empty() {
+        let inner = io::Error::from(io::ErrorKind::NotFound);
+
+        Err(Error::from(inner).with_message("Hostname is not set"))
+    } else {
+        Ok(hostname)
+    }
+}
@@ -32,13 +32,17 @@ pub use platforms::target::Arch;
 
 mod sys;
 
+mod arch;
 mod boot_time;
+mod hostname;
 pub mod os;
 mod platform;
 mod uptime;
 mod users;
 
+pub use self::arch::*;
 pub use self::boot_time::*;
+pub use self::hostname::*;
 pub use self::platform::*;
 pub use self::uptime::*;
 pub use self::users::*;
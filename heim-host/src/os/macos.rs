@@ -25,11 +25,12 @@ pub trait UserExt {
 #[cfg(target_os = "macos")]
 impl UserExt for crate::User {
     fn pid(&self) -> Pid {
-        self.as_ref().pid()
+        // `utmpx` entries for user sessions always have login process PID
+        self.as_ref().pid().unwrap_or_default()
     }
 
     fn terminal(&self) -> &str {
-        self.as_ref().terminal()
+        self.as_ref().terminal().unwrap_or_default()
     }
 
     fn id(&self) -> &str {
@@ -37,6 +38,6 @@ impl UserExt for crate::User {
     }
 
     fn hostname(&self) -> &str {
-        self.as_ref().hostname()
+        self.as_ref().host().unwrap_or_default()
     }
 }
@@ -39,6 +39,14 @@ pub struct Platform(sys::Platform);
 
 wrap!(Platform, sys::Platform);
 
+heim_common::serde!(Platform {
+    system,
+    release,
+    version,
+    hostname,
+    architecture => |platform| platform.architecture().as_str(),
+});
+
 impl Platform {
     /// Returns system name.
     pub fn system(&self) -> &str {
@@ -56,11 +64,22 @@ impl Platform {
     }
 
     /// Returns system hostname.
+    ///
+    /// For *nix systems `gethostname(2)` is used as a fallback
+    /// if `uname(2)` returns an empty node name.
     pub fn hostname(&self) -> &str {
         self.as_ref().hostname()
     }
 
     /// Returns system architecture.
+    ///
+    /// Various machine names reported by `uname(2)`, ex. `amd64`, `i686` or `arm64`,
+    /// are normalized into the [Arch] variants.
+    ///
+    /// See [architecture] function if Rosetta 2 translation should be detected too.
+    ///
+    /// [Arch]: ./enum.Arch.html
+    /// [architecture]: ./fn.architecture.html
     pub fn architecture(&self) -> Arch {
         self.as_ref().architecture()
     }
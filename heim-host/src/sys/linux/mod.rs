@@ -1,7 +1,5 @@
 mod boot_time;
-mod uptime;
 mod users;
 
 pub use self::boot_time::*;
-pub use self::uptime::*;
 pub use self::users::*;
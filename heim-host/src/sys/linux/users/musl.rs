@@ -1,6 +1,7 @@
 use std::net::IpAddr;
 
 use heim_common::prelude::*;
+use heim_common::units::Time;
 use heim_common::Pid;
 
 use crate::os::linux::SessionId;
@@ -13,7 +14,7 @@ impl User {
         unimplemented!("https://github.com/heim-rs/heim/issues/141")
     }
 
-    pub fn terminal(&self) -> &str {
+    pub fn terminal(&self) -> Option<&str> {
         unimplemented!("https://github.com/heim-rs/heim/issues/141")
     }
 
@@ -21,11 +22,11 @@ impl User {
         unimplemented!("https://github.com/heim-rs/heim/issues/141")
     }
 
-    pub fn pid(&self) -> Pid {
+    pub fn pid(&self) -> Option<Pid> {
         unimplemented!("https://github.com/heim-rs/heim/issues/141")
     }
 
-    pub fn hostname(&self) -> &str {
+    pub fn host(&self) -> Option<&str> {
         unimplemented!("https://github.com/heim-rs/heim/issues/141")
     }
 
@@ -36,6 +37,10 @@ impl User {
     pub fn session_id(&self) -> SessionId {
         unimplemented!("https://github.com/heim-rs/heim/issues/141")
     }
+
+    pub fn started(&self) -> Time {
+        unimplemented!("https://github.com/heim-rs/heim/issues/141")
+    }
 }
 
 pub async fn users() -> Result<impl Stream<Item = Result<User>>> {
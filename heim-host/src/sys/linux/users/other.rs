@@ -1,11 +1,11 @@
-use std::ffi::CStr;
 use std::net::IpAddr;
 
 use heim_common::prelude::*;
+use heim_common::units::{time, Time};
 use heim_common::Pid;
 
 use crate::os::linux::SessionId;
-use crate::sys::unix::{from_ut_addr_v6, get_users};
+use crate::sys::unix::{from_ut_addr_v6, from_ut_str, get_users};
 
 #[derive(Debug)]
 pub struct User {
@@ -16,6 +16,7 @@ pub struct User {
     pid: libc::pid_t,
     addr: Option<IpAddr>,
     session_id: SessionId,
+    started: Time,
 }
 
 impl User {
@@ -23,20 +24,28 @@ impl User {
         &self.username
     }
 
-    pub fn terminal(&self) -> &str {
-        &self.terminal
+    pub fn terminal(&self) -> Option<&str> {
+        if self.terminal.is_empty() {
+            None
+        } else {
+            Some(&self.terminal)
+        }
     }
 
     pub fn id(&self) -> &str {
         &self.id
     }
 
-    pub fn pid(&self) -> Pid {
-        self.pid
+    pub fn pid(&self) -> Option<Pid> {
+        Some(Pid::from(self.pid))
     }
 
-    pub fn hostname(&self) -> &str {
-        &self.hostname
+    pub fn host(&self) -> Option<&str> {
+        if self.hostname.is_empty() {
+            None
+        } else {
+            Some(&self.hostname)
+        }
     }
 
     pub fn address(&self) -> Option<IpAddr> {
@@ -46,39 +55,27 @@ impl User {
     pub fn session_id(&self) -> SessionId {
         self.session_id
     }
+
+    pub fn started(&self) -> Time {
+        self.started
+    }
 }
 
 impl From<libc::utmpx> for User {
     fn from(entry: libc::utmpx) -> User {
-        let username = unsafe {
-            CStr::from_ptr(entry.ut_user.as_ptr())
-                .to_string_lossy()
-                .into_owned()
-        };
-        let terminal = unsafe {
-            CStr::from_ptr(entry.ut_line.as_ptr())
-                .to_string_lossy()
-                .into_owned()
-        };
-        let id = unsafe {
-            CStr::from_ptr(entry.ut_id.as_ptr())
-                .to_string_lossy()
-                .into_owned()
-        };
-        let hostname = unsafe {
-            CStr::from_ptr(entry.ut_host.as_ptr())
-                .to_string_lossy()
-                .into_owned()
-        };
+        // `ut_tv` fields are 32-bit wide for some targets
+        let started = Time::new::<time::second>(entry.ut_tv.tv_sec as f64)
+            + Time::new::<time::microsecond>(entry.ut_tv.tv_usec as f64);
 
         User {
-            username,
-            terminal,
-            id,
-            hostname,
+            username: from_ut_str(&entry.ut_user),
+            terminal: from_ut_str(&entry.ut_line),
+            id: from_ut_str(&entry.ut_id),
+            hostname: from_ut_str(&entry.ut_host),
             pid: entry.ut_pid,
             session_id: entry.ut_session,
             addr: from_ut_addr_v6(&entry.ut_addr_v6),
+            started,
         }
     }
 }
@@ -1,9 +1,9 @@
-use std::ffi::CStr;
-
 use heim_common::prelude::*;
+use heim_common::sys::IntoTime;
+use heim_common::units::Time;
 use heim_common::Pid;
 
-use super::super::unix::get_users;
+use super::super::unix::{from_ut_str, get_users};
 
 #[derive(Debug)]
 pub struct User {
@@ -12,6 +12,7 @@ pub struct User {
     id: String,
     hostname: String,
     pid: Pid,
+    started: Time,
 }
 
 impl User {
@@ -19,52 +20,44 @@ impl User {
         &self.username
     }
 
-    pub fn terminal(&self) -> &str {
-        &self.terminal
+    pub fn terminal(&self) -> Option<&str> {
+        if self.terminal.is_empty() {
+            None
+        } else {
+            Some(&self.terminal)
+        }
     }
 
     pub fn id(&self) -> &str {
         &self.id
     }
 
-    pub fn pid(&self) -> Pid {
-        self.pid
+    pub fn pid(&self) -> Option<Pid> {
+        Some(self.pid)
+    }
+
+    pub fn host(&self) -> Option<&str> {
+        if self.hostname.is_empty() {
+            None
+        } else {
+            Some(&self.hostname)
+        }
     }
 
-    pub fn hostname(&self) -> &str {
-        &self.hostname
+    pub fn started(&self) -> Time {
+        self.started
     }
 }
 
 impl From<libc::utmpx> for User {
     fn from(entry: libc::utmpx) -> User {
-        let username = unsafe {
-            CStr::from_ptr(entry.ut_user.as_ptr())
-                .to_string_lossy()
-                .into_owned()
-        };
-        let terminal = unsafe {
-            CStr::from_ptr(entry.ut_line.as_ptr())
-                .to_string_lossy()
-                .into_owned()
-        };
-        let id = unsafe {
-            CStr::from_ptr(entry.ut_id.as_ptr())
-                .to_string_lossy()
-                .into_owned()
-        };
-        let hostname = unsafe {
-            CStr::from_ptr(entry.ut_host.as_ptr())
-                .to_string_lossy()
-                .into_owned()
-        };
-
         User {
-            username,
-            terminal,
-            id,
-            hostname,
-            pid: entry.ut_pid,
+            username: from_ut_str(&entry.ut_user),
+            terminal: from_ut_str(&entry.ut_line),
+            id: from_ut_str(&entry.ut_id),
+            hostname: from_ut_str(&entry.ut_host),
+            pid: Pid::from(entry.ut_pid),
+            started: entry.ut_tv.into_time(),
         }
     }
 }
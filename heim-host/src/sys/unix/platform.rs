@@ -1,4 +1,5 @@
 use std::ffi::CStr;
+use std::io;
 use std::mem;
 use std::str::FromStr;
 
@@ -47,11 +48,14 @@ pub async fn platform() -> Result<Platform> {
             Err(Error::last_os_error().with_ffi("uname"))
         } else {
             let uts = uts.assume_init();
-            let raw_arch = CStr::from_ptr(uts.machine.as_ptr()).to_string_lossy();
-            let arch = arch_from_uname(&raw_arch).unwrap_or_else(|| {
-                log::error!("Unable to parse CPU architecture from \"{}\"", raw_arch);
-                Arch::Unknown
-            });
+            let arch = machine_arch(&uts);
+
+            let mut hostname = CStr::from_ptr(uts.nodename.as_ptr())
+                .to_string_lossy()
+                .into_owned();
+            if hostname.is_empty() {
+                hostname = gethostname()?;
+            }
 
             Ok(Platform {
                 system: CStr::from_ptr(uts.sysname.as_ptr())
@@ -63,15 +67,101 @@ pub async fn platform() -> Result<Platform> {
                 version: CStr::from_ptr(uts.version.as_ptr())
                     .to_string_lossy()
                     .into_owned(),
-                hostname: CStr::from_ptr(uts.nodename.as_ptr())
-                    .to_string_lossy()
-                    .into_owned(),
+                hostname,
                 arch,
             })
         }
     }
 }
 
+pub async fn architecture() -> Result<Arch> {
+    let arch = unsafe {
+        let mut uts = mem::MaybeUninit::<libc::utsname>::uninit();
+        let result = libc::uname(uts.as_mut_ptr());
+
+        if result != 0 {
+            return Err(Error::last_os_error().with_ffi("uname"));
+        }
+
+        machine_arch(&uts.assume_init())
+    };
+
+    // `uname(2)` reports `x86_64` machine for processes translated by Rosetta 2
+    #[cfg(target_os = "macos")]
+    {
+        if arch == Arch::X86_64 && is_translated() {
+            return Ok(Arch::AARCH64);
+        }
+    }
+
+    Ok(arch)
+}
+
+#[cfg(target_os = "macos")]
+fn is_translated() -> bool {
+    use heim_common::sys::macos::sysctl::sysctlbyname;
+
+    // Key is missing for the Intel-based systems, which are not translating anything
+    let result = unsafe { sysctlbyname::<libc::c_int>(b"sysctl.proc_translated\0") };
+
+    matches!(result, Ok(1))
+}
+
+fn machine_arch(uts: &libc::utsname) -> Arch {
+    let raw_arch = unsafe { CStr::from_ptr(uts.machine.as_ptr()) }.to_string_lossy();
+
+    arch_from_uname(&raw_arch).unwrap_or_else(|| {
+        log::error!("Unable to parse CPU architecture from \"{}\"", raw_arch);
+        Arch::Unknown
+    })
+}
+
+/// Upper bound for the `gethostname` buffer growth.
+const MAX_HOSTNAME_BUFFER: usize = 64 * 1024;
+
+fn gethostname() -> Result<String> {
+    // `HOST_NAME_MAX` is not available for all platforms, but 255 bytes is a POSIX limit;
+    // buffer is grown anyway if the hostname turns out to be longer than that
+    let mut buffer: Vec<u8> = vec![0; 256];
+
+    loop {
+        let result =
+            unsafe { libc::gethostname(buffer.as_mut_ptr() as *mut libc::c_char, buffer.len()) };
+        let truncated = if result != 0 {
+            let e = Error::last_os_error();
+            match e.raw_os_error() {
+                // glibc reports the truncation with `ENAMETOOLONG`, some other libcs with `EINVAL`
+                Some(libc::ENAMETOOLONG) | Some(libc::EINVAL) => true,
+                _ => return Err(e.with_ffi("gethostname")),
+            }
+        } else {
+            // Truncated hostname might be returned silently, either without the NUL terminator,
+            // or with the NUL terminator replacing the last byte
+            match buffer.iter().position(|byte| *byte == 0) {
+                Some(end) if end + 1 < buffer.len() => {
+                    buffer.truncate(end);
+                    return Ok(String::from_utf8_lossy(&buffer).into_owned());
+                }
+                _ => true,
+            }
+        };
+
+        if truncated && buffer.len() < MAX_HOSTNAME_BUFFER {
+            let len = buffer.len() * 2;
+            buffer.clear();
+            buffer.resize(len, 0);
+        } else {
+            let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+            return Err(Error::from(inner).with_message("Hostname is too long"));
+        }
+    }
+}
+
+pub async fn hostname() -> Result<String> {
+    gethostname()
+}
+
 /// Arch::from_str only handles the exact names used
 /// by the rust compiler (that's the policy of the platforms crate)
 /// However, uname -m has more varied output, which we need to detect
@@ -81,8 +171,36 @@ fn arch_from_uname(raw: &str) -> Option<Arch> {
     };
 
     match raw {
-        "armv7" | "armv7l" | "arm64" => Some(Arch::ARM),
+        "i386" | "i486" | "i586" | "i686" | "i86pc" => Some(Arch::X86),
+        "amd64" => Some(Arch::X86_64),
+        // macOS reports 64-bit ARM as `arm64`
+        "arm64" | "aarch64_be" => Some(Arch::AARCH64),
+        "ppc" => Some(Arch::POWERPC),
         "ppc64" | "ppc64le" => Some(Arch::POWERPC64),
+        "mips64" | "mips64el" => Some(Arch::MIPS64),
+        "mipsel" => Some(Arch::MIPS),
+        "riscv32" | "riscv64" => Some(Arch::RISCV),
+        // `armv7l`, `armv6l`, `armv8l` (32-bit mode of ARMv8 CPUs) and so on
+        other if other.starts_with("arm") => Some(Arch::ARM),
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::arch_from_uname;
+    use crate::Arch;
+
+    #[test]
+    fn test_arch_from_uname() {
+        assert_eq!(Some(Arch::X86_64), arch_from_uname("x86_64"));
+        assert_eq!(Some(Arch::X86_64), arch_from_uname("amd64"));
+        assert_eq!(Some(Arch::X86), arch_from_uname("i686"));
+        assert_eq!(Some(Arch::AARCH64), arch_from_uname("aarch64"));
+        assert_eq!(Some(Arch::AARCH64), arch_from_uname("arm64"));
+        assert_eq!(Some(Arch::ARM), arch_from_uname("armv7l"));
+        assert_eq!(Some(Arch::POWERPC64), arch_from_uname("ppc64le"));
+        assert_eq!(Some(Arch::RISCV), arch_from_uname("riscv64"));
+        assert_eq!(None, arch_from_uname("vax"));
+    }
+}
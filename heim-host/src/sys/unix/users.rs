@@ -1,4 +1,6 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::ptr;
+
 /// While utmp handling routines are all the same,
 /// `utmpx` struct varies from platform to platform.
 ///
@@ -16,8 +18,6 @@ use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 ///
 /// See also:
 ///  * https://github.com/libyal/dtformats/blob/master/documentation/Utmp%20login%20records%20format.asciidoc
-use std::ptr;
-
 pub fn get_users<T: From<libc::utmpx>>() -> Vec<T> {
     // TODO: Should we try to guess the capacity?
     let mut users = Vec::with_capacity(1);
@@ -55,3 +55,38 @@ pub(crate) fn from_ut_addr_v6(addr: &[i32; 4]) -> Option<IpAddr> {
         }
     }
 }
+
+/// Converts `utmpx` string field into the `String`.
+///
+/// Fields are not NUL-terminated if value occupies the whole field length.
+pub(crate) fn from_ut_str(chars: &[libc::c_char]) -> String {
+    let bytes = chars
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as u8)
+        .collect::<Vec<_>>();
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_ut_str;
+
+    #[test]
+    fn test_from_ut_str() {
+        let chars = [
+            b'r' as libc::c_char,
+            b'o' as _,
+            b'o' as _,
+            b't' as _,
+            0,
+            b'x' as _,
+        ];
+        assert_eq!("root", from_ut_str(&chars));
+
+        // Whole field is occupied, no NUL terminator
+        let chars = [b't' as libc::c_char, b't' as _, b'y' as _];
+        assert_eq!("tty", from_ut_str(&chars));
+    }
+}
@@ -10,13 +10,19 @@ use heim_common::units::{time, Time};
 pub async fn boot_time() -> Result<Time> {
     let mut filetime = mem::MaybeUninit::<minwindef::FILETIME>::uninit();
 
-    // `time` value is now a time amount from the January 1, 1601
-    let time = unsafe {
+    // `now` value is now a time amount from the January 1, 1601
+    let (now, ms) = unsafe {
         // https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemtimeasfiletime
         // function returns nothing and can't fail, apparently
         sysinfoapi::GetSystemTimeAsFileTime(filetime.as_mut_ptr());
-        filetime.assume_init().into_time()
+        // https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-gettickcount64
+        // seems not to be able to fail too
+        let ms = sysinfoapi::GetTickCount64();
+
+        (filetime.assume_init().into_time(), ms)
     };
+    // Time amount elapsed since the system was started
+    let elapsed = Time::new::<time::millisecond>(ms as f64);
 
     // Seconds amount between the "Windows epoch" (January 1, 1601)
     // and the Unix epoch (January 1, 1970).
@@ -24,5 +30,5 @@ pub async fn boot_time() -> Result<Time> {
     // as soon as `uom` will mark `Time::new` as a `const fn`
     let unix_epoch_delta = Time::new::<time::second>(11_644_473_600.0);
 
-    Ok(time - unix_epoch_delta)
+    Ok(now - unix_epoch_delta - elapsed)
 }
@@ -3,10 +3,8 @@ mod wrappers;
 
 mod boot_time;
 mod platform;
-mod uptime;
 mod users;
 
 pub use self::boot_time::*;
 pub use self::platform::*;
-pub use self::uptime::*;
 pub use self::users::*;
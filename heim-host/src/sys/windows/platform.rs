@@ -31,6 +31,24 @@ impl From<sysinfoapi::SYSTEM_INFO> for SystemInfo {
     }
 }
 
+impl SystemInfo {
+    fn architecture(&self) -> Arch {
+        match self.processor_arch {
+            // While there are other `PROCESSOR_ARCHITECTURE_*` consts exists,
+            // MSDN described only the following.
+            // https://docs.microsoft.com/ru-ru/windows/desktop/api/sysinfoapi/ns-sysinfoapi-_system_info#members
+            winnt::PROCESSOR_ARCHITECTURE_AMD64 => Arch::X86_64,
+            winnt::PROCESSOR_ARCHITECTURE_ARM => Arch::ARM,
+            winnt::PROCESSOR_ARCHITECTURE_ARM64 => Arch::AARCH64,
+            // TODO: Is it okay to match Ia64 to unknown arch?
+            // `platforms::Arch` enum does not have specific member for Itanium.
+            winnt::PROCESSOR_ARCHITECTURE_IA64 => Arch::Unknown,
+            winnt::PROCESSOR_ARCHITECTURE_INTEL => Arch::X86,
+            _ => Arch::Unknown,
+        }
+    }
+}
+
 pub struct Platform {
     sysinfo: SystemInfo,
     version: winnt::OSVERSIONINFOEXW,
@@ -88,19 +106,7 @@ impl Platform {
     }
 
     pub fn architecture(&self) -> Arch {
-        match self.sysinfo.processor_arch {
-            // While there are other `PROCESSOR_ARCHITECTURE_*` consts exists,
-            // MSDN described only the following.
-            // https://docs.microsoft.com/ru-ru/windows/desktop/api/sysinfoapi/ns-sysinfoapi-_system_info#members
-            winnt::PROCESSOR_ARCHITECTURE_AMD64 => Arch::X86_64,
-            winnt::PROCESSOR_ARCHITECTURE_ARM => Arch::ARM,
-            winnt::PROCESSOR_ARCHITECTURE_ARM64 => Arch::AARCH64,
-            // TODO: Is it okay to match Ia64 to unknown arch?
-            // `platforms::Arch` enum does not have specific member for Itanium.
-            winnt::PROCESSOR_ARCHITECTURE_IA64 => Arch::Unknown,
-            winnt::PROCESSOR_ARCHITECTURE_INTEL => Arch::X86,
-            _ => Arch::Unknown,
-        }
+        self.sysinfo.architecture()
     }
 }
 
@@ -126,6 +132,11 @@ fn get_native_system_info() -> SystemInfo {
     }
 }
 
+pub async fn architecture() -> Result<Arch> {
+    // `GetNativeSystemInfo` reports the OS architecture even for WOW64 processes
+    Ok(get_native_system_info().architecture())
+}
+
 /// Based on the `platform-info` crate source:
 /// https://github.com/uutils/platform-info/blob/8fa071f764d55bd8e41a96cf42009da9ae20a650/src/windows.rs
 fn rtl_get_version() -> winnt::OSVERSIONINFOEXW {
@@ -181,6 +192,10 @@ fn get_computer_domain() -> Result<String> {
     get_value_from_get_computer_name_ex_w(ComputerNameDnsDomain)
 }
 
+pub async fn hostname() -> Result<String> {
+    get_computer_name()
+}
+
 pub async fn platform() -> Result<Platform> {
     let version = rtl_get_version();
 
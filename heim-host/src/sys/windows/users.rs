@@ -2,12 +2,16 @@ use std::net::IpAddr;
 
 use super::wrappers::{Session, Sessions};
 use heim_common::prelude::*;
+use heim_common::units::Time;
+use heim_common::Pid;
 
 #[derive(Debug)]
 pub struct User {
     domain: String,
     username: String,
+    station_name: String,
     address: Option<IpAddr>,
+    started: Time,
 }
 
 impl User {
@@ -23,7 +27,9 @@ impl User {
         Ok(Some(User {
             domain,
             username,
+            station_name: info.station_name(),
             address: session.address()?,
+            started: info.logon_time(),
         }))
     }
 
@@ -35,6 +41,26 @@ impl User {
         self.username.as_str()
     }
 
+    pub fn terminal(&self) -> Option<&str> {
+        if self.station_name.is_empty() {
+            None
+        } else {
+            Some(&self.station_name)
+        }
+    }
+
+    pub fn host(&self) -> Option<&str> {
+        None
+    }
+
+    pub fn pid(&self) -> Option<Pid> {
+        None
+    }
+
+    pub fn started(&self) -> Time {
+        self.started
+    }
+
     pub fn address(&self) -> Option<&IpAddr> {
         self.address.as_ref()
     }
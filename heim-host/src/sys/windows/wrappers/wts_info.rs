@@ -5,6 +5,9 @@ use std::os::windows::ffi::OsStringExt;
 
 use winapi::shared::ntdef::{PVOID, WCHAR};
 
+use heim_common::sys::IntoTime;
+use heim_common::units::{time, Time};
+
 use super::super::bindings::wtsapi32;
 
 pub struct WtsInfo(pub wtsapi32::WTSINFOW);
@@ -22,6 +25,16 @@ impl WtsInfo {
         Self::from_wide(&self.0.UserName)
     }
 
+    pub fn station_name(&self) -> String {
+        Self::from_wide(&self.0.WinStationName)
+    }
+
+    pub fn logon_time(&self) -> Time {
+        // `LogonTime` is a time amount from the January 1, 1601
+        // and seconds amount between it and the Unix epoch should be subtracted
+        self.0.LogonTime.into_time() - Time::new::<time::second>(11_644_473_600.0)
+    }
+
     // TODO: Seems like it is used widely across `heim`, should be refactored
     fn from_wide(chars: &[WCHAR]) -> String {
         // TODO: Use `memchr` crate if possible?
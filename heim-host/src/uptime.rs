@@ -1,10 +1,29 @@
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use heim_common::prelude::*;
+use heim_common::units::time;
 
-use crate::{sys, Time};
+use crate::{boot_time, Time};
 
 /// Returns [Time] amount from the system boot.
 ///
+/// Uptime is calculated as a difference between the current system time
+/// and the [boot_time], so these two values are always consistent
+/// and time spent in the suspended state is included too.
+///
+/// Boot time is cached after the first call, see [boot_time] for details,
+/// so all the uptime values are sharing the same anchor point.
+///
 /// [Time]: ./struct.Time.html
+/// [boot_time]: ./fn.boot_time.html
 pub async fn uptime() -> Result<Time> {
-    sys::uptime().await
+    let boot_time = boot_time().await?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|_| {
+        let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+        Error::from(inner).with_message("System time is set before the UNIX epoch")
+    })?;
+
+    Ok(Time::new::<time::second>(now.as_secs_f64()) - boot_time)
 }
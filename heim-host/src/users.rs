@@ -1,6 +1,7 @@
 use std::fmt;
 
 use crate::sys;
+use crate::{Pid, Time};
 use heim_common::prelude::*;
 
 /// User currently connected to system.
@@ -12,23 +13,76 @@ pub struct User(sys::User);
 
 wrap!(User, sys::User);
 
+heim_common::serde!(User {
+    username,
+    terminal,
+    host,
+    pid,
+    started
+});
+
 impl User {
     /// Returns the name of user.
     pub fn username(&self) -> &str {
         self.as_ref().username()
     }
+
+    /// Returns the tty or pseudo-tty name associated with user session.
+    ///
+    /// For Windows it is a window station name, ex. `Console` or `RDP-Tcp#0`.
+    pub fn terminal(&self) -> Option<&str> {
+        self.as_ref().terminal()
+    }
+
+    /// Returns the remote host name user is logged in from.
+    ///
+    /// `None` is returned for local sessions.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method always returns `None`,
+    /// client address is available via [UserExt::address] method.
+    ///
+    /// [UserExt::address]: ./os/windows/trait.UserExt.html#tymethod.address
+    pub fn host(&self) -> Option<&str> {
+        self.as_ref().host()
+    }
+
+    /// Returns the `Pid` of the login process.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method always returns `None`.
+    pub fn pid(&self) -> Option<Pid> {
+        self.as_ref().pid()
+    }
+
+    /// Returns the [Time] when user session was started since the UNIX epoch.
+    ///
+    /// [Time]: ./struct.Time.html
+    pub fn started(&self) -> Time {
+        self.as_ref().started()
+    }
 }
 
 impl fmt::Debug for User {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("User")
             .field("username", &self.username())
+            .field("terminal", &self.terminal())
+            .field("host", &self.host())
+            .field("pid", &self.pid())
+            .field("started", &self.started())
             .finish()
     }
 }
 
 /// Returns a stream over [User] sessions currently connected to the system.
 ///
+/// For *nix systems sessions are loaded from the `utmpx` database,
+/// and only the user process records are yielded, so boot time,
+/// run level and dead process entries are skipped.
+///
 /// ## Compatibility
 ///
 /// For `musl` target environment this stream always will be empty,
@@ -0,0 +1,35 @@
+use heim_host as host;
+use heim_host::Arch;
+
+#[heim_derive::test]
+async fn test_architecture_is_known() {
+    let arch = host::architecture().await.unwrap();
+
+    assert_ne!(Arch::Unknown, arch);
+}
+
+#[heim_derive::test]
+async fn test_architecture_matches_platform() {
+    let arch = host::architecture().await.unwrap();
+    let platform = host::platform().await.unwrap();
+
+    // Rosetta 2 translation is not reflected by `uname(2)`
+    if cfg!(not(target_os = "macos")) {
+        assert_eq!(platform.architecture(), arch);
+    }
+}
+
+#[test]
+fn test_compiled_architecture() {
+    let expected = if cfg!(target_arch = "x86_64") {
+        Arch::X86_64
+    } else if cfg!(target_arch = "x86") {
+        Arch::X86
+    } else if cfg!(target_arch = "aarch64") {
+        Arch::AARCH64
+    } else {
+        return;
+    };
+
+    assert_eq!(expected, host::compiled_architecture());
+}
@@ -0,0 +1,21 @@
+use heim_common::units::time;
+use heim_host as host;
+
+#[heim_derive::test]
+async fn test_boot_time_cached() {
+    let first = host::boot_time().await.unwrap();
+    let second = host::boot_time().await.unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[heim_derive::test]
+async fn test_uptime_shares_boot_time() {
+    let boot_time = host::boot_time().await.unwrap();
+    let first = host::uptime().await.unwrap();
+    let second = host::uptime().await.unwrap();
+
+    assert!(second >= first);
+    assert!(first.get::<time::second>() > 0.0);
+    assert_eq!(boot_time, host::boot_time().await.unwrap());
+}
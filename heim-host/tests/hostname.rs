@@ -0,0 +1,16 @@
+use heim_host as host;
+
+#[heim_derive::test]
+async fn test_hostname() {
+    let hostname = host::hostname().await.unwrap();
+
+    assert!(!hostname.is_empty());
+}
+
+#[heim_derive::test]
+async fn test_hostname_matches_platform() {
+    let hostname = host::hostname().await.unwrap();
+    let platform = host::platform().await.unwrap();
+
+    assert_eq!(platform.hostname(), hostname);
+}
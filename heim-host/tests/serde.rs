@@ -0,0 +1,41 @@
+#![cfg(feature = "serde")]
+
+use heim_common::prelude::*;
+use heim_common::units::time;
+use heim_host as host;
+
+#[heim_derive::test]
+async fn serde_platform() {
+    let platform = host::platform().await.unwrap();
+
+    let value = serde_json::to_value(&platform).unwrap();
+
+    assert_eq!(value["system"].as_str(), Some(platform.system()));
+    assert_eq!(value["release"].as_str(), Some(platform.release()));
+    assert_eq!(value["version"].as_str(), Some(platform.version()));
+    assert_eq!(value["hostname"].as_str(), Some(platform.hostname()));
+    assert_eq!(
+        value["architecture"].as_str(),
+        Some(platform.architecture().as_str())
+    );
+}
+
+#[heim_derive::test]
+async fn serde_users() {
+    let users = host::users().await.unwrap();
+    ::futures::pin_mut!(users);
+    while let Some(user) = users.next().await {
+        let user = user.unwrap();
+
+        let value = serde_json::to_value(&user).unwrap();
+
+        assert_eq!(value["username"].as_str(), Some(user.username()));
+        assert_eq!(value["terminal"].as_str(), user.terminal());
+        assert_eq!(value["host"].as_str(), user.host());
+        assert_eq!(
+            value["started"].as_f64(),
+            Some(user.started().get::<time::second>())
+        );
+        assert!(value.get("pid").is_some());
+    }
+}
@@ -43,13 +43,17 @@ async fn smoke_users() {
         let user = user.unwrap();
 
         let _ = user.username();
+        let _ = user.terminal();
+        let _ = user.host();
+        let _ = user.pid();
+        let _ = user.started();
 
         #[cfg(target_os = "linux")]
         {
             use heim_host::os::linux::UserExt;
 
-            let _ = user.pid();
-            let _ = user.terminal();
+            let _ = UserExt::pid(&user);
+            let _ = UserExt::terminal(&user);
             let _ = user.id();
             let _ = user.hostname();
             let _ = user.address();
@@ -60,8 +64,8 @@ async fn smoke_users() {
         {
             use heim_host::os::macos::UserExt;
 
-            let _ = user.pid();
-            let _ = user.terminal();
+            let _ = UserExt::pid(&user);
+            let _ = UserExt::terminal(&user);
             let _ = user.id();
             let _ = user.hostname();
         }
@@ -75,3 +79,26 @@ async fn smoke_users() {
         }
     }
 }
+
+#[heim_derive::test]
+async fn smoke_uptime_consistency() {
+    let boot_time = host::boot_time().await.unwrap();
+    let uptime = host::uptime().await.unwrap();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64();
+
+    let delta = now - (boot_time + uptime).get::<time::second>();
+    assert!(delta.abs() < 1.0);
+}
+
+#[heim_derive::test]
+async fn smoke_architecture() {
+    let arch = host::architecture().await.unwrap();
+    println!(
+        "Architecture = {}, compiled for {}",
+        arch,
+        host::compiled_architecture()
+    );
+}
@@ -1,13 +1,13 @@
 use std::fmt;
 
 use heim_common::prelude::*;
-use heim_common::units::Information;
+use heim_common::units::{information, ratio, Information, Ratio};
 
 use crate::sys;
 
 /// Physical memory statistics.
 ///
-/// Only three metrics are guaranteed to be cross-platform,
+/// Metrics which are not provided by the platform are equal to zero,
 /// for other metrics see `MemoryExt` traits in the [os] submodules.
 ///
 /// [os]: ./os/index.html
@@ -15,6 +15,16 @@ pub struct Memory(sys::Memory);
 
 wrap!(Memory, sys::Memory);
 
+heim_common::serde!(Memory {
+    total,
+    available,
+    free,
+    used,
+    buffers,
+    cached,
+    shared
+});
+
 impl Memory {
     /// The total amount of physical memory.
     pub fn total(&self) -> Information {
@@ -23,6 +33,15 @@ impl Memory {
 
     /// The amount of memory that can be given instantly to processes
     /// without the system going into swap.
+    ///
+    /// This metric accounts for the reclaimable cache memory
+    /// and should be used to monitor the actual memory usage.
+    ///
+    /// ## Compatibility
+    ///
+    /// * Linux: `MemAvailable` value from the `/proc/meminfo`
+    /// * macOS: `total - used`, which consists of free pages and file cache pages
+    /// * Windows: `ullAvailPhys` value from the `GlobalMemoryStatusEx` call
     pub fn available(&self) -> Information {
         self.as_ref().available()
     }
@@ -32,6 +51,62 @@ impl Memory {
     pub fn free(&self) -> Information {
         self.as_ref().free()
     }
+
+    /// The amount of memory used.
+    ///
+    /// It is designed for informational purposes only and might vary vastly
+    /// from platform to platform.
+    ///
+    /// ## Compatibility
+    ///
+    /// * Linux: `total - available`, same as `free` command reports
+    /// * macOS: `(internal - purgeable) + wire + compressor` pages from the `host_statistics64` call,
+    ///   which matches the "Memory Used" value reported by Activity Monitor
+    /// * Windows: `total - available`
+    pub fn used(&self) -> Information {
+        self.as_ref().used()
+    }
+
+    /// The memory usage ratio, calculated as `(total - available) / total`,
+    /// same as `psutil` does.
+    ///
+    /// Use `get::<ratio::percent>()` to get the value in percents.
+    pub fn percent(&self) -> Ratio {
+        let total = self.total().get::<information::byte>();
+        let available = self.available().get::<information::byte>();
+        if total == 0 || available >= total {
+            return Ratio::new::<ratio::ratio>(0.0);
+        }
+
+        Ratio::new::<ratio::ratio>(((total - available) as f64 / total as f64) as f32)
+    }
+
+    /// The amount of memory used for file buffers.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux only, zero is returned for other platforms.
+    pub fn buffers(&self) -> Information {
+        self.as_ref().buffers()
+    }
+
+    /// The amount of memory used as cache memory.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux only, zero is returned for other platforms.
+    pub fn cached(&self) -> Information {
+        self.as_ref().cached()
+    }
+
+    /// The amount of memory that may be simultaneously accessed by multiple processes.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux only, zero is returned for other platforms.
+    pub fn shared(&self) -> Information {
+        self.as_ref().shared()
+    }
 }
 
 impl fmt::Debug for Memory {
@@ -40,6 +115,11 @@ impl fmt::Debug for Memory {
             .field("total", &self.total())
             .field("available", &self.available())
             .field("free", &self.free())
+            .field("used", &self.used())
+            .field("buffers", &self.buffers())
+            .field("cached", &self.cached())
+            .field("shared", &self.shared())
+            .field("percent", &self.percent())
             .finish()
     }
 }
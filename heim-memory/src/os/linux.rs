@@ -1,7 +1,7 @@
 //! Linux-specific extensions
 
 /// Reference: https://gitlab.com/procps-ng/procps/blob/master/proc/sysinfo.c
-use heim_common::units::{information, Information};
+use heim_common::units::Information;
 
 use crate::Memory;
 
@@ -36,17 +36,7 @@ pub trait MemoryExt {
 #[cfg(target_os = "linux")]
 impl MemoryExt for Memory {
     fn used(&self) -> Information {
-        let inner = self.as_ref();
-
-        let mut used = inner.total() - inner.free() - self.cached() - self.buffers();
-        if used <= Information::new::<information::byte>(0) {
-            // May be symptomatic of running within a LCX container where such
-            // values will be dramatically distorted over those of the host.
-            // Source: psutil
-            used = inner.total() - inner.free()
-        }
-
-        used
+        self.as_ref().used()
     }
 
     fn buffers(&self) -> Information {
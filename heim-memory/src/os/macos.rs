@@ -14,6 +14,13 @@ pub trait MemoryExt {
 
     /// Returns memory that is marked to always stay in RAM. It is never moved to disk.
     fn wire(&self) -> Information;
+
+    /// Returns memory occupied by the compressor,
+    /// i.e. the physical memory used to store compressed pages.
+    ///
+    /// It is counted as used memory, same as the "Compressed" value
+    /// reported by Activity Monitor or the "Pages occupied by compressor" by `vm_stat`.
+    fn compressed(&self) -> Information;
 }
 
 #[cfg(target_os = "macos")]
@@ -29,4 +36,8 @@ impl MemoryExt for crate::Memory {
     fn wire(&self) -> Information {
         self.as_ref().wire()
     }
+
+    fn compressed(&self) -> Information {
+        self.as_ref().compressed()
+    }
 }
@@ -7,14 +7,19 @@ use crate::sys;
 
 /// Swap memory statistics.
 ///
-/// Only three metrics are guaranteed to be cross-platform,
-/// for other metrics see [OS]-specific extensions.
-///
-/// [OS]: ./os/index.html
+/// If no swap is configured in the system, all metrics are equal to zero.
 pub struct Swap(sys::Swap);
 
 wrap!(Swap, sys::Swap);
 
+heim_common::serde!(Swap {
+    total,
+    used,
+    free,
+    sin,
+    sout
+});
+
 impl Swap {
     /// The total amount of swap memory
     pub fn total(&self) -> Information {
@@ -30,6 +35,24 @@ impl Swap {
     pub fn free(&self) -> Information {
         self.0.free()
     }
+
+    /// The cumulative amount of information the system has swapped in from disk.
+    ///
+    /// ## Compatibility
+    ///
+    /// Windows does not provide this value and zero is returned.
+    pub fn sin(&self) -> Information {
+        self.0.sin().unwrap_or_default()
+    }
+
+    /// The cumulative amount of information the system has swapped out to disk.
+    ///
+    /// ## Compatibility
+    ///
+    /// Windows does not provide this value and zero is returned.
+    pub fn sout(&self) -> Information {
+        self.0.sout().unwrap_or_default()
+    }
 }
 
 impl fmt::Debug for Swap {
@@ -38,6 +61,8 @@ impl fmt::Debug for Swap {
             .field("total", &self.total())
             .field("used", &self.used())
             .field("free", &self.free())
+            .field("sin", &self.sin())
+            .field("sout", &self.sout())
             .finish()
     }
 }
@@ -27,6 +27,15 @@ impl Memory {
     pub fn available(&self) -> Information {
         self.available
     }
+    pub fn used(&self) -> Information {
+        // Same as `free` from the `procps` and `psutil` are doing,
+        // `MemAvailable` might exceed `MemTotal` in the containers though
+        if self.available < self.total {
+            self.total - self.available
+        } else {
+            Information::new::<information::byte>(0)
+        }
+    }
     pub fn buffers(&self) -> Information {
         self.buffers
     }
@@ -130,6 +130,11 @@ impl Swap {
             }
         }
 
+        // Kernels built without swap support do not report any swap keys at all
+        if matched_lines == 0 {
+            return Ok(swap);
+        }
+
         // `FromStr` knows nothing about `/proc/meminfo`,
         // but at this point we are not tracking which exact field are we missing.
         // TODO: Rewrite parser and use `Error::missing_key` instead
@@ -148,3 +153,27 @@ pub async fn swap() -> Result<Swap> {
     })
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::information;
+
+    use super::{Swap, VmStat};
+
+    #[test]
+    fn test_parse_no_swap() {
+        const MEMINFO: &str = "MemTotal:       16318468 kB
+MemFree:         1373212 kB
+SwapTotal:             0 kB
+SwapFree:              0 kB
+";
+
+        let swap = Swap::parse_str(MEMINFO, VmStat::default()).unwrap();
+
+        assert_eq!(0, swap.total().get::<information::byte>());
+        assert_eq!(0, swap.used().get::<information::byte>());
+        assert_eq!(0, swap.free().get::<information::byte>());
+        assert_eq!(None, swap.sin());
+        assert_eq!(None, swap.sout());
+    }
+}
@@ -12,6 +12,7 @@ pub struct Memory {
     active: Information,
     inactive: Information,
     wire: Information,
+    compressed: Information,
 }
 
 impl Memory {
@@ -31,6 +32,18 @@ impl Memory {
         self.free
     }
 
+    pub fn buffers(&self) -> Information {
+        Information::new::<information::byte>(0)
+    }
+
+    pub fn cached(&self) -> Information {
+        Information::new::<information::byte>(0)
+    }
+
+    pub fn shared(&self) -> Information {
+        Information::new::<information::byte>(0)
+    }
+
     pub fn active(&self) -> Information {
         self.active
     }
@@ -42,36 +55,44 @@ impl Memory {
     pub fn wire(&self) -> Information {
         self.wire
     }
+
+    pub fn compressed(&self) -> Information {
+        self.compressed
+    }
 }
 
 pub async fn memory() -> Result<Memory> {
     let total = bindings::hw_memsize()?;
     let vm_stats = bindings::host_vm_info()?;
     let page_size = *PAGE_SIZE;
+    let pages = |count: u32| Information::new::<information::byte>(u64::from(count) * page_size);
 
     let total = Information::new::<information::byte>(total);
-    let available = Information::new::<information::byte>(
-        u64::from(vm_stats.active_count + vm_stats.free_count) * page_size,
-    );
-    let free = Information::new::<information::byte>(
-        u64::from(vm_stats.free_count - vm_stats.speculative_count) * page_size,
-    );
-    let used = Information::new::<information::byte>(
-        u64::from(vm_stats.active_count + vm_stats.wire_count) * page_size,
-    );
-    let active =
-        Information::new::<information::byte>(u64::from(vm_stats.active_count) * page_size);
-    let inactive =
-        Information::new::<information::byte>(u64::from(vm_stats.inactive_count) * page_size);
-    let wire = Information::new::<information::byte>(u64::from(vm_stats.wire_count) * page_size);
+    // Same as the "Memory Used" value reported by Activity Monitor:
+    // "App Memory" (anonymous pages which can't be purged) + "Wired Memory" + "Compressed".
+    // Compressed pages are not counted neither as active nor as inactive ones,
+    // so they would be treated as a free memory otherwise.
+    let app = vm_stats
+        .internal_page_count
+        .saturating_sub(vm_stats.purgeable_count);
+    let used = pages(app) + pages(vm_stats.wire_count) + pages(vm_stats.compressor_page_count);
+    // Everything else is either free or a file cache ("Cached Files" in Activity Monitor)
+    // which can be evicted without swapping.
+    let available = if used < total {
+        total - used
+    } else {
+        Information::new::<information::byte>(0)
+    };
+    let free = pages(vm_stats.free_count - vm_stats.speculative_count);
 
     Ok(Memory {
         total,
         available,
         free,
         used,
-        active,
-        inactive,
-        wire,
+        active: pages(vm_stats.active_count),
+        inactive: pages(vm_stats.inactive_count),
+        wire: pages(vm_stats.wire_count),
+        compressed: pages(vm_stats.compressor_page_count),
     })
 }
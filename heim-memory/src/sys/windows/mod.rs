@@ -24,6 +24,22 @@ impl Memory {
     pub fn free(&self) -> Information {
         self.available()
     }
+
+    pub fn used(&self) -> Information {
+        self.total() - self.available()
+    }
+
+    pub fn buffers(&self) -> Information {
+        Information::new::<information::byte>(0)
+    }
+
+    pub fn cached(&self) -> Information {
+        Information::new::<information::byte>(0)
+    }
+
+    pub fn shared(&self) -> Information {
+        Information::new::<information::byte>(0)
+    }
 }
 
 impl fmt::Debug for Memory {
@@ -54,6 +70,14 @@ impl Swap {
             self.total(),
         )
     }
+
+    pub fn sin(&self) -> Option<Information> {
+        None
+    }
+
+    pub fn sout(&self) -> Option<Information> {
+        None
+    }
 }
 
 impl fmt::Debug for Swap {
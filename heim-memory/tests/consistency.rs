@@ -0,0 +1,26 @@
+use heim_common::units::ratio;
+use heim_memory as memory;
+
+#[heim_derive::test]
+async fn memory_used_and_available_fit_total() {
+    let mem = memory::memory().await.unwrap();
+
+    assert!(mem.used() + mem.available() <= mem.total());
+}
+
+#[heim_derive::test]
+async fn memory_percent_in_range() {
+    let mem = memory::memory().await.unwrap();
+    let percent = mem.percent().get::<ratio::percent>();
+
+    assert!(percent > 0.0);
+    assert!(percent <= 100.0);
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn memory_used_matches_available() {
+    let mem = memory::memory().await.unwrap();
+
+    assert_eq!(mem.total(), mem.used() + mem.available());
+}
@@ -0,0 +1,61 @@
+#![cfg(target_os = "macos")]
+
+use std::process::Command;
+
+use heim_common::units::information;
+use heim_memory as memory;
+use heim_memory::os::macos::MemoryExt;
+
+/// Parses the `vm_stat` output, returning page size and the amount of pages
+/// for the `key` line, ex. `Pages occupied by compressor:  12345.`
+fn vm_stat(key: &str) -> (u64, u64) {
+    let output = Command::new("vm_stat").output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines();
+
+    // Mach Virtual Memory Statistics: (page size of 4096 bytes)
+    let header = lines.next().unwrap();
+    let page_size = header
+        .split("page size of ")
+        .nth(1)
+        .and_then(|rest| rest.split_whitespace().next())
+        .unwrap()
+        .parse()
+        .unwrap();
+    let pages = lines
+        .find(|line| line.starts_with(key))
+        .and_then(|line| line.rsplit(':').next())
+        .unwrap()
+        .trim()
+        .trim_end_matches('.')
+        .parse()
+        .unwrap();
+
+    (page_size, pages)
+}
+
+#[heim_derive::test]
+async fn memory_compressed_matches_vm_stat() {
+    let mem = memory::memory().await.unwrap();
+    let (page_size, pages) = vm_stat("Pages occupied by compressor");
+
+    let expected = page_size * pages;
+    let actual = mem.compressed().get::<information::byte>();
+    // Compressor size changes all the time, allowing it to drift a bit between the calls
+    let tolerance = 64 * 1024 * 1024 + expected / 10;
+    let difference = actual.max(expected) - actual.min(expected);
+    assert!(
+        difference <= tolerance,
+        "compressed memory {} is too far from `vm_stat` value {}",
+        actual,
+        expected
+    );
+}
+
+#[heim_derive::test]
+async fn memory_used_includes_compressed() {
+    let mem = memory::memory().await.unwrap();
+
+    assert!(mem.used() >= mem.wire() + mem.compressed());
+    assert_eq!(mem.total(), mem.used() + mem.available());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "serde")]
+
+use heim_common::units::information;
+use heim_memory as memory;
+
+#[heim_derive::test]
+async fn serde_memory() {
+    let mem = memory::memory().await.unwrap();
+
+    let value = serde_json::to_value(&mem).unwrap();
+
+    assert_eq!(
+        value["total"].as_u64(),
+        Some(mem.total().get::<information::byte>())
+    );
+    assert_eq!(
+        value["available"].as_u64(),
+        Some(mem.available().get::<information::byte>())
+    );
+    assert_eq!(
+        value["free"].as_u64(),
+        Some(mem.free().get::<information::byte>())
+    );
+    for field in &["used", "buffers", "cached", "shared"] {
+        assert!(value[field].is_u64(), "{} field is missing", field);
+    }
+}
+
+#[heim_derive::test]
+async fn serde_swap() {
+    let swap = memory::swap().await.unwrap();
+
+    let value = serde_json::to_value(&swap).unwrap();
+
+    assert_eq!(
+        value["total"].as_u64(),
+        Some(swap.total().get::<information::byte>())
+    );
+    assert_eq!(
+        value["used"].as_u64(),
+        Some(swap.used().get::<information::byte>())
+    );
+    assert_eq!(
+        value["free"].as_u64(),
+        Some(swap.free().get::<information::byte>())
+    );
+    for field in &["sin", "sout"] {
+        assert!(value[field].is_u64(), "{} field is missing", field);
+    }
+}
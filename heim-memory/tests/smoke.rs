@@ -11,15 +11,19 @@ async fn smoke_memory() {
     assert!(mem.total().get::<information::byte>() > 0);
     assert!(mem.available().get::<information::byte>() > 0);
     assert!(mem.free().get::<information::byte>() > 0);
+    assert!(mem.used() <= mem.total());
+    let _ = mem.buffers();
+    let _ = mem.cached();
+    let _ = mem.shared();
 
     #[cfg(target_os = "linux")]
     {
         use heim_memory::os::linux::MemoryExt;
 
-        let _ = mem.used();
-        let _ = mem.buffers();
-        let _ = mem.cached();
-        let _ = mem.shared();
+        let _ = MemoryExt::used(&mem);
+        let _ = MemoryExt::buffers(&mem);
+        let _ = MemoryExt::cached(&mem);
+        let _ = MemoryExt::shared(&mem);
         let _ = mem.active();
         let _ = mem.inactive();
     }
@@ -31,6 +35,7 @@ async fn smoke_memory() {
         let _ = mem.active();
         let _ = mem.inactive();
         let _ = mem.wire();
+        let _ = mem.compressed();
     }
 }
 
@@ -42,11 +47,17 @@ async fn smoke_swap() {
     assert!(swap.is_ok());
     let swap = swap.unwrap();
 
+    let _ = swap.total();
+    let _ = swap.used();
+    let _ = swap.free();
+    let _ = swap.sin();
+    let _ = swap.sout();
+
     #[cfg(not(windows))]
     {
         use heim_memory::os::SwapExt;
 
-        let _ = swap.sin();
-        let _ = swap.sout();
+        let _ = SwapExt::sin(&swap);
+        let _ = SwapExt::sout(&swap);
     }
 }
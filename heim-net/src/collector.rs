@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+
+use crate::{io_counters, IoCounters};
+
+/// Network device I/O counters change between two [NetIoCollector] measurements.
+///
+/// [NetIoCollector]: ./struct.NetIoCollector.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IoCountersDelta {
+    bytes_sent: u64,
+    bytes_recv: u64,
+    packets_sent: u64,
+    packets_recv: u64,
+    errors_sent: u64,
+    errors_recv: u64,
+    drop_recv: u64,
+    drop_sent: u64,
+}
+
+impl IoCountersDelta {
+    /// Returns information amount which was sent via this interface.
+    pub fn bytes_sent(&self) -> Information {
+        Information::new::<information::byte>(self.bytes_sent)
+    }
+
+    /// Returns information amount which was received via this interface.
+    pub fn bytes_recv(&self) -> Information {
+        Information::new::<information::byte>(self.bytes_recv)
+    }
+
+    /// Returns packets amount which was sent via this interface.
+    pub fn packets_sent(&self) -> u64 {
+        self.packets_sent
+    }
+
+    /// Returns packets amount which was received via this interface.
+    pub fn packets_recv(&self) -> u64 {
+        self.packets_recv
+    }
+
+    /// Returns errors amount which had occurred while sending data
+    /// via this interface.
+    pub fn errors_sent(&self) -> u64 {
+        self.errors_sent
+    }
+
+    /// Returns errors amount which had occurred while receiving data
+    /// via this interface.
+    pub fn errors_recv(&self) -> u64 {
+        self.errors_recv
+    }
+
+    /// Returns packets amount which were dropped while receiving them.
+    pub fn drop_recv(&self) -> u64 {
+        self.drop_recv
+    }
+
+    /// Returns packets amount which were dropped while sending them.
+    pub fn drop_sent(&self) -> u64 {
+        self.drop_sent
+    }
+
+    fn between(previous: &Snapshot, current: &Snapshot) -> IoCountersDelta {
+        IoCountersDelta {
+            bytes_sent: wrapping_delta(previous.bytes_sent, current.bytes_sent),
+            bytes_recv: wrapping_delta(previous.bytes_recv, current.bytes_recv),
+            packets_sent: wrapping_delta(previous.packets_sent, current.packets_sent),
+            packets_recv: wrapping_delta(previous.packets_recv, current.packets_recv),
+            errors_sent: wrapping_delta(previous.errors_sent, current.errors_sent),
+            errors_recv: wrapping_delta(previous.errors_recv, current.errors_recv),
+            drop_recv: wrapping_delta(previous.drop_recv, current.drop_recv),
+            drop_sent: wrapping_delta(previous.drop_sent, current.drop_sent),
+        }
+    }
+}
+
+/// Cumulative I/O counters values remembered by the collector between measurements.
+#[derive(Debug, Default, Clone, Copy)]
+struct Snapshot {
+    bytes_sent: u64,
+    bytes_recv: u64,
+    packets_sent: u64,
+    packets_recv: u64,
+    errors_sent: u64,
+    errors_recv: u64,
+    drop_recv: u64,
+    drop_sent: u64,
+}
+
+impl From<&IoCounters> for Snapshot {
+    fn from(counters: &IoCounters) -> Snapshot {
+        Snapshot {
+            bytes_sent: counters.bytes_sent().get::<information::byte>(),
+            bytes_recv: counters.bytes_recv().get::<information::byte>(),
+            packets_sent: counters.packets_sent(),
+            packets_recv: counters.packets_recv(),
+            errors_sent: counters.errors_sent(),
+            errors_recv: counters.errors_recv(),
+            drop_recv: counters.drop_recv(),
+            drop_sent: counters.drop_sent(),
+        }
+    }
+}
+
+/// Stateful sampler of the network devices I/O counters.
+///
+/// Collector remembers the counters fetched by the previous call
+/// and returns how much they had changed since then for each network interface.
+///
+/// Some network drivers are exposing 32-bit counters only, which are wrapping
+/// pretty fast for the busy interfaces; counter decrease is considered as a wrap
+/// and corrected, so the returned deltas are never negative or unreasonably huge.
+///
+/// ## Example
+///
+/// ```rust
+/// # use heim_common::prelude::*;
+/// # use heim_net::NetIoCollector;
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> Result<()> {
+/// let mut collector = NetIoCollector::new();
+/// // Nothing to compare with yet, all deltas are zeros
+/// let _ = collector.measure().await?;
+///
+/// // Some time later
+/// for (interface, delta) in collector.measure().await? {
+///     println!("{}: {:?} received", interface, delta.bytes_recv());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct NetIoCollector {
+    last: HashMap<String, Snapshot>,
+}
+
+impl NetIoCollector {
+    /// Creates a new collector.
+    pub fn new() -> NetIoCollector {
+        NetIoCollector::default()
+    }
+
+    /// Returns I/O counters change for each network interface
+    /// since the previous call of this method.
+    ///
+    /// Interfaces, which were not seen by the previous call (including the first call at all),
+    /// have zero deltas, as there is nothing to compare with yet.
+    /// Interfaces, which are gone since the previous call, are forgotten.
+    pub async fn measure(&mut self) -> Result<HashMap<String, IoCountersDelta>> {
+        let counters = io_counters().await?;
+        let current = counters
+            .map_ok(|counters| (counters.interface().to_string(), (&counters).into()))
+            .try_collect::<HashMap<String, Snapshot>>()
+            .await?;
+
+        Ok(self.update(current))
+    }
+
+    fn update(&mut self, current: HashMap<String, Snapshot>) -> HashMap<String, IoCountersDelta> {
+        let deltas = current
+            .iter()
+            .map(|(interface, current)| {
+                let delta = match self.last.get(interface) {
+                    Some(previous) => IoCountersDelta::between(previous, current),
+                    None => IoCountersDelta::default(),
+                };
+
+                (interface.clone(), delta)
+            })
+            .collect();
+
+        self.last = current;
+
+        deltas
+    }
+}
+
+/// Calculates the counter change, taking its possible wrap into account.
+///
+/// Counter which was decreased and was fitting into 32 bits is assumed
+/// to be wrapped at `u32::MAX`; 64-bit counters are not realistically wrapping,
+/// so their decrease means that the counter was reset (ex. interface was re-created)
+/// and the current value is used as is.
+fn wrapping_delta(previous: u64, current: u64) -> u64 {
+    if current >= previous {
+        current - previous
+    } else if previous <= u64::from(u32::MAX) {
+        current + (u64::from(u32::MAX) - previous) + 1
+    } else {
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use heim_common::units::information;
+
+    use super::{wrapping_delta, NetIoCollector, Snapshot};
+
+    fn snapshot(bytes_recv: u64, packets_recv: u64) -> HashMap<String, Snapshot> {
+        let mut snapshot = HashMap::new();
+        let _ = snapshot.insert(
+            "eth0".to_string(),
+            Snapshot {
+                bytes_recv,
+                packets_recv,
+                ..Snapshot::default()
+            },
+        );
+
+        snapshot
+    }
+
+    #[test]
+    fn test_wrapping_delta() {
+        assert_eq!(0, wrapping_delta(10, 10));
+        assert_eq!(5, wrapping_delta(10, 15));
+        // 32-bit counter wrap
+        assert_eq!(11, wrapping_delta(u64::from(u32::MAX) - 5, 5));
+        assert_eq!(1, wrapping_delta(u64::from(u32::MAX), 0));
+        // 64-bit counter reset
+        assert_eq!(7, wrapping_delta(u64::from(u32::MAX) + 100, 7));
+    }
+
+    #[test]
+    fn test_collector_update() {
+        let mut collector = NetIoCollector::new();
+
+        let first = collector.update(snapshot(1_000, 10));
+        assert_eq!(0, first["eth0"].bytes_recv().get::<information::byte>());
+        assert_eq!(0, first["eth0"].packets_recv());
+
+        let second = collector.update(snapshot(1_500, 14));
+        assert_eq!(500, second["eth0"].bytes_recv().get::<information::byte>());
+        assert_eq!(4, second["eth0"].packets_recv());
+        assert_eq!(0, second["eth0"].bytes_sent().get::<information::byte>());
+
+        let wrapped = collector.update(snapshot(99, 20));
+        assert_eq!(
+            u64::from(u32::MAX) - 1_500 + 100,
+            wrapped["eth0"].bytes_recv().get::<information::byte>()
+        );
+        assert_eq!(6, wrapped["eth0"].packets_recv());
+
+        // Vanished interface is forgotten and starts from zero deltas again
+        assert!(collector.update(HashMap::new()).is_empty());
+        let reappeared = collector.update(snapshot(5_000, 50));
+        assert_eq!(
+            0,
+            reappeared["eth0"].bytes_recv().get::<information::byte>()
+        );
+    }
+}
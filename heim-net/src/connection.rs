@@ -0,0 +1,183 @@
+use std::fmt;
+use std::net::SocketAddr;
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+
+use crate::sys;
+
+/// Kind of the connections to load.
+///
+/// Used as a filter for [connections] function.
+///
+/// [connections]: ./fn.connections.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionKind {
+    /// TCP over IPv4
+    Tcp4,
+    /// TCP over IPv6
+    Tcp6,
+    /// UDP over IPv4
+    Udp4,
+    /// UDP over IPv6
+    Udp6,
+    /// All of the above
+    All,
+}
+
+impl ConnectionKind {
+    /// Checks if connection with the given `protocol` and IP version belongs to this kind.
+    ///
+    /// This method is considered to be an internal API
+    /// and should not be used by external parties.
+    #[doc(hidden)]
+    pub fn contains(self, protocol: Protocol, is_ipv6: bool) -> bool {
+        matches!(
+            (self, protocol, is_ipv6),
+            (ConnectionKind::All, _, _)
+                | (ConnectionKind::Tcp4, Protocol::Tcp, false)
+                | (ConnectionKind::Tcp6, Protocol::Tcp, true)
+                | (ConnectionKind::Udp4, Protocol::Udp, false)
+                | (ConnectionKind::Udp6, Protocol::Udp, true)
+        )
+    }
+}
+
+heim_common::serde! {
+    /// Transport protocol of the connection.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum Protocol {
+        /// Transmission Control Protocol
+        Tcp,
+        /// User Datagram Protocol
+        Udp,
+    }
+}
+
+heim_common::serde! {
+    /// TCP connection state, as defined by [RFC 793].
+    ///
+    /// [RFC 793]: https://tools.ietf.org/html/rfc793
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum TcpState {
+        /// Connection is established
+        Established,
+        /// Connection request was sent
+        SynSent,
+        /// Connection request was received
+        SynReceived,
+        /// Waiting for a connection termination request or for an acknowledgement of the sent one
+        FinWait1,
+        /// Waiting for a connection termination request from the remote side
+        FinWait2,
+        /// Waiting to be sure that the remote side received the termination acknowledgement
+        TimeWait,
+        /// Connection is closed
+        Closed,
+        /// Waiting for a connection termination request from the local user
+        CloseWait,
+        /// Waiting for an acknowledgement of the termination request sent
+        LastAck,
+        /// Listening for incoming connections
+        Listen,
+        /// Waiting for a connection termination acknowledgement from the remote side
+        Closing,
+    }
+}
+
+/// System-wide network connection.
+pub struct Connection(sys::Connection);
+
+wrap!(Connection, sys::Connection);
+
+heim_common::serde!(Connection {
+    protocol,
+    local_address,
+    remote_address,
+    state,
+    pid
+});
+
+impl Connection {
+    /// Returns connection transport protocol.
+    pub fn protocol(&self) -> Protocol {
+        self.as_ref().protocol()
+    }
+
+    /// Returns local address of the socket.
+    pub fn local_address(&self) -> SocketAddr {
+        self.as_ref().local_address()
+    }
+
+    /// Returns remote address of the socket.
+    ///
+    /// `None` is returned for listening and not connected sockets.
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.as_ref().remote_address()
+    }
+
+    /// Returns TCP connection state.
+    ///
+    /// Always `None` for UDP sockets.
+    pub fn state(&self) -> Option<TcpState> {
+        self.as_ref().state()
+    }
+
+    /// Returns PID of the process owning this socket.
+    ///
+    /// `None` is returned if the owner can't be determined,
+    /// for example, if socket belongs to other user process and current user
+    /// does not have enough permissions to inspect it.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux, sockets in the `TIME_WAIT` state are not owned by any process,
+    /// so `None` is always returned for them.
+    pub fn pid(&self) -> Option<Pid> {
+        self.as_ref().pid()
+    }
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("protocol", &self.protocol())
+            .field("local_address", &self.local_address())
+            .field("remote_address", &self.remote_address())
+            .field("state", &self.state())
+            .field("pid", &self.pid())
+            .finish()
+    }
+}
+
+/// Returns `None` for the unspecified remote address,
+/// which listening and not connected sockets have.
+///
+/// Not a part of the public API, shared with the `heim-process` crate.
+#[cfg(unix)]
+#[doc(hidden)]
+pub fn connected_address(address: SocketAddr) -> Option<SocketAddr> {
+    if address.ip().is_unspecified() && address.port() == 0 {
+        None
+    } else {
+        Some(address)
+    }
+}
+
+/// Returns a stream over the system-wide network [connections] of the given `kind`.
+///
+/// ## Compatibility
+///
+/// For Linux, connections are parsed from the `/proc/net/{tcp,tcp6,udp,udp6}` tables,
+/// and then joined with the socket inodes found during a single pass
+/// over the `/proc/<pid>/fd/` directories in order to resolve their owners [PIDs].
+///
+/// Windows implementation is missing and an empty stream is returned.
+///
+/// [connections]: struct.Connection.html
+/// [PIDs]: struct.Connection.html#method.pid
+pub async fn connections(kind: ConnectionKind) -> Result<impl Stream<Item = Result<Connection>>> {
+    let inner = sys::connections(kind).await?;
+
+    Ok(inner.map_ok(Into::into))
+}
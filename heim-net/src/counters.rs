@@ -6,10 +6,24 @@ use heim_common::units::Information;
 use crate::sys;
 
 /// Network device I/O counters.
+///
+/// All counters are cumulative since the interface was brought up.
 pub struct IoCounters(sys::IoCounters);
 
 wrap!(IoCounters, sys::IoCounters);
 
+heim_common::serde!(IoCounters {
+    interface,
+    bytes_sent,
+    bytes_recv,
+    packets_sent,
+    packets_recv,
+    errors_sent,
+    errors_recv,
+    drop_recv,
+    drop_sent
+});
+
 impl IoCounters {
     /// Returns network interface name.
     pub fn interface(&self) -> &str {
@@ -56,6 +70,11 @@ impl IoCounters {
     pub fn drop_recv(&self) -> u64 {
         self.as_ref().drop_recv()
     }
+
+    /// Returns packets amount which were dropped while sending them.
+    pub fn drop_sent(&self) -> u64 {
+        self.as_ref().drop_sent()
+    }
 }
 
 impl fmt::Debug for IoCounters {
@@ -69,12 +88,17 @@ impl fmt::Debug for IoCounters {
             .field("errors_sent", &self.errors_sent())
             .field("errors_recv", &self.errors_recv())
             .field("drop_recv", &self.drop_recv())
+            .field("drop_sent", &self.drop_sent())
             .finish()
     }
 }
 
 /// Returns a stream over the [IO counters] for each network interface.
 ///
+/// Loopback interface is included too and can be distinguished
+/// by its [name](struct.IoCounters.html#method.interface)
+/// (usually `lo` for Linux and `lo0` for macOS).
+///
 /// ## Compatibility
 ///
 /// Windows implementation is missing, see [related issue](https://github.com/heim-rs/heim/issues/26)
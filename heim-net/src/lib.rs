@@ -27,9 +27,13 @@
 pub mod os;
 mod sys;
 
+mod collector;
+mod connection;
 mod counters;
 mod nic;
 
+pub use self::collector::*;
+pub use self::connection::*;
 pub use self::counters::*;
 pub use self::nic::*;
 
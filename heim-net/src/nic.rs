@@ -2,21 +2,47 @@ use std::fmt;
 use std::net;
 
 use heim_common::prelude::*;
+use heim_common::units::InformationRate;
 
 use crate::sys;
 
-/// Network interface address.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[non_exhaustive]
-pub enum Address {
-    /// IPv4 Internet protocols
-    Inet(net::SocketAddrV4),
+heim_common::serde! {
+    /// Network interface address.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+    #[non_exhaustive]
+    pub enum Address {
+        /// IPv4 Internet protocols
+        Inet(net::SocketAddrV4),
 
-    /// IPv6 Internet protocols
-    Inet6(net::SocketAddrV6),
+        /// IPv6 Internet protocols
+        Inet6(net::SocketAddrV6),
 
-    /// Link level interface
-    Link(macaddr::MacAddr),
+        /// Link level interface
+        #[cfg_attr(feature = "serde", serde(with = "mac_address"))]
+        Link(macaddr::MacAddr),
+    }
+}
+
+/// `macaddr::MacAddr` is not implementing `serde` traits,
+/// so it is (de)serialized as a string, ex. `"AC:DE:48:23:45:67"`.
+#[cfg(feature = "serde")]
+mod mac_address {
+    use heim_common::serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        address: &macaddr::MacAddr,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(address)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<macaddr::MacAddr, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
 }
 
 // TODO: Consider implement `Address::to_family()` method
@@ -29,6 +55,21 @@ pub struct Nic(sys::Nic);
 
 wrap!(Nic, sys::Nic);
 
+heim_common::serde!(Nic {
+    name,
+    index,
+    address,
+    netmask,
+    broadcast,
+    destination,
+    is_up,
+    is_running,
+    is_loopback,
+    is_multicast,
+    mtu,
+    speed
+});
+
 impl Nic {
     /// Returns NIC name.
     pub fn name(&self) -> &str {
@@ -52,6 +93,15 @@ impl Nic {
         self.as_ref().netmask()
     }
 
+    /// Returns broadcast address if available.
+    ///
+    /// ## Compatibility
+    ///
+    /// Not available for Windows, `None` is returned.
+    pub fn broadcast(&self) -> Option<Address> {
+        self.as_ref().broadcast()
+    }
+
     /// Returns destination address if available.
     pub fn destination(&self) -> Option<Address> {
         self.as_ref().destination()
@@ -76,6 +126,33 @@ impl Nic {
     pub fn is_multicast(&self) -> bool {
         self.as_ref().is_multicast()
     }
+
+    /// Returns the maximum transmission unit of the interface in bytes, if available.
+    ///
+    /// ## Compatibility
+    ///
+    /// * Linux: `/sys/class/net/<name>/mtu` file
+    /// * macOS: `SIOCGIFMTU` ioctl
+    /// * Windows: `Mtu` field from the `GetAdaptersAddresses` call
+    pub fn mtu(&self) -> Option<u32> {
+        self.as_ref().mtu()
+    }
+
+    /// Returns the negotiated link speed, ex. 1 Gbit/s for gigabit Ethernet.
+    ///
+    /// `None` is returned if interface has no link (ex. cable is unplugged)
+    /// or if the speed is not known, which is common for the virtual
+    /// and wireless interfaces.
+    ///
+    /// ## Compatibility
+    ///
+    /// * Linux: `/sys/class/net/<name>/speed` file
+    /// * macOS: `ifi_baudrate` from the interface data,
+    ///   link state is checked with the `SIOCGIFMEDIA` ioctl
+    /// * Windows: `TransmitLinkSpeed` field from the `GetAdaptersAddresses` call
+    pub fn speed(&self) -> Option<InformationRate> {
+        self.as_ref().speed()
+    }
 }
 
 impl fmt::Debug for Nic {
@@ -84,19 +161,28 @@ impl fmt::Debug for Nic {
             .field("name", &self.name())
             .field("address", &self.address())
             .field("netmask", &self.netmask())
+            .field("broadcast", &self.broadcast())
             .field("destination", &self.destination())
             .field("is_up", &self.is_up())
             .field("is_loopback", &self.is_loopback())
             .field("is_multicast", &self.is_multicast())
+            .field("mtu", &self.mtu())
+            .field("speed", &self.speed())
             .finish()
     }
 }
 
 /// Returns a stream over the [Network Interface Cards].
 ///
-/// [Network Interface Cards]: struct.Nic.html
+/// NICs that have multiple addresses are enumerated several times,
+/// with a different [`address`](Nic::address) every time;
+/// MAC address of the NIC is yielded as a separate item with the [`Address::Link`] variant.
 ///
-/// Depending on your platform, NICs that have multiple addresses may be enumerated several times, with a different [`address`](Nic::address) every time.
+/// ## Compatibility
+///
+/// Link level addresses are not yielded for Windows.
+///
+/// [Network Interface Cards]: struct.Nic.html
 pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
     let inner = sys::nic().await?;
 
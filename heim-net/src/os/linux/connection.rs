@@ -0,0 +1,37 @@
+use heim_common::prelude::*;
+use heim_common::Pid;
+
+use crate::{sys, Connection, ConnectionKind};
+
+/// Linux-specific extension for [Connection].
+///
+/// [Connection]: ../../struct.Connection.html
+pub trait ConnectionExt {
+    /// Returns inode number of the socket.
+    fn inode(&self) -> u64;
+}
+
+#[cfg(target_os = "linux")]
+impl ConnectionExt for crate::Connection {
+    fn inode(&self) -> u64 {
+        self.as_ref().inode()
+    }
+}
+
+/// Returns stream which yields [connections] from the network namespace of process with given `pid`.
+///
+/// Owner PID is not resolved for the yielded connections.
+///
+/// **MUST** be used as `process::os::unix::ProcessExt::connections()`
+///
+/// [connections]: ../../struct.Connection.html
+#[doc(hidden)]
+#[cfg(target_os = "linux")]
+pub async fn connections_for_pid(
+    pid: Pid,
+    kind: ConnectionKind,
+) -> Result<impl Stream<Item = Result<Connection>>> {
+    let inner = sys::connections_for_pid(pid, kind).await?;
+
+    Ok(inner.map_ok(Into::into))
+}
@@ -2,8 +2,10 @@
 //!
 //! Available only for `cfg(target_os = "linux")`
 
+mod connection;
 mod counters;
 mod nic;
 
+pub use self::connection::*;
 pub use self::counters::*;
 pub use self::nic::*;
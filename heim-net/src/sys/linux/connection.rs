@@ -0,0 +1,337 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_common::Pid;
+use heim_runtime as rt;
+
+use crate::connection::connected_address;
+use crate::{ConnectionKind, Protocol, TcpState};
+
+/// Connection tables from the `net/` directory of the network namespace.
+const TABLES: [(&str, Protocol, bool); 4] = [
+    ("tcp", Protocol::Tcp, false),
+    ("tcp6", Protocol::Tcp, true),
+    ("udp", Protocol::Udp, false),
+    ("udp6", Protocol::Udp, true),
+];
+
+#[derive(Debug)]
+pub struct Connection {
+    protocol: Protocol,
+    local_address: SocketAddr,
+    remote_address: Option<SocketAddr>,
+    state: Option<TcpState>,
+    inode: u64,
+    pid: Option<Pid>,
+}
+
+impl Connection {
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    pub fn local_address(&self) -> SocketAddr {
+        self.local_address
+    }
+
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.remote_address
+    }
+
+    pub fn state(&self) -> Option<TcpState> {
+        self.state
+    }
+
+    pub fn pid(&self) -> Option<Pid> {
+        self.pid
+    }
+
+    pub fn inode(&self) -> u64 {
+        self.inode
+    }
+}
+
+// See `include/net/tcp_states.h`
+fn tcp_state(value: u8) -> Result<TcpState> {
+    match value {
+        0x01 => Ok(TcpState::Established),
+        0x02 => Ok(TcpState::SynSent),
+        0x03 | 0x0C => Ok(TcpState::SynReceived),
+        0x04 => Ok(TcpState::FinWait1),
+        0x05 => Ok(TcpState::FinWait2),
+        0x06 => Ok(TcpState::TimeWait),
+        0x07 => Ok(TcpState::Closed),
+        0x08 => Ok(TcpState::CloseWait),
+        0x09 => Ok(TcpState::LastAck),
+        0x0A => Ok(TcpState::Listen),
+        0x0B => Ok(TcpState::Closing),
+        other => {
+            let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+            Err(Error::from(inner).with_message(format!("Unknown TCP state {}", other)))
+        }
+    }
+}
+
+/// Single line from the `net/{tcp,udp}{,6}` tables.
+#[derive(Debug)]
+struct Entry {
+    local_address: SocketAddr,
+    remote_address: SocketAddr,
+    state: u8,
+    inode: u64,
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split_whitespace();
+        let _sl = parts.try_next()?;
+        let local_address = parts.try_next().and_then(parse_address)?;
+        let remote_address = parts.try_next().and_then(parse_address)?;
+        let state = parts
+            .try_next()
+            .and_then(|value| u8::from_str_radix(value, 16).map_err(Error::from))?;
+        let _queues = parts.try_next()?;
+        let _timer = parts.try_next()?;
+        let _retransmits = parts.try_next()?;
+        let _uid = parts.try_next()?;
+        let _timeout = parts.try_next()?;
+        let inode = parts.try_parse_next()?;
+
+        Ok(Entry {
+            local_address,
+            remote_address,
+            state,
+            inode,
+        })
+    }
+}
+
+/// Parses `0100007F:0035`-like address.
+///
+/// IP address is printed by kernel as a sequence of 32-bit words in host byte order,
+/// while the port is printed in a regular way.
+fn parse_address(value: &str) -> Result<SocketAddr> {
+    let invalid = || Error::from(io::Error::from(io::ErrorKind::InvalidData));
+    let mut parts = value.splitn(2, ':');
+    let ip = parts.next().ok_or_else(invalid)?;
+    let port = parts.next().ok_or_else(invalid)?;
+    let port = u16::from_str_radix(port, 16)?;
+
+    let mut octets = [0u8; 16];
+    let words = ip.len() / 8;
+    if (words != 1 && words != 4) || ip.len() % 8 != 0 {
+        return Err(invalid());
+    }
+    for (idx, chunk) in octets.chunks_mut(4).take(words).enumerate() {
+        let word = ip.get(idx * 8..idx * 8 + 8).ok_or_else(invalid)?;
+        let word = u32::from_str_radix(word, 16)?;
+        chunk.copy_from_slice(&word.to_ne_bytes());
+    }
+
+    if words == 1 {
+        let ip = Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]);
+        Ok(SocketAddr::new(ip.into(), port))
+    } else {
+        Ok(SocketAddr::new(Ipv6Addr::from(octets).into(), port))
+    }
+}
+
+/// Reads all connection tables of the given `kind` from the `root` directory.
+async fn read_tables(root: PathBuf, kind: ConnectionKind) -> Result<Vec<Connection>> {
+    let mut connections = Vec::new();
+
+    for &(name, protocol, is_ipv6) in TABLES.iter() {
+        if !kind.contains(protocol, is_ipv6) {
+            continue;
+        }
+
+        let path = root.join(name);
+        let contents = match rt::fs::read_to_string(&path).await {
+            Ok(contents) => contents,
+            // IPv6 might be disabled in the system
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(Error::from(e).with_file(path)),
+        };
+
+        // First line is a table header
+        for line in contents.lines().skip(1) {
+            let entry = Entry::from_str(line)?;
+            let state = match protocol {
+                Protocol::Tcp => Some(tcp_state(entry.state)?),
+                Protocol::Udp => None,
+            };
+
+            connections.push(Connection {
+                protocol,
+                local_address: entry.local_address,
+                remote_address: connected_address(entry.remote_address),
+                state,
+                inode: entry.inode,
+                pid: None,
+            });
+        }
+    }
+
+    Ok(connections)
+}
+
+/// Extracts the socket inode from the `/proc/<pid>/fd/<fd>` link target,
+/// which is looking like `socket:[12345]`.
+fn socket_inode(target: &Path) -> Option<u64> {
+    target
+        .to_str()?
+        .strip_prefix("socket:[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// Returns socket `inodes` mapped to the PIDs of processes which opened them.
+///
+/// All `/proc/<pid>/fd/` directories are scanned once, so the whole lookup
+/// takes `O(fds)` time instead of scanning them again for each socket.
+/// Scan stops as soon as all `inodes` are found.
+///
+/// Processes which can't be inspected (exited already or owned by other users)
+/// are silently skipped, so their sockets are missing from the result.
+fn socket_owners(mut inodes: HashSet<u64>) -> HashMap<u64, Pid> {
+    let mut owners = HashMap::with_capacity(inodes.len());
+    let entries = match fs::read_dir(rt::linux::procfs_root()) {
+        Ok(entries) => entries,
+        Err(..) => return owners,
+    };
+
+    for entry in entries.flatten() {
+        if inodes.is_empty() {
+            break;
+        }
+
+        let pid = match entry.file_name().to_str().map(str::parse::<Pid>) {
+            Some(Ok(pid)) => pid,
+            _ => continue,
+        };
+        let fds = match fs::read_dir(entry.path().join("fd")) {
+            Ok(fds) => fds,
+            Err(..) => continue,
+        };
+
+        for fd in fds.flatten() {
+            let inode = fs::read_link(fd.path())
+                .ok()
+                .and_then(|target| socket_inode(&target));
+            if let Some(inode) = inode {
+                // Socket might be shared between processes, first found owner is used
+                if inodes.remove(&inode) {
+                    let _ = owners.insert(inode, pid);
+                }
+            }
+        }
+    }
+
+    owners
+}
+
+pub async fn connections(kind: ConnectionKind) -> Result<impl Stream<Item = Result<Connection>>> {
+    let mut connections = read_tables(rt::linux::procfs_root().join("net"), kind).await?;
+
+    // Sockets in the `TIME_WAIT` state are not owned by any process
+    // and have the zero inode
+    let inodes = connections
+        .iter()
+        .map(|connection| connection.inode)
+        .filter(|inode| *inode != 0)
+        .collect::<HashSet<_>>();
+    if !inodes.is_empty() {
+        let owners = rt::spawn_blocking(move || socket_owners(inodes)).await;
+        for connection in connections.iter_mut() {
+            connection.pid = owners.get(&connection.inode).copied();
+        }
+    }
+
+    Ok(stream::iter(connections).map(Ok))
+}
+
+pub async fn connections_for_pid(
+    pid: Pid,
+    kind: ConnectionKind,
+) -> Result<impl Stream<Item = Result<Connection>>> {
+    let root = rt::linux::procfs_root().join(pid.to_string()).join("net");
+    let connections = read_tables(root, kind).await?;
+
+    Ok(stream::iter(connections).map(Ok))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use super::{parse_address, socket_inode, tcp_state, Entry};
+    use crate::TcpState;
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_ipv4_address() {
+        let address = parse_address("0100007F:0035").unwrap();
+
+        assert_eq!(
+            address,
+            SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 53)
+        );
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_ipv6_address() {
+        let address = parse_address("00000000000000000000000001000000:1F90").unwrap();
+
+        assert_eq!(address, SocketAddr::new(Ipv6Addr::LOCALHOST.into(), 8080));
+    }
+
+    #[test]
+    fn test_parse_invalid_address() {
+        assert!(parse_address("0100007F").is_err());
+        assert!(parse_address("0100:0035").is_err());
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_entry() {
+        let line = "   0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 20844 1 0000000000000000 100 0 0 10 0";
+        let entry = Entry::from_str(line).unwrap();
+
+        assert_eq!(
+            entry.local_address,
+            SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 631)
+        );
+        assert!(entry.remote_address.ip().is_unspecified());
+        assert_eq!(entry.state, 0x0A);
+        assert_eq!(entry.inode, 20844);
+    }
+
+    #[test]
+    fn test_tcp_state() {
+        assert_eq!(TcpState::Established, tcp_state(0x01).unwrap());
+        assert_eq!(TcpState::Listen, tcp_state(0x0A).unwrap());
+        assert_eq!(TcpState::SynReceived, tcp_state(0x0C).unwrap());
+        assert!(tcp_state(0xFF).is_err());
+    }
+
+    #[test]
+    fn test_socket_inode() {
+        assert_eq!(Some(20844), socket_inode(Path::new("socket:[20844]")));
+        assert_eq!(None, socket_inode(Path::new("pipe:[20844]")));
+        assert_eq!(None, socket_inode(Path::new("/dev/null")));
+        assert_eq!(None, socket_inode(Path::new("socket:[]")));
+    }
+}
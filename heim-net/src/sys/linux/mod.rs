@@ -1,3 +1,7 @@
+mod connection;
 mod counters;
+mod nic;
 
+pub use self::connection::*;
 pub use self::counters::*;
+pub use self::nic::*;
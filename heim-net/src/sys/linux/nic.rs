@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use heim_runtime as rt;
+
+use crate::sys::unix::Link;
+
+/// Reads the numeric attribute of the interface.
+///
+/// Reading might fail with `EINVAL` error for some attributes, ex. `speed` of the interface
+/// without a carrier, so any error is treated as a missing value.
+async fn read_attribute(path: PathBuf) -> Option<i64> {
+    let contents = rt::fs::read_to_string(path).await.ok()?;
+
+    contents.trim().parse().ok()
+}
+
+async fn link(name: &str) -> Link {
+    let root = rt::linux::sysfs_root().join("class/net").join(name);
+    let mtu = read_attribute(root.join("mtu")).await;
+    // Speed is in megabits per second and is `-1` for the interfaces with no link
+    let speed = read_attribute(root.join("speed")).await;
+
+    Link {
+        mtu: mtu.filter(|mtu| *mtu > 0).map(|mtu| mtu as u32),
+        speed: speed
+            .filter(|speed| *speed > 0)
+            .map(|speed| speed as u64 * 1_000_000),
+    }
+}
+
+/// Loads link properties for each of the interfaces `names`.
+pub async fn links(names: &[&str]) -> HashMap<String, Link> {
+    let mut links = HashMap::with_capacity(names.len());
+    for name in names {
+        let _ = links.insert(name.to_string(), link(name).await);
+    }
+
+    links
+}
@@ -1,3 +1,4 @@
+use std::ffi::CStr;
 use std::mem;
 use std::ptr;
 
@@ -153,3 +154,267 @@ pub unsafe fn net_pf_route() -> Result<Routes> {
         Err(Error::last_os_error().with_sysctl(name.as_ref()))
     }
 }
+
+// See `bsd/sys/sockio.h` and `bsd/net/if_media.h`,
+// `_IOWR('i', 51, struct ifreq)` and `_IOWR('i', 56, struct ifmediareq)` respectively.
+const SIOCGIFMTU: libc::c_ulong = 0xc020_6933;
+const SIOCGIFMEDIA: libc::c_ulong = 0xc030_6938;
+
+const IFM_AVALID: libc::c_int = 0x0000_0001;
+const IFM_ACTIVE: libc::c_int = 0x0000_0002;
+
+/// `struct ifreq` with the `ifr_mtu` member of the union.
+#[repr(C)]
+struct ifreq_mtu {
+    ifr_name: [libc::c_char; libc::IFNAMSIZ],
+    ifr_mtu: libc::c_int,
+    _padding: [u8; 12],
+}
+
+#[repr(C)]
+struct ifmediareq {
+    ifm_name: [libc::c_char; libc::IFNAMSIZ],
+    ifm_current: libc::c_int,
+    ifm_mask: libc::c_int,
+    ifm_status: libc::c_int,
+    ifm_active: libc::c_int,
+    ifm_count: libc::c_int,
+    ifm_ulist: *mut libc::c_int,
+}
+
+/// Datagram socket used for the interface ioctls, closed on drop.
+#[derive(Debug)]
+pub struct Socket(libc::c_int);
+
+impl Socket {
+    pub fn new() -> Result<Socket> {
+        let fd = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0) };
+        if fd < 0 {
+            Err(Error::last_os_error().with_ffi("socket"))
+        } else {
+            Ok(Socket(fd))
+        }
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        let _ = unsafe { libc::close(self.0) };
+    }
+}
+
+fn interface_name(name: &str) -> Result<[libc::c_char; libc::IFNAMSIZ]> {
+    let mut raw = [0; libc::IFNAMSIZ];
+    // Name should be NUL-terminated
+    if name.len() >= libc::IFNAMSIZ {
+        let inner = std::io::Error::from(std::io::ErrorKind::InvalidInput);
+        return Err(Error::from(inner).with_message(format!("Invalid interface name {}", name)));
+    }
+    for (dst, src) in raw.iter_mut().zip(name.bytes()) {
+        *dst = src as libc::c_char;
+    }
+
+    Ok(raw)
+}
+
+#[allow(trivial_casts)]
+pub fn interface_mtu(socket: &Socket, name: &str) -> Result<u32> {
+    let mut req = ifreq_mtu {
+        ifr_name: interface_name(name)?,
+        ifr_mtu: 0,
+        _padding: [0; 12],
+    };
+
+    let result = unsafe { libc::ioctl(socket.0, SIOCGIFMTU, &mut req as *mut ifreq_mtu) };
+    if result < 0 {
+        Err(Error::last_os_error().with_ffi("ioctl"))
+    } else {
+        Ok(req.ifr_mtu as u32)
+    }
+}
+
+/// Returns `true` if interface has an active link, as in case when the cable is plugged in.
+#[allow(trivial_casts)]
+pub fn interface_media_active(socket: &Socket, name: &str) -> Result<bool> {
+    let mut req = ifmediareq {
+        ifm_name: interface_name(name)?,
+        ifm_current: 0,
+        ifm_mask: 0,
+        ifm_status: 0,
+        ifm_active: 0,
+        ifm_count: 0,
+        ifm_ulist: ptr::null_mut(),
+    };
+
+    let result = unsafe { libc::ioctl(socket.0, SIOCGIFMEDIA, &mut req as *mut ifmediareq) };
+    if result < 0 {
+        return Err(Error::last_os_error().with_ffi("ioctl"));
+    }
+
+    // Link status is unknown if `IFM_AVALID` bit is not set
+    Ok(req.ifm_status & IFM_AVALID == 0 || req.ifm_status & IFM_ACTIVE != 0)
+}
+
+// Following declarations are used to parse the `net.inet.{tcp,udp}.pcblist_n` sysctl output,
+// see `bsd/netinet/in_pcb.h`, `bsd/sys/socketvar.h` and `bsd/netinet/tcp_var.h`.
+// Only the leading fields which are used by `heim` are declared.
+
+pub const XSO_SOCKET: u32 = 0x001;
+pub const XSO_RCVBUF: u32 = 0x002;
+pub const XSO_SNDBUF: u32 = 0x004;
+pub const XSO_STATS: u32 = 0x008;
+pub const XSO_INPCB: u32 = 0x010;
+pub const XSO_TCPCB: u32 = 0x020;
+
+pub const INP_IPV6: libc::c_uchar = 0x2;
+
+// See `bsd/netinet/tcp_fsm.h`
+pub const TCPS_CLOSED: libc::c_int = 0;
+pub const TCPS_LISTEN: libc::c_int = 1;
+pub const TCPS_SYN_SENT: libc::c_int = 2;
+pub const TCPS_SYN_RECEIVED: libc::c_int = 3;
+pub const TCPS_ESTABLISHED: libc::c_int = 4;
+pub const TCPS_CLOSE_WAIT: libc::c_int = 5;
+pub const TCPS_FIN_WAIT_1: libc::c_int = 6;
+pub const TCPS_CLOSING: libc::c_int = 7;
+pub const TCPS_LAST_ACK: libc::c_int = 8;
+pub const TCPS_FIN_WAIT_2: libc::c_int = 9;
+pub const TCPS_TIME_WAIT: libc::c_int = 10;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct xinpgen {
+    pub xig_len: u32,
+    pub xig_count: libc::c_uint,
+    pub xig_gen: u64,
+    pub xig_sogen: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct xgen_n {
+    pub xgn_len: u32,
+    pub xgn_kind: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct xsocket_n {
+    pub xso_len: u32,
+    pub xso_kind: u32,
+    pub xso_so: u64,
+    pub so_type: libc::c_short,
+    pub so_options: u32,
+    pub so_linger: libc::c_short,
+    pub so_state: libc::c_short,
+    pub so_pcb: u64,
+    pub xso_protocol: libc::c_int,
+    pub xso_family: libc::c_int,
+    pub so_qlen: libc::c_short,
+    pub so_incqlen: libc::c_short,
+    pub so_qlimit: libc::c_short,
+    pub so_timeo: libc::c_short,
+    pub so_error: libc::c_ushort,
+    pub so_pgid: libc::pid_t,
+    pub so_oobmark: u32,
+    pub so_uid: libc::uid_t,
+    pub so_last_pid: libc::pid_t,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct xinpcb_n {
+    pub xi_len: u32,
+    pub xi_kind: u32,
+    pub xi_inpp: u64,
+    pub inp_fport: libc::c_ushort,
+    pub inp_lport: libc::c_ushort,
+    pub inp_ppcb: u64,
+    pub inp_gencnt: u64,
+    pub inp_flags: libc::c_int,
+    pub inp_flow: u32,
+    pub inp_vflag: libc::c_uchar,
+    pub inp_ip_ttl: libc::c_uchar,
+    pub inp_ip_p: libc::c_uchar,
+    // `in_addr_4in6` or `in6_addr` unions,
+    // IPv4 address is stored in the last four bytes for the former one
+    pub inp_faddr: [u32; 4],
+    pub inp_laddr: [u32; 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct xtcpcb_n {
+    pub xt_len: u32,
+    pub xt_kind: u32,
+    pub t_segq: u64,
+    pub t_dupacks: libc::c_int,
+    pub t_timer: [libc::c_int; 4],
+    pub t_state: libc::c_int,
+}
+
+/// Reads the `T` struct from the `data` at `offset` position.
+///
+/// `None` is returned if there is not enough data to read the struct.
+pub fn read_struct<T: Copy>(data: &[u8], offset: usize) -> Option<T> {
+    let end = offset.checked_add(mem::size_of::<T>())?;
+    if end > data.len() {
+        return None;
+    }
+
+    let value = unsafe { ptr::read_unaligned(data.as_ptr().add(offset) as *const T) };
+
+    Some(value)
+}
+
+/// Fetches raw `net.inet.{tcp,udp}.pcblist_n` sysctl output.
+///
+/// ## Safety
+///
+/// It is up to caller to provide proper null-terminated C string for `key` argument,
+/// ex. `pcblist(b"net.inet.tcp.pcblist_n\0")`.
+pub unsafe fn pcblist(key: &[u8]) -> Result<Vec<u8>> {
+    let name = CStr::from_bytes_with_nul_unchecked(key);
+    let mut length: libc::size_t = 0;
+
+    let result = libc::sysctlbyname(
+        name.as_ptr(),
+        ptr::null_mut(),
+        &mut length,
+        ptr::null_mut(),
+        0,
+    );
+    if result != 0 {
+        return Err(Error::last_os_error().with_sysctlbyname(name));
+    }
+
+    let mut data: Vec<u8> = Vec::with_capacity(length);
+    let result = libc::sysctlbyname(
+        name.as_ptr(),
+        data.as_mut_ptr() as *mut libc::c_void,
+        &mut length,
+        ptr::null_mut(),
+        0,
+    );
+
+    if result == 0 {
+        data.set_len(length);
+        Ok(data)
+    } else {
+        Err(Error::last_os_error().with_sysctlbyname(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::{ifmediareq, ifreq_mtu};
+
+    #[test]
+    fn test_layout() {
+        // Sizes are encoded into the `SIOCGIFMTU` and `SIOCGIFMEDIA` values
+        assert_eq!(mem::size_of::<ifreq_mtu>(), 32);
+        assert_eq!(mem::size_of::<ifmediareq>(), 48);
+    }
+}
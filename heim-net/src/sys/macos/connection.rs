@@ -0,0 +1,189 @@
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+
+use super::bindings;
+use crate::connection::connected_address;
+use crate::{ConnectionKind, Protocol, TcpState};
+
+#[derive(Debug)]
+pub struct Connection {
+    protocol: Protocol,
+    local_address: SocketAddr,
+    remote_address: Option<SocketAddr>,
+    state: Option<TcpState>,
+    pid: Option<Pid>,
+}
+
+impl Connection {
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    pub fn local_address(&self) -> SocketAddr {
+        self.local_address
+    }
+
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.remote_address
+    }
+
+    pub fn state(&self) -> Option<TcpState> {
+        self.state
+    }
+
+    pub fn pid(&self) -> Option<Pid> {
+        self.pid
+    }
+}
+
+fn tcp_state(value: libc::c_int) -> Result<TcpState> {
+    match value {
+        bindings::TCPS_CLOSED => Ok(TcpState::Closed),
+        bindings::TCPS_LISTEN => Ok(TcpState::Listen),
+        bindings::TCPS_SYN_SENT => Ok(TcpState::SynSent),
+        bindings::TCPS_SYN_RECEIVED => Ok(TcpState::SynReceived),
+        bindings::TCPS_ESTABLISHED => Ok(TcpState::Established),
+        bindings::TCPS_CLOSE_WAIT => Ok(TcpState::CloseWait),
+        bindings::TCPS_FIN_WAIT_1 => Ok(TcpState::FinWait1),
+        bindings::TCPS_CLOSING => Ok(TcpState::Closing),
+        bindings::TCPS_LAST_ACK => Ok(TcpState::LastAck),
+        bindings::TCPS_FIN_WAIT_2 => Ok(TcpState::FinWait2),
+        bindings::TCPS_TIME_WAIT => Ok(TcpState::TimeWait),
+        other => {
+            let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+            Err(Error::from(inner).with_message(format!("Unknown TCP state {}", other)))
+        }
+    }
+}
+
+fn socket_address(addr: [u32; 4], port: libc::c_ushort, is_ipv6: bool) -> SocketAddr {
+    let ip: IpAddr = if is_ipv6 {
+        let mut octets = [0u8; 16];
+        for (chunk, word) in octets.chunks_mut(4).zip(addr.iter()) {
+            chunk.copy_from_slice(&word.to_ne_bytes());
+        }
+        Ipv6Addr::from(octets).into()
+    } else {
+        Ipv4Addr::from(u32::from_be(addr[3])).into()
+    };
+    // Port is stored in the network byte order
+    SocketAddr::new(ip, u16::from_be(port))
+}
+
+// Same to `ROUNDUP64` macro from the `netstat` sources
+fn round_up64(value: usize) -> usize {
+    if value > 0 {
+        1 + ((value - 1) | 7)
+    } else {
+        8
+    }
+}
+
+/// Parses `net.inet.{tcp,udp}.pcblist_n` sysctl output.
+///
+/// Output starts with a `xinpgen` struct, followed by the `xinpcb_n`, `xsocket_n`,
+/// `xsockbuf_n` (twice), `xsockstat_n` and `xtcpcb_n` (for TCP only) records
+/// for each socket, and ends with another `xinpgen` struct.
+fn parse_pcblist(data: &[u8], protocol: Protocol, kind: ConnectionKind) -> Result<Vec<Connection>> {
+    let invalid = || Error::from(io::Error::from(io::ErrorKind::InvalidData));
+    let header = bindings::read_struct::<bindings::xinpgen>(data, 0).ok_or_else(invalid)?;
+    let expected = match protocol {
+        Protocol::Tcp => {
+            bindings::XSO_SOCKET
+                | bindings::XSO_RCVBUF
+                | bindings::XSO_SNDBUF
+                | bindings::XSO_STATS
+                | bindings::XSO_INPCB
+                | bindings::XSO_TCPCB
+        }
+        Protocol::Udp => {
+            bindings::XSO_SOCKET
+                | bindings::XSO_RCVBUF
+                | bindings::XSO_SNDBUF
+                | bindings::XSO_STATS
+                | bindings::XSO_INPCB
+        }
+    };
+
+    let mut connections = Vec::new();
+    let mut offset = round_up64(header.xig_len as usize);
+    let mut seen = 0;
+    let mut inp = None;
+    let mut so = None;
+    let mut tp = None;
+
+    while let Some(item) = bindings::read_struct::<bindings::xgen_n>(data, offset) {
+        // Trailing `xinpgen` struct
+        if item.xgn_len as usize <= std::mem::size_of::<bindings::xinpgen>() {
+            break;
+        }
+
+        match item.xgn_kind {
+            bindings::XSO_INPCB => inp = bindings::read_struct::<bindings::xinpcb_n>(data, offset),
+            bindings::XSO_SOCKET => so = bindings::read_struct::<bindings::xsocket_n>(data, offset),
+            bindings::XSO_TCPCB => tp = bindings::read_struct::<bindings::xtcpcb_n>(data, offset),
+            _ => {}
+        }
+        seen |= item.xgn_kind;
+        offset += round_up64(item.xgn_len as usize);
+
+        if seen != expected {
+            continue;
+        }
+        seen = 0;
+
+        let (inp, so) = match (inp.take(), so.take()) {
+            (Some(inp), Some(so)) => (inp, so),
+            _ => return Err(invalid()),
+        };
+        let is_ipv6 = inp.inp_vflag & bindings::INP_IPV6 != 0;
+        if !kind.contains(protocol, is_ipv6) {
+            continue;
+        }
+        let state = match protocol {
+            Protocol::Tcp => {
+                let tp = tp.take().ok_or_else(invalid)?;
+                Some(tcp_state(tp.t_state)?)
+            }
+            Protocol::Udp => None,
+        };
+        let pid = if so.so_last_pid > 0 {
+            Some(Pid::from(so.so_last_pid))
+        } else {
+            None
+        };
+
+        connections.push(Connection {
+            protocol,
+            local_address: socket_address(inp.inp_laddr, inp.inp_lport, is_ipv6),
+            remote_address: connected_address(socket_address(
+                inp.inp_faddr,
+                inp.inp_fport,
+                is_ipv6,
+            )),
+            state,
+            pid,
+        });
+    }
+
+    Ok(connections)
+}
+
+pub async fn connections(kind: ConnectionKind) -> Result<impl Stream<Item = Result<Connection>>> {
+    let mut connections = Vec::new();
+
+    if kind.contains(Protocol::Tcp, false) || kind.contains(Protocol::Tcp, true) {
+        let data = unsafe { bindings::pcblist(b"net.inet.tcp.pcblist_n\0")? };
+        connections.extend(parse_pcblist(&data, Protocol::Tcp, kind)?);
+    }
+    if kind.contains(Protocol::Udp, false) || kind.contains(Protocol::Udp, true) {
+        let data = unsafe { bindings::pcblist(b"net.inet.udp.pcblist_n\0")? };
+        connections.extend(parse_pcblist(&data, Protocol::Udp, kind)?);
+    }
+
+    Ok(stream::iter(connections).map(Ok))
+}
@@ -42,6 +42,10 @@ impl IoCounters {
     pub fn drop_recv(&self) -> u64 {
         self.data.ifm_data.ifi_iqdrops
     }
+
+    pub fn drop_sent(&self) -> u64 {
+        self.data.ifm_snd_drops as u64
+    }
 }
 
 impl fmt::Debug for IoCounters {
@@ -1,4 +1,8 @@
 mod bindings;
+mod connection;
 mod counters;
+mod nic;
 
+pub use self::connection::*;
 pub use self::counters::*;
+pub use self::nic::*;
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use super::bindings::{self, net_pf_route, Socket};
+use crate::sys::unix::Link;
+
+/// Loads link speed for all interfaces, keyed by the interface index.
+fn baudrates() -> HashMap<u32, u64> {
+    match unsafe { net_pf_route() } {
+        Ok(routes) => routes
+            .map(|msg| (u32::from(msg.ifm_index), msg.ifm_data.ifi_baudrate))
+            .collect(),
+        Err(..) => HashMap::new(),
+    }
+}
+
+fn link(name: &str, baudrates: &HashMap<u32, u64>) -> Link {
+    let socket = match Socket::new() {
+        Ok(socket) => socket,
+        Err(..) => return Link::default(),
+    };
+    let mtu = bindings::interface_mtu(&socket, name).ok();
+
+    // Media status is not supported by all interfaces, ex. by the loopback one,
+    // in that case the link is considered to be active
+    let is_active = bindings::interface_media_active(&socket, name).unwrap_or(true);
+    let speed = if is_active {
+        let index = nix::net::if_::if_nametoindex(name).ok();
+        index
+            .and_then(|index| baudrates.get(&index).copied())
+            .filter(|baudrate| *baudrate > 0)
+    } else {
+        None
+    };
+
+    Link { mtu, speed }
+}
+
+/// Loads link properties for each of the interfaces `names`.
+pub async fn links(names: &[&str]) -> HashMap<String, Link> {
+    // Routing table dump contains all the interfaces at once
+    let baudrates = baudrates();
+
+    names
+        .iter()
+        .map(|name| (name.to_string(), link(name, &baudrates)))
+        .collect()
+}
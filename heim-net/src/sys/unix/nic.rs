@@ -7,11 +7,20 @@ use nix::net::if_::InterfaceFlags;
 use nix::sys::socket;
 
 use heim_common::prelude::*;
+use heim_common::units::{information_rate, InformationRate};
 
 use crate::Address;
 
+/// Link-level properties which are shared by all the addresses of the interface.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Link {
+    pub mtu: Option<u32>,
+    /// Link speed in bits per second.
+    pub speed: Option<u64>,
+}
+
 #[derive(Debug)]
-pub struct Nic(ifaddrs::InterfaceAddress);
+pub struct Nic(ifaddrs::InterfaceAddress, Link);
 
 impl Nic {
     pub fn name(&self) -> &str {
@@ -65,16 +74,43 @@ impl Nic {
     pub fn is_multicast(&self) -> bool {
         self.0.flags.contains(InterfaceFlags::IFF_MULTICAST)
     }
+
+    pub fn mtu(&self) -> Option<u32> {
+        self.1.mtu
+    }
+
+    pub fn speed(&self) -> Option<InformationRate> {
+        self.1
+            .speed
+            .map(InformationRate::new::<information_rate::bit_per_second>)
+    }
 }
 
 pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
-    let iter = ifaddrs::getifaddrs()?
-        .filter_map(|addr| {
-            if addr.address.is_some() {
-                Some(Ok(Nic(addr)))
-            } else {
-                None
-            }
+    let addrs = ifaddrs::getifaddrs()?
+        .filter(|addr| match addr.address {
+            // Addresses from other families (ex. `AF_NETLINK`) can't be represented
+            // as an `Address` and are skipped
+            Some(socket::SockAddr::Inet(..)) | Some(socket::SockAddr::Link(..)) => true,
+            _ => false,
+        })
+        .collect::<Vec<_>>();
+
+    // Interfaces are yielded once per address, so link properties are loaded once per name
+    let mut names = addrs
+        .iter()
+        .map(|addr| addr.interface_name.as_str())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    let links = crate::sys::links(&names).await;
+
+    let iter = addrs
+        .into_iter()
+        .map(|addr| {
+            let link = links.get(&addr.interface_name).copied().unwrap_or_default();
+
+            Ok(Nic(addr, link))
         })
         .collect::<Vec<_>>();
 
@@ -0,0 +1,37 @@
+use std::net::SocketAddr;
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+
+use crate::{ConnectionKind, Protocol, TcpState};
+
+/// Connections are not implemented for Windows yet,
+/// so the type is uninhabited and its methods can't be called.
+#[derive(Debug)]
+pub enum Connection {}
+
+impl Connection {
+    pub fn protocol(&self) -> Protocol {
+        match *self {}
+    }
+
+    pub fn local_address(&self) -> SocketAddr {
+        match *self {}
+    }
+
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        match *self {}
+    }
+
+    pub fn state(&self) -> Option<TcpState> {
+        match *self {}
+    }
+
+    pub fn pid(&self) -> Option<Pid> {
+        match *self {}
+    }
+}
+
+pub async fn connections(_kind: ConnectionKind) -> Result<impl Stream<Item = Result<Connection>>> {
+    Ok(stream::iter(vec![]))
+}
@@ -1,4 +1,5 @@
 use heim_common::prelude::*;
+use heim_common::units::{information_rate, InformationRate};
 
 use std::net::Ipv4Addr;
 use std::net::Ipv6Addr;
@@ -30,6 +31,8 @@ pub struct Nic {
     guid: String,
     friendly_name: String,
     is_up: bool,
+    mtu: Option<u32>,
+    speed: Option<u64>,
     address: Option<Address>,
     netmask: Option<Address>,
 }
@@ -123,6 +126,11 @@ impl Nic {
         self.netmask
     }
 
+    pub fn broadcast(&self) -> Option<Address> {
+        // TODO: we could implement something one day
+        None
+    }
+
     pub fn destination(&self) -> Option<Address> {
         // TODO: we could implement something one day
         None
@@ -152,6 +160,15 @@ impl Nic {
             _ => false,
         }
     }
+
+    pub fn mtu(&self) -> Option<u32> {
+        self.mtu
+    }
+
+    pub fn speed(&self) -> Option<InformationRate> {
+        self.speed
+            .map(InformationRate::new::<information_rate::bit_per_second>)
+    }
 }
 
 pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
@@ -206,6 +223,8 @@ pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
         let iface_guid_cstr;
         let iface_fname_ucstr;
         let is_up;
+        let mtu;
+        let speed;
         let mut cur_address;
 
         unsafe {
@@ -214,6 +233,8 @@ pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
             iface_fname_ucstr = UCStr::from_ptr_str(cur_iface.FriendlyName);
             cur_address = *(cur_iface.FirstUnicastAddress);
             is_up = cur_iface.OperStatus == IfOperStatusUp;
+            mtu = cur_iface.Mtu;
+            speed = cur_iface.TransmitLinkSpeed;
         }
         let iface_guid = iface_guid_cstr
             .to_str()
@@ -226,6 +247,10 @@ pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
             friendly_name: iface_friendly_name,
             guid: iface_guid,
             is_up,
+            // `ULONG_MAX` is reported for the interfaces without the MTU, as the loopback one
+            mtu: Some(mtu).filter(|mtu| *mtu > 0 && *mtu != ULONG::max_value()),
+            // Speed is unknown if it is `ULONG64_MAX` and meaningless if interface is not up
+            speed: Some(speed).filter(|speed| is_up && *speed > 0 && *speed != u64::max_value()),
             address: None,
             netmask: None,
         };
@@ -0,0 +1,48 @@
+use heim_common::prelude::*;
+use heim_common::units::information;
+use heim_net as net;
+
+#[heim_derive::test]
+async fn test_collector_first_measure() {
+    let mut collector = net::NetIoCollector::new();
+    let deltas = collector.measure().await.unwrap();
+
+    for delta in deltas.values() {
+        assert_eq!(&net::IoCountersDelta::default(), delta);
+    }
+}
+
+#[heim_derive::test]
+async fn test_collector_interfaces() {
+    let mut collector = net::NetIoCollector::new();
+    let first = collector.measure().await.unwrap();
+    let second = collector.measure().await.unwrap();
+    let counters = net::io_counters()
+        .await
+        .unwrap()
+        .map_ok(|counters| (counters.interface().to_string(), counters))
+        .try_collect::<std::collections::HashMap<_, _>>()
+        .await
+        .unwrap();
+
+    // Interfaces are rarely changing, but still might
+    for interface in second.keys().filter(|name| first.contains_key(*name)) {
+        let delta = &second[interface];
+        let total = match counters.get(interface) {
+            Some(total) => total,
+            None => continue,
+        };
+
+        // Changes between two back-to-back measurements can't exceed the cumulative counters
+        assert!(
+            delta.bytes_recv().get::<information::byte>()
+                <= total.bytes_recv().get::<information::byte>()
+        );
+        assert!(
+            delta.bytes_sent().get::<information::byte>()
+                <= total.bytes_sent().get::<information::byte>()
+        );
+        assert!(delta.packets_recv() <= total.packets_recv());
+        assert!(delta.packets_sent() <= total.packets_sent());
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(target_os = "linux")]
+
+use std::net::TcpListener;
+
+use heim_common::prelude::*;
+use heim_common::{Pid, RawPid};
+use heim_net as net;
+
+#[heim_derive::test]
+async fn test_connections_pid() -> Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+
+    let connections = net::connections(net::ConnectionKind::Tcp4).await?;
+    ::futures::pin_mut!(connections);
+    let mut found = None;
+    while let Some(connection) = connections.next().await {
+        let connection = connection?;
+        if connection.local_address() == address {
+            found = Some(connection);
+            break;
+        }
+    }
+
+    let connection = found.expect("Listening socket is missing");
+    assert_eq!(Some(net::TcpState::Listen), connection.state());
+    assert_eq!(
+        Some(Pid::from(std::process::id() as RawPid)),
+        connection.pid()
+    );
+
+    Ok(())
+}
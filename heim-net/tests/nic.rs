@@ -0,0 +1,38 @@
+use futures::TryStreamExt;
+
+use heim_net as net;
+
+#[cfg(unix)]
+#[heim_derive::test]
+async fn test_loopback_link() {
+    let nics = net::nic().await.unwrap();
+    let loopback = nics
+        .try_filter(|nic| futures::future::ready(nic.is_loopback()))
+        .try_next()
+        .await
+        .unwrap();
+
+    // Loopback interface might be missing in the sandboxed environments
+    if let Some(loopback) = loopback {
+        assert!(loopback.mtu().unwrap() > 0);
+        // Loopback interface has no physical link
+        #[cfg(target_os = "linux")]
+        assert!(loopback.speed().is_none());
+    }
+}
+
+#[heim_derive::test]
+async fn test_link_is_shared_by_addresses() {
+    let nics = net::nic()
+        .await
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    for nic in nics.iter() {
+        for other in nics.iter().filter(|other| other.name() == nic.name()) {
+            assert_eq!(nic.mtu(), other.mtu());
+        }
+    }
+}
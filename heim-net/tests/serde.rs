@@ -0,0 +1,71 @@
+#![cfg(feature = "serde")]
+
+use heim_common::prelude::*;
+use heim_common::units::information;
+use heim_net as net;
+
+#[heim_derive::test]
+async fn serde_io_counters() {
+    let counters = net::io_counters().await.unwrap();
+    ::futures::pin_mut!(counters);
+    while let Some(counter) = counters.next().await {
+        let counter = counter.unwrap();
+
+        let value = serde_json::to_value(&counter).unwrap();
+
+        assert_eq!(value["interface"].as_str(), Some(counter.interface()));
+        assert_eq!(
+            value["bytes_sent"].as_u64(),
+            Some(counter.bytes_sent().get::<information::byte>())
+        );
+        assert_eq!(
+            value["bytes_recv"].as_u64(),
+            Some(counter.bytes_recv().get::<information::byte>())
+        );
+        assert_eq!(value["packets_sent"].as_u64(), Some(counter.packets_sent()));
+        assert_eq!(value["packets_recv"].as_u64(), Some(counter.packets_recv()));
+        for field in &["errors_sent", "errors_recv", "drop_sent", "drop_recv"] {
+            assert!(value[field].is_u64(), "{} field is missing", field);
+        }
+    }
+}
+
+#[heim_derive::test]
+async fn serde_nic() {
+    let nics = net::nic().await.unwrap();
+    ::futures::pin_mut!(nics);
+    while let Some(nic) = nics.next().await {
+        let nic = nic.unwrap();
+
+        let value = serde_json::to_value(&nic).unwrap();
+
+        assert_eq!(value["name"].as_str(), Some(nic.name()));
+        assert_eq!(
+            serde_json::from_value::<net::Address>(value["address"].clone()).unwrap(),
+            nic.address()
+        );
+        assert_eq!(value["is_up"].as_bool(), Some(nic.is_up()));
+        assert_eq!(value["is_loopback"].as_bool(), Some(nic.is_loopback()));
+        for field in &[
+            "index",
+            "netmask",
+            "broadcast",
+            "destination",
+            "mtu",
+            "speed",
+        ] {
+            assert!(value.get(field).is_some(), "{} field is missing", field);
+        }
+    }
+}
+
+#[test]
+fn serde_link_address() {
+    let address = net::Address::Link([0xAC, 0xDE, 0x48, 0x23, 0x45, 0x67].into());
+
+    let json = serde_json::to_string(&address).unwrap();
+    assert_eq!(json, r#"{"Link":"AC:DE:48:23:45:67"}"#);
+
+    let restored: net::Address = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, address);
+}
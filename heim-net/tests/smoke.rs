@@ -22,9 +22,10 @@ async fn smoke_io_counters() -> Result<()> {
         let _ = counter.errors_sent();
         let _ = counter.errors_recv();
         let _ = counter.drop_recv();
+        let _ = counter.drop_sent();
 
         #[cfg(any(target_os = "linux", target_os = "windows"))]
-        let _ = counter.drop_sent();
+        let _ = IoCountersExt::drop_sent(&counter);
     }
 
     Ok(())
@@ -42,12 +43,15 @@ async fn smoke_nic() -> Result<()> {
         println!("  index: {:?}", iface.index());
         println!("  address: {:?}", iface.address());
         println!("  netmask: {:?}", iface.netmask());
+        println!("  broadcast: {:?}", iface.broadcast());
 
         println!("  destination: {:?}", iface.destination());
         println!("  is_up: {:?}", iface.is_up());
         println!("  is_running: {:?}", iface.is_running());
         println!("  is_loopback: {:?}", iface.is_loopback());
         println!("  is_multicast: {:?}", iface.is_multicast());
+        println!("  mtu: {:?}", iface.mtu());
+        println!("  speed: {:?}", iface.speed());
 
         #[cfg(target_os = "windows")]
         {
@@ -60,7 +64,7 @@ async fn smoke_nic() -> Result<()> {
         {
             use heim_net::os::linux::NicExt;
 
-            println!("  broadcast: {:?}", iface.broadcast());
+            println!("  broadcast: {:?}", NicExt::broadcast(&iface));
             println!("  is_broadcast: {:?}", iface.is_broadcast());
             println!("  is_point_to_point: {:?}", iface.is_point_to_point());
         }
@@ -69,7 +73,7 @@ async fn smoke_nic() -> Result<()> {
         {
             use heim_net::os::macos::NicExt;
 
-            println!("  broadcast: {:?}", iface.broadcast());
+            println!("  broadcast: {:?}", NicExt::broadcast(&iface));
             println!("  is_broadcast: {:?}", iface.is_broadcast());
             println!("  is_point_to_point: {:?}", iface.is_point_to_point());
         }
@@ -77,3 +81,27 @@ async fn smoke_nic() -> Result<()> {
 
     Ok(())
 }
+
+#[heim_derive::test]
+async fn smoke_connections() -> Result<()> {
+    let connections = net::connections(net::ConnectionKind::All).await?;
+    ::futures::pin_mut!(connections);
+    while let Some(connection) = connections.next().await {
+        let connection = connection?;
+
+        let _ = connection.protocol();
+        let _ = connection.local_address();
+        let _ = connection.remote_address();
+        let _ = connection.state();
+        let _ = connection.pid();
+
+        #[cfg(target_os = "linux")]
+        {
+            use heim_net::os::linux::ConnectionExt;
+
+            let _ = connection.inode();
+        }
+    }
+
+    Ok(())
+}
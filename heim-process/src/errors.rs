@@ -24,6 +24,47 @@ pub enum ProcessError {
     Load(Error),
     /// UnreadablePeb
     UnreadablePeb(Pid),
+    /// Process has no executable file, as the kernel threads.
+    NoExecutable(Pid),
+}
+
+impl ProcessError {
+    /// Returns pid of the process this error is related to.
+    ///
+    /// `None` is returned for the [Load] errors, as they are not bound to any process.
+    ///
+    /// [Load]: #variant.Load
+    pub fn pid(&self) -> Option<Pid> {
+        match self {
+            ProcessError::NoSuchProcess(pid)
+            | ProcessError::ZombieProcess(pid)
+            | ProcessError::AccessDenied(pid)
+            | ProcessError::UnreadablePeb(pid)
+            | ProcessError::NoExecutable(pid) => Some(*pid),
+            ProcessError::Load(..) => None,
+        }
+    }
+
+    /// Checks if this error is a transient one, which is expected to happen
+    /// while enumerating the processes and is usually skipped.
+    ///
+    /// Following errors are considered as transient:
+    ///
+    /// * [NoSuchProcess], as process might exit at any moment
+    /// * [ZombieProcess], as process had already exited, but was not reaped yet
+    /// * [AccessDenied], as some processes are not available for the current user
+    ///
+    /// [NoSuchProcess]: #variant.NoSuchProcess
+    /// [ZombieProcess]: #variant.ZombieProcess
+    /// [AccessDenied]: #variant.AccessDenied
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            ProcessError::NoSuchProcess(..)
+                | ProcessError::ZombieProcess(..)
+                | ProcessError::AccessDenied(..)
+        )
+    }
 }
 
 impl fmt::Display for ProcessError {
@@ -41,6 +82,9 @@ impl fmt::Display for ProcessError {
             ProcessError::UnreadablePeb(pid) => {
                 f.write_fmt(format_args!("Unable to read process PEB {}", pid))
             }
+            ProcessError::NoExecutable(pid) => {
+                f.write_fmt(format_args!("Process {} has no executable", pid))
+            }
             ProcessError::Load(e) => fmt::Display::fmt(e, f),
         }
     }
@@ -66,3 +110,49 @@ impl From<io::Error> for ProcessError {
         ProcessError::from(Error::from(e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::ProcessError;
+    use crate::Pid;
+
+    #[test]
+    fn test_pid() {
+        assert_eq!(
+            Some(Pid::new(1)),
+            ProcessError::NoSuchProcess(Pid::new(1)).pid()
+        );
+        assert_eq!(
+            Some(Pid::new(2)),
+            ProcessError::ZombieProcess(Pid::new(2)).pid()
+        );
+        assert_eq!(
+            Some(Pid::new(3)),
+            ProcessError::AccessDenied(Pid::new(3)).pid()
+        );
+        assert_eq!(
+            Some(Pid::new(4)),
+            ProcessError::UnreadablePeb(Pid::new(4)).pid()
+        );
+        assert_eq!(
+            Some(Pid::new(5)),
+            ProcessError::NoExecutable(Pid::new(5)).pid()
+        );
+
+        let e = io::Error::from(io::ErrorKind::Other);
+        assert_eq!(None, ProcessError::from(e).pid());
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(ProcessError::NoSuchProcess(Pid::new(1)).is_transient());
+        assert!(ProcessError::ZombieProcess(Pid::new(1)).is_transient());
+        assert!(ProcessError::AccessDenied(Pid::new(1)).is_transient());
+        assert!(!ProcessError::NoExecutable(Pid::new(1)).is_transient());
+
+        let e = io::Error::from(io::ErrorKind::Other);
+        assert!(!ProcessError::from(e).is_transient());
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use heim_common::prelude::*;
+
+use crate::{
+    processes, processes_with_info, Process, ProcessError, ProcessResult, ProcessStreamExt,
+};
+
+/// Returns a stream over the processes with the `name` given.
+///
+/// Names are compared exactly with the [ProcessInfo::name] values,
+/// which are loaded in batch by the [processes_with_info] function,
+/// so no extra calls are made for each process.
+///
+/// Processes which can't be inspected or which exited during the search are skipped.
+///
+/// ## Compatibility
+///
+/// On macOS process names are truncated by the kernel to 16 characters,
+/// so the longer `name` never matches; use [find_by_exe] in that case.
+///
+/// On Linux names are truncated to 15 characters too, but they are restored
+/// from the process command line, same as the [Process::name] does.
+///
+/// [ProcessInfo::name]: ./struct.ProcessInfo.html#method.name
+/// [processes_with_info]: ./fn.processes_with_info.html
+/// [find_by_exe]: ./fn.find_by_exe.html
+/// [Process::name]: ./struct.Process.html#method.name
+pub async fn find_by_name(name: &str) -> Result<impl Stream<Item = ProcessResult<Process>>> {
+    let name = name.to_owned();
+    let processes = processes_with_info().await?;
+
+    let stream = processes
+        .skip_transient()
+        .try_filter_map(move |(process, info)| {
+            let found = if info.name() == name {
+                Some(process)
+            } else {
+                None
+            };
+
+            future::ok(found)
+        });
+
+    Ok(stream)
+}
+
+/// Returns a stream over the processes started from the executable at `path`.
+///
+/// Paths are compared exactly with the [Process::exe] values,
+/// so `path` should be an absolute one with all symlinks resolved.
+///
+/// Unlike [find_by_name], executable path is loaded for each process separately.
+/// Processes which can't be inspected, which exited during the search
+/// or which have no executable (as the kernel threads) are skipped.
+///
+/// [Process::exe]: ./struct.Process.html#method.exe
+/// [find_by_name]: ./fn.find_by_name.html
+pub async fn find_by_exe<T>(path: T) -> Result<impl Stream<Item = ProcessResult<Process>>>
+where
+    T: AsRef<Path>,
+{
+    let path = path.as_ref().to_path_buf();
+    let processes = processes().await?;
+
+    let stream = processes.skip_transient().try_filter_map(move |process| {
+        let path = path.clone();
+
+        async move {
+            match process.exe().await {
+                Ok(exe) if exe == path => Ok(Some(process)),
+                Ok(..) => Ok(None),
+                Err(ProcessError::NoExecutable(..)) => Ok(None),
+                Err(e) if e.is_transient() => Ok(None),
+                Err(e) => Err(e),
+            }
+        }
+    });
+
+    Ok(stream)
+}
@@ -28,15 +28,19 @@ mod errors;
 pub mod os;
 mod sys;
 
+mod find;
 mod pids;
 mod process;
+mod stream;
 
+pub use self::find::{find_by_exe, find_by_name};
 pub use self::pids::*;
 pub use self::process::*;
+pub use self::stream::{ProcessStreamExt, SkipTransient};
 
 pub use self::errors::{ProcessError, ProcessResult};
 pub use heim_common::units::Time;
-pub use heim_common::Pid;
+pub use heim_common::{Pid, RawPid};
 
 #[cfg(target_os = "linux")]
 pub use heim_net::IoCounters;
@@ -0,0 +1,39 @@
+use heim_common::units::{time, Information, Time};
+
+/// Resource limits applied to the process by its control groups.
+///
+/// Returned by [ProcessExt::cgroup_limits] method.
+///
+/// [ProcessExt::cgroup_limits]: ./trait.ProcessExt.html#tymethod.cgroup_limits
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CgroupLimits {
+    pub(crate) memory_max: Option<Information>,
+    pub(crate) cpu_max: Option<(Time, Time)>,
+}
+
+impl CgroupLimits {
+    /// Returns the memory usage limit.
+    ///
+    /// `None` is returned if memory usage is not limited.
+    pub fn memory_max(&self) -> Option<Information> {
+        self.memory_max
+    }
+
+    /// Returns the CPU bandwidth limit as a `(quota, period)` pair,
+    /// meaning that process group can consume up to `quota` of CPU time
+    /// during each `period`.
+    ///
+    /// `None` is returned if CPU bandwidth is not limited.
+    pub fn cpu_max(&self) -> Option<(Time, Time)> {
+        self.cpu_max
+    }
+
+    /// Returns the CPU bandwidth limit as an amount of CPUs,
+    /// ex. `1.5` if process group can fully use only one and a half CPUs.
+    ///
+    /// `None` is returned if CPU bandwidth is not limited.
+    pub fn cpus(&self) -> Option<f64> {
+        self.cpu_max
+            .map(|(quota, period)| quota.get::<time::second>() / period.get::<time::second>())
+    }
+}
@@ -2,9 +2,14 @@ use heim_common::units::Information;
 
 /// Linux-specific extension to process [Memory] information.
 ///
+/// All values are loaded from the `/proc/<pid>/statm` file, same as the `Memory` itself.
+///
 /// [Memory]: ../../struct.Memory.html
 pub trait MemoryExt {
     /// Returns the amount of memory that could be potentially shared with other processes.
+    ///
+    /// It is a sum of the resident file mappings and shared memory,
+    /// which are reported as `RssFile` and `RssShmem` in the `/proc/<pid>/status` file.
     fn shared(&self) -> Information;
 
     /// Returns TRS (*text resident set*) - the amount of memory devoted to executable code.
@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+use heim_common::units::Information;
+
+/// Process memory mapping.
+///
+/// Returned by [ProcessExt::memory_maps] method.
+///
+/// [ProcessExt::memory_maps]: ./trait.ProcessExt.html#tymethod.memory_maps
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryMap {
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) rss: Information,
+    pub(crate) pss: Information,
+    pub(crate) shared_clean: Information,
+    pub(crate) shared_dirty: Information,
+    pub(crate) private_clean: Information,
+    pub(crate) private_dirty: Information,
+    pub(crate) swap: Information,
+}
+
+impl MemoryMap {
+    /// Returns path to the mapped file.
+    ///
+    /// Special regions are represented with their pseudo-paths,
+    /// for example `[heap]`, `[stack]` or `[vdso]`.\
+    /// `None` is returned for anonymous mappings.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the amount of memory of this mapping currently resident in RAM.
+    pub fn rss(&self) -> Information {
+        self.rss
+    }
+
+    /// Returns the proportional set size, i.e. the process share of this mapping
+    /// resident memory, with the shared pages being split among all processes mapping them.
+    pub fn pss(&self) -> Information {
+        self.pss
+    }
+
+    /// Returns the amount of clean memory shared with other processes.
+    pub fn shared_clean(&self) -> Information {
+        self.shared_clean
+    }
+
+    /// Returns the amount of dirty memory shared with other processes.
+    pub fn shared_dirty(&self) -> Information {
+        self.shared_dirty
+    }
+
+    /// Returns the amount of clean memory private to this process.
+    pub fn private_clean(&self) -> Information {
+        self.private_clean
+    }
+
+    /// Returns the amount of dirty memory private to this process.
+    pub fn private_dirty(&self) -> Information {
+        self.private_dirty
+    }
+
+    /// Returns the amount of memory of this mapping swapped out.
+    pub fn swap(&self) -> Information {
+        self.swap
+    }
+}
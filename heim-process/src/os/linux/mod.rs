@@ -4,11 +4,19 @@ use heim_common::prelude::BoxStream;
 
 use crate::ProcessResult;
 
+mod cgroup;
 mod io_counters;
 mod memory;
+mod memory_map;
+mod rlimit;
+mod sched;
 
+pub use self::cgroup::CgroupLimits;
 pub use self::io_counters::IoCountersExt;
 pub use self::memory::MemoryExt;
+pub use self::memory_map::MemoryMap;
+pub use self::rlimit::Resource;
+pub use self::sched::SchedPolicy;
 
 /// Linux-specific extension to [Process]
 ///
@@ -25,6 +33,90 @@ pub trait ProcessExt {
     async fn net_io_counters(
         &self,
     ) -> ProcessResult<BoxStream<'_, ProcessResult<heim_net::IoCounters>>>;
+
+    /// Returns soft and hard limits for the process `resource` consumption.
+    ///
+    /// Unlimited values are represented as `u64::MAX` (`RLIM_INFINITY`).
+    async fn rlimit(&self, resource: Resource) -> ProcessResult<(u64, u64)>;
+
+    /// Sets soft and hard limits for the process `resource` consumption.
+    ///
+    /// `u64::MAX` value can be used to remove the limit (`RLIM_INFINITY`).
+    ///
+    /// Raising the hard limit requires the `CAP_SYS_RESOURCE` capability,
+    /// [AccessDenied] error is returned otherwise.
+    ///
+    /// [AccessDenied]: ../../enum.ProcessError.html#variant.AccessDenied
+    async fn set_rlimit(&self, resource: Resource, limits: (u64, u64)) -> ProcessResult<()>;
+
+    /// Returns the process scheduling policy and its static priority.
+    ///
+    /// Static priority is in a `1..=99` range for the [real-time] policies
+    /// and is always `0` for the other ones.
+    ///
+    /// ## Niceness
+    ///
+    /// Scheduling policy and the [niceness] are interacting with each other:
+    /// niceness affects the CPU time share only for the [Normal] and [Batch] policies,
+    /// it is ignored for the real-time and [Idle] ones, while the real-time processes
+    /// are always preferred over the non-real-time ones regardless of their niceness.
+    ///
+    /// [real-time]: ./enum.SchedPolicy.html#method.is_realtime
+    /// [niceness]: ../unix/trait.ProcessExt.html#tymethod.niceness
+    /// [Normal]: ./enum.SchedPolicy.html#variant.Normal
+    /// [Batch]: ./enum.SchedPolicy.html#variant.Batch
+    /// [Idle]: ./enum.SchedPolicy.html#variant.Idle
+    async fn sched_policy(&self) -> ProcessResult<(SchedPolicy, i32)>;
+
+    /// Sets the process scheduling policy and its static priority.
+    ///
+    /// `priority` should be in a `1..=99` range for the [real-time] policies and `0` otherwise.
+    /// Process niceness is preserved on the policy change,
+    /// see [sched_policy] for details on how they interact.
+    ///
+    /// Setting the real-time policy requires the `CAP_SYS_NICE` capability
+    /// (or a non-zero `RLIMIT_RTPRIO` limit), [AccessDenied] error is returned otherwise.
+    ///
+    /// [Deadline] policy can't be set with this method, as it requires extra parameters.
+    ///
+    /// [real-time]: ./enum.SchedPolicy.html#method.is_realtime
+    /// [sched_policy]: #tymethod.sched_policy
+    /// [AccessDenied]: ../../enum.ProcessError.html#variant.AccessDenied
+    /// [Deadline]: ./enum.SchedPolicy.html#variant.Deadline
+    async fn set_sched_policy(&self, policy: SchedPolicy, priority: i32) -> ProcessResult<()>;
+
+    /// Returns stream which yields this process [memory mappings].
+    ///
+    /// Mappings are parsed from the `/proc/{pid}/smaps` file incrementally,
+    /// as it can be quite large.
+    /// [AccessDenied] error is returned if process memory mappings
+    /// can't be inspected by the current user.
+    ///
+    /// [memory mappings]: ./struct.MemoryMap.html
+    /// [AccessDenied]: ../../enum.ProcessError.html#variant.AccessDenied
+    async fn memory_maps(&self) -> ProcessResult<BoxStream<'static, ProcessResult<MemoryMap>>>;
+
+    /// Checks if the process executable was removed or replaced on disk
+    /// after the process had started.
+    ///
+    /// Kernel marks the `/proc/{pid}/exe` link target with the `" (deleted)"` suffix
+    /// in that case, which is stripped by the [Process::exe] method.
+    ///
+    /// [Process::exe]: ../../struct.Process.html#method.exe
+    async fn exe_deleted(&self) -> ProcessResult<bool>;
+
+    /// Returns memory and CPU [limits] applied to the process by its control groups,
+    /// as in case when process is running in a container.
+    ///
+    /// Limits are read from the cgroup v2 `memory.max` and `cpu.max` files
+    /// under the `/sys/fs/cgroup/` directory, or from the cgroup v1 `memory.limit_in_bytes`,
+    /// `cpu.cfs_quota_us` and `cpu.cfs_period_us` files if the unified hierarchy is not used.
+    ///
+    /// Limits of the parent control groups are applied too,
+    /// so the most strict limits from the whole process cgroup path are returned.
+    ///
+    /// [limits]: ./struct.CgroupLimits.html
+    async fn cgroup_limits(&self) -> ProcessResult<CgroupLimits>;
 }
 
 #[cfg(target_os = "linux")]
@@ -37,4 +129,34 @@ impl ProcessExt for crate::Process {
 
         Ok(stream)
     }
+
+    async fn rlimit(&self, resource: Resource) -> ProcessResult<(u64, u64)> {
+        self.as_ref().rlimit(resource).await
+    }
+
+    async fn set_rlimit(&self, resource: Resource, limits: (u64, u64)) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
+        self.as_ref().set_rlimit(resource, limits).await
+    }
+
+    async fn sched_policy(&self) -> ProcessResult<(SchedPolicy, i32)> {
+        self.as_ref().sched_policy().await
+    }
+
+    async fn set_sched_policy(&self, policy: SchedPolicy, priority: i32) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
+        self.as_ref().set_sched_policy(policy, priority).await
+    }
+
+    async fn memory_maps(&self) -> ProcessResult<BoxStream<'static, ProcessResult<MemoryMap>>> {
+        self.as_ref().memory_maps().await
+    }
+
+    async fn exe_deleted(&self) -> ProcessResult<bool> {
+        self.as_ref().exe_deleted().await
+    }
+
+    async fn cgroup_limits(&self) -> ProcessResult<CgroupLimits> {
+        self.as_ref().cgroup_limits().await
+    }
 }
@@ -0,0 +1,61 @@
+//! Process resource limits.
+
+/// Resource which consumption can be limited for a process.
+///
+/// See [`prlimit(2)`] for more information about each resource.
+///
+/// [`prlimit(2)`]: http://man7.org/linux/man-pages/man2/prlimit.2.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Resource {
+    /// Maximum size of the process virtual memory in bytes (`RLIMIT_AS`).
+    AddressSpace,
+
+    /// Maximum size of a core file in bytes (`RLIMIT_CORE`).
+    Core,
+
+    /// Limit on the amount of CPU time in seconds (`RLIMIT_CPU`).
+    Cpu,
+
+    /// Maximum size of the process data segment in bytes (`RLIMIT_DATA`).
+    Data,
+
+    /// Maximum size of files in bytes that the process may create (`RLIMIT_FSIZE`).
+    FileSize,
+
+    /// Limit on the number of file locks (`RLIMIT_LOCKS`).
+    Locks,
+
+    /// Maximum number of bytes of memory that may be locked into RAM (`RLIMIT_MEMLOCK`).
+    MemLock,
+
+    /// Limit on the number of bytes that can be allocated
+    /// for POSIX message queues (`RLIMIT_MSGQUEUE`).
+    MsgQueue,
+
+    /// Ceiling to which the process nice value can be raised (`RLIMIT_NICE`).
+    Nice,
+
+    /// Maximum file descriptor number that can be opened by the process (`RLIMIT_NOFILE`).
+    NoFile,
+
+    /// Limit on the number of processes for the real user ID
+    /// of the calling process (`RLIMIT_NPROC`).
+    NProc,
+
+    /// Limit on the process resident set in bytes (`RLIMIT_RSS`).
+    Rss,
+
+    /// Ceiling on the real-time priority (`RLIMIT_RTPRIO`).
+    RtPrio,
+
+    /// Limit on the amount of CPU time in microseconds that a process
+    /// scheduled under a real-time policy may consume
+    /// without making a blocking system call (`RLIMIT_RTTIME`).
+    RtTime,
+
+    /// Limit on the number of signals that may be queued (`RLIMIT_SIGPENDING`).
+    SigPending,
+
+    /// Maximum size of the process stack in bytes (`RLIMIT_STACK`).
+    Stack,
+}
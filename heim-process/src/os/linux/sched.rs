@@ -0,0 +1,35 @@
+//! Process scheduling policies.
+
+/// Scheduling policy of a process.
+///
+/// See [`sched(7)`] for more information about each policy.
+///
+/// [`sched(7)`]: http://man7.org/linux/man-pages/man7/sched.7.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SchedPolicy {
+    /// Default time-sharing policy (`SCHED_OTHER`).
+    Normal,
+
+    /// First in, first out real-time policy (`SCHED_FIFO`).
+    Fifo,
+
+    /// Round-robin real-time policy (`SCHED_RR`).
+    Rr,
+
+    /// Time-sharing policy for the CPU-intensive batch processes (`SCHED_BATCH`).
+    Batch,
+
+    /// Policy for running very low priority background jobs (`SCHED_IDLE`).
+    Idle,
+
+    /// Sporadic task model deadline policy (`SCHED_DEADLINE`).
+    Deadline,
+}
+
+impl SchedPolicy {
+    /// Checks if this is one of the real-time policies,
+    /// which are using the static priority in a `1..=99` range.
+    pub fn is_realtime(&self) -> bool {
+        matches!(self, SchedPolicy::Fifo | SchedPolicy::Rr)
+    }
+}
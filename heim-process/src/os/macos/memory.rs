@@ -1,7 +1,24 @@
+use heim_common::units::Information;
+
 /// macOS-specific extension to process [Memory] information.
 ///
 /// [Memory]: ../../struct.Memory.html
 pub trait MemoryExt {
+    /// Returns the amount of memory that could be potentially shared with other processes.
+    ///
+    /// Always `None`, as macOS does not provide it for other processes.
+    fn shared(&self) -> Option<Information>;
+
+    /// Returns the amount of memory devoted to executable code.
+    ///
+    /// Always `None`, as macOS does not provide it for other processes.
+    fn text(&self) -> Option<Information>;
+
+    /// Returns the amount of physical memory devoted to other than executable code.
+    ///
+    /// Always `None`, as macOS does not provide it for other processes.
+    fn data(&self) -> Option<Information>;
+
     /// Returns the amount of page faults.
     fn faults(&self) -> u64;
 
@@ -10,6 +27,18 @@ pub trait MemoryExt {
 }
 
 impl MemoryExt for crate::Memory {
+    fn shared(&self) -> Option<Information> {
+        None
+    }
+
+    fn text(&self) -> Option<Information> {
+        None
+    }
+
+    fn data(&self) -> Option<Information> {
+        None
+    }
+
     fn faults(&self) -> u64 {
         self.as_ref().faults()
     }
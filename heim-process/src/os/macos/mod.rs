@@ -1,5 +1,36 @@
 //! macOS-specific extensions.
 
+use crate::ProcessResult;
+
 mod memory;
+mod snapshot;
 
 pub use self::memory::MemoryExt;
+pub use self::snapshot::ProcessSnapshot;
+
+/// macOS-specific extension to [Process].
+///
+/// [Process]: ../../struct.Process.html
+#[async_trait::async_trait]
+pub trait ProcessExt {
+    /// Returns the process information snapshot.
+    ///
+    /// Unlike calling [`Process::name`], [`Process::parent_pid`] and similar methods
+    /// one by one, which are querying the kernel each time, this method fetches
+    /// all the [snapshot] fields with a single `sysctl` call.
+    /// It is faster if multiple fields are needed at once and also guarantees
+    /// that all of them are describing the same process state.
+    ///
+    /// [`Process::name`]: ../../struct.Process.html#method.name
+    /// [`Process::parent_pid`]: ../../struct.Process.html#method.parent_pid
+    /// [snapshot]: ./struct.ProcessSnapshot.html
+    async fn snapshot(&self) -> ProcessResult<ProcessSnapshot>;
+}
+
+#[cfg(target_os = "macos")]
+#[async_trait::async_trait]
+impl ProcessExt for crate::Process {
+    async fn snapshot(&self) -> ProcessResult<ProcessSnapshot> {
+        self.as_ref().snapshot().await
+    }
+}
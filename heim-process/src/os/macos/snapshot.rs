@@ -0,0 +1,75 @@
+use heim_common::units::Time;
+
+use crate::os::unix::Uids;
+use crate::{Pid, Status};
+
+/// Consistent snapshot of the process information.
+///
+/// All the fields are fetched at once with a single `sysctl` call
+/// and therefore are describing the same process state.
+///
+/// Returned by [ProcessExt::snapshot] method.
+///
+/// [ProcessExt::snapshot]: ./trait.ProcessExt.html#tymethod.snapshot
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessSnapshot {
+    pid: Pid,
+    parent_pid: Pid,
+    name: String,
+    status: Status,
+    uids: Uids,
+    create_time: Time,
+}
+
+impl ProcessSnapshot {
+    #[cfg(target_os = "macos")]
+    pub(crate) fn new(
+        pid: Pid,
+        parent_pid: Pid,
+        name: String,
+        status: Status,
+        uids: Uids,
+        create_time: Time,
+    ) -> ProcessSnapshot {
+        ProcessSnapshot {
+            pid,
+            parent_pid,
+            name,
+            status,
+            uids,
+            create_time,
+        }
+    }
+
+    /// Returns the process pid.
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns the process parent pid.
+    pub fn parent_pid(&self) -> Pid {
+        self.parent_pid
+    }
+
+    /// Returns the process name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the process status.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns the process user IDs.
+    pub fn uids(&self) -> Uids {
+        self.uids
+    }
+
+    /// Returns the process creation time, expressed as a [Time] since the UNIX epoch.
+    ///
+    /// [Time]: ../../units/type.Time.html
+    pub fn create_time(&self) -> Time {
+        self.create_time
+    }
+}
@@ -0,0 +1,61 @@
+use std::net::SocketAddr;
+
+pub use heim_net::{ConnectionKind, Protocol, TcpState};
+
+/// Network connection opened by process.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Connection {
+    fd: libc::c_int,
+    protocol: Protocol,
+    local_address: SocketAddr,
+    remote_address: Option<SocketAddr>,
+    state: Option<TcpState>,
+}
+
+impl Connection {
+    #[cfg(unix)]
+    pub(crate) fn new(
+        fd: libc::c_int,
+        protocol: Protocol,
+        local_address: SocketAddr,
+        remote_address: Option<SocketAddr>,
+        state: Option<TcpState>,
+    ) -> Connection {
+        Connection {
+            fd,
+            protocol,
+            local_address,
+            remote_address,
+            state,
+        }
+    }
+
+    /// Returns socket file descriptor number.
+    pub fn fd(&self) -> libc::c_int {
+        self.fd
+    }
+
+    /// Returns connection transport protocol.
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    /// Returns local address of the socket.
+    pub fn local_address(&self) -> SocketAddr {
+        self.local_address
+    }
+
+    /// Returns remote address of the socket.
+    ///
+    /// `None` is returned for listening and not connected sockets.
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.remote_address
+    }
+
+    /// Returns TCP connection state.
+    ///
+    /// Always `None` for UDP sockets.
+    pub fn state(&self) -> Option<TcpState> {
+        self.state
+    }
+}
@@ -0,0 +1,36 @@
+use std::convert::TryFrom;
+
+use super::Signal;
+
+/// Unix-specific extension to [ExitStatus].
+///
+/// [ExitStatus]: ../../struct.ExitStatus.html
+pub trait ExitStatusExt {
+    /// Returns the signal which terminated the process (`WTERMSIG`),
+    /// if it was terminated by a signal (`WIFSIGNALED`).
+    ///
+    /// `None` is also returned for the signals which are not represented
+    /// by the [Signal] enum, raw signal number is available via the
+    /// `std::process::ExitStatus` converted from this status.
+    ///
+    /// [Signal]: ./enum.Signal.html
+    fn signal(&self) -> Option<Signal>;
+
+    /// Returns `true` if process was terminated by a signal
+    /// and produced a core dump (`WCOREDUMP`).
+    fn core_dumped(&self) -> bool;
+}
+
+#[cfg(unix)]
+impl ExitStatusExt for crate::ExitStatus {
+    fn signal(&self) -> Option<Signal> {
+        std::os::unix::process::ExitStatusExt::signal(&self.0)
+            .and_then(|signal| Signal::try_from(signal).ok())
+    }
+
+    fn core_dumped(&self) -> bool {
+        let raw = std::os::unix::process::ExitStatusExt::into_raw(self.0);
+
+        libc::WIFSIGNALED(raw) && libc::WCOREDUMP(raw)
+    }
+}
@@ -0,0 +1,75 @@
+/// Process user IDs.
+///
+/// Returned by [ProcessExt::uids] method.
+///
+/// [ProcessExt::uids]: ./trait.ProcessExt.html#tymethod.uids
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Uids {
+    real: u32,
+    effective: u32,
+    saved: u32,
+}
+
+impl Uids {
+    #[cfg(unix)]
+    pub(crate) fn new(real: u32, effective: u32, saved: u32) -> Uids {
+        Uids {
+            real,
+            effective,
+            saved,
+        }
+    }
+
+    /// Returns real user ID.
+    pub fn real(&self) -> u32 {
+        self.real
+    }
+
+    /// Returns effective user ID.
+    pub fn effective(&self) -> u32 {
+        self.effective
+    }
+
+    /// Returns saved set-user-ID.
+    pub fn saved(&self) -> u32 {
+        self.saved
+    }
+}
+
+/// Process group IDs.
+///
+/// Returned by [ProcessExt::gids] method.
+///
+/// [ProcessExt::gids]: ./trait.ProcessExt.html#tymethod.gids
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Gids {
+    real: u32,
+    effective: u32,
+    saved: u32,
+}
+
+impl Gids {
+    #[cfg(unix)]
+    pub(crate) fn new(real: u32, effective: u32, saved: u32) -> Gids {
+        Gids {
+            real,
+            effective,
+            saved,
+        }
+    }
+
+    /// Returns real group ID.
+    pub fn real(&self) -> u32 {
+        self.real
+    }
+
+    /// Returns effective group ID.
+    pub fn effective(&self) -> u32 {
+        self.effective
+    }
+
+    /// Returns saved set-group-ID.
+    pub fn saved(&self) -> u32 {
+        self.saved
+    }
+}
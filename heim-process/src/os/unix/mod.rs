@@ -1,9 +1,19 @@
 //! Unix-specific extensions.
 
+use heim_common::prelude::BoxStream;
+
 use crate::ProcessResult;
 
+mod connection;
+mod exit_status;
+mod ids;
+mod open_file;
 mod signal;
 
+pub use self::connection::{Connection, ConnectionKind, Protocol, TcpState};
+pub use self::exit_status::ExitStatusExt;
+pub use self::ids::{Gids, Uids};
+pub use self::open_file::{FileMode, OpenFile};
 pub use self::signal::Signal;
 
 /// Unix-specific extension to [Process].
@@ -19,12 +29,63 @@ pub trait ProcessExt {
 
     /// Set process niceness.
     async fn set_niceness(&self, value: libc::c_int) -> ProcessResult<()>;
+
+    /// Returns real, effective and saved user IDs of this process.
+    async fn uids(&self) -> ProcessResult<Uids>;
+
+    /// Returns real, effective and saved group IDs of this process.
+    async fn gids(&self) -> ProcessResult<Gids>;
+
+    /// Returns supplementary group IDs of this process.
+    ///
+    /// Empty `Vec` is returned if process has no supplementary groups.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux, groups are loaded from the `Groups` line of the `/proc/<pid>/status` file.
+    ///
+    /// For macOS, process credentials are holding up to 16 groups only,
+    /// so the list might be truncated for users which are members of more groups.
+    async fn groups(&self) -> ProcessResult<Vec<u32>>;
+
+    /// Returns login name of this process effective user.
+    ///
+    /// If there is no passwd entry for the user ID (which is common in containers),
+    /// numeric user ID is returned instead.
+    async fn username(&self) -> ProcessResult<String>;
+
+    /// Returns the number of file descriptors opened by this process.
+    async fn num_fds(&self) -> ProcessResult<u64>;
+
+    /// Returns stream which yields regular files opened by this process.
+    ///
+    /// Sockets, pipes and other file descriptors without a filesystem path
+    /// are not yielded, but they are still counted by [`num_fds`](#tymethod.num_fds).
+    ///
+    /// File open mode and current position are loaded from the `/proc/<pid>/fdinfo/<fd>` files
+    /// for Linux and from the `proc_pidfdinfo` call for macOS.
+    /// File descriptors closed during the enumeration are skipped.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Stream`. This behavior will change later.
+    async fn open_files(&self) -> ProcessResult<BoxStream<'_, ProcessResult<OpenFile>>>;
+
+    /// Returns stream which yields TCP and UDP sockets opened by this process,
+    /// filtered by the connection `kind`.
+    ///
+    /// Since `-> impl Trait` is not allowed yet in the trait methods,
+    /// this method returns boxed `Stream`. This behavior will change later.
+    async fn connections(
+        &self,
+        kind: ConnectionKind,
+    ) -> ProcessResult<BoxStream<'_, ProcessResult<Connection>>>;
 }
 
 #[cfg(unix)]
 #[async_trait::async_trait]
 impl ProcessExt for crate::Process {
     async fn signal(&self, signal: Signal) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
         self.as_ref().signal(signal).await
     }
 
@@ -33,6 +94,38 @@ impl ProcessExt for crate::Process {
     }
 
     async fn set_niceness(&self, value: libc::c_int) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
         self.as_ref().set_niceness(value).await
     }
+
+    async fn uids(&self) -> ProcessResult<Uids> {
+        self.as_ref().uids().await
+    }
+
+    async fn gids(&self) -> ProcessResult<Gids> {
+        self.as_ref().gids().await
+    }
+
+    async fn groups(&self) -> ProcessResult<Vec<u32>> {
+        self.as_ref().groups().await
+    }
+
+    async fn username(&self) -> ProcessResult<String> {
+        self.as_ref().username().await
+    }
+
+    async fn num_fds(&self) -> ProcessResult<u64> {
+        self.as_ref().num_fds().await
+    }
+
+    async fn open_files(&self) -> ProcessResult<BoxStream<'_, ProcessResult<OpenFile>>> {
+        self.as_ref().open_files().await
+    }
+
+    async fn connections(
+        &self,
+        kind: ConnectionKind,
+    ) -> ProcessResult<BoxStream<'_, ProcessResult<Connection>>> {
+        self.as_ref().connections(kind).await
+    }
 }
@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+
+/// Mode the file was opened with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileMode {
+    /// File is opened for reading only.
+    Read,
+    /// File is opened for writing only.
+    Write,
+    /// File is opened both for reading and writing.
+    ReadWrite,
+    /// File is opened for writing in the append mode, it might be readable too.
+    Append,
+}
+
+/// File opened by process.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OpenFile {
+    path: PathBuf,
+    fd: libc::c_int,
+    mode: Option<FileMode>,
+    position: Option<u64>,
+}
+
+impl OpenFile {
+    #[cfg(unix)]
+    pub(crate) fn new(
+        path: PathBuf,
+        fd: libc::c_int,
+        mode: Option<FileMode>,
+        position: Option<u64>,
+    ) -> OpenFile {
+        OpenFile {
+            path,
+            fd,
+            mode,
+            position,
+        }
+    }
+
+    /// Returns absolute path to the opened file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns file descriptor number.
+    pub fn fd(&self) -> libc::c_int {
+        self.fd
+    }
+
+    /// Returns mode the file was opened with, if it is known.
+    pub fn mode(&self) -> Option<FileMode> {
+        self.mode
+    }
+
+    /// Returns current file position (offset), if it is known.
+    pub fn position(&self) -> Option<u64> {
+        self.position
+    }
+}
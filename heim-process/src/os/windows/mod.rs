@@ -28,6 +28,7 @@ impl ProcessExt for crate::Process {
     }
 
     async fn set_priority(&self, value: Priority) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
         self.as_ref().set_priority(value).await
     }
 }
@@ -12,6 +12,20 @@ pub async fn pids() -> Result<impl Stream<Item = Result<Pid>>> {
 }
 
 /// Checks if the process with given `pid` exists.
+///
+/// This is a cheaper alternative to the [get] function,
+/// as no process information is loaded.
+///
+/// ## Compatibility
+///
+/// For *nix systems `kill(pid, 0)` call is used; if it fails with `EPERM` error,
+/// process exists but it is owned by some other user, so `true` is returned in that case.
+/// Negative pids are always considered as non-existing.
+///
+/// For Windows, processes which can't be opened due to insufficient permissions
+/// are considered as existing too.
+///
+/// [get]: ./fn.get.html
 pub async fn pid_exists(pid: Pid) -> ProcessResult<bool> {
     sys::pid_exists(pid).await
 }
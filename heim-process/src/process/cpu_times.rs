@@ -11,7 +11,24 @@ pub struct CpuTime(sys::CpuTime);
 
 wrap!(CpuTime, sys::CpuTime);
 
+heim_common::serde!(CpuTime {
+    user,
+    system,
+    children_user,
+    children_system
+});
+
 impl CpuTime {
+    /// Creates a new `CpuTime` from the user and system times.
+    ///
+    /// Mostly useful for testing the code which aggregates process CPU times
+    /// without the real processes; platform-specific values
+    /// provided by the OS extension traits are zeroed
+    /// and children CPU times are not available.
+    pub fn new(user: Time, system: Time) -> CpuTime {
+        sys::CpuTime::new(user, system).into()
+    }
+
     /// Returns amount of CPU time spent in user mode within the process.
     pub fn user(&self) -> Time {
         self.as_ref().user()
@@ -21,6 +38,34 @@ impl CpuTime {
     pub fn system(&self) -> Time {
         self.as_ref().system()
     }
+
+    /// Returns amount of CPU time spent in user mode by the terminated children
+    /// of the process, which were waited for (reaped) by it.
+    ///
+    /// Includes CPU time of all the reaped descendants, if each of them
+    /// waited for its own children too, which is handy for accounting
+    /// the CPU usage of shells and build tools spawning many short-lived processes.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux only (`cutime` value from the `/proc/<pid>/stat` file),
+    /// `None` is returned for other platforms.
+    pub fn children_user(&self) -> Option<Time> {
+        self.as_ref().children_user()
+    }
+
+    /// Returns amount of CPU time spent in kernel by the terminated children
+    /// of the process, which were waited for (reaped) by it.
+    ///
+    /// See [children_user](#method.children_user) for details.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux only (`cstime` value from the `/proc/<pid>/stat` file),
+    /// `None` is returned for other platforms.
+    pub fn children_system(&self) -> Option<Time> {
+        self.as_ref().children_system()
+    }
 }
 
 impl fmt::Debug for CpuTime {
@@ -28,6 +73,8 @@ impl fmt::Debug for CpuTime {
         f.debug_struct("CpuTime")
             .field("user", &self.user())
             .field("system", &self.system())
+            .field("children_user", &self.children_user())
+            .field("children_system", &self.children_system())
             .finish()
     }
 }
@@ -0,0 +1,45 @@
+/// Context switches made by process.
+///
+/// Returned by [Process::num_ctx_switches] method.
+///
+/// [Process::num_ctx_switches]: ./struct.Process.html#method.num_ctx_switches
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CtxSwitches {
+    voluntary: u64,
+    involuntary: u64,
+}
+
+heim_common::serde!(CtxSwitches {
+    voluntary,
+    involuntary
+});
+
+impl CtxSwitches {
+    pub(crate) fn new(voluntary: u64, involuntary: u64) -> CtxSwitches {
+        CtxSwitches {
+            voluntary,
+            involuntary,
+        }
+    }
+
+    /// Returns the number of voluntary context switches,
+    /// made because process was waiting for a resource to become available.
+    ///
+    /// ## Compatibility
+    ///
+    /// For macOS and Windows it is the total number of the context switches,
+    /// as these platforms are not distinguishing between them.
+    pub fn voluntary(&self) -> u64 {
+        self.voluntary
+    }
+
+    /// Returns the number of involuntary context switches,
+    /// made because process time slice has expired.
+    ///
+    /// ## Compatibility
+    ///
+    /// For macOS and Windows it is always `0`, see [voluntary](#method.voluntary).
+    pub fn involuntary(&self) -> u64 {
+        self.involuntary
+    }
+}
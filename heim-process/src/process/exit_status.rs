@@ -0,0 +1,49 @@
+use std::fmt;
+use std::process;
+
+/// Exit status of a terminated process.
+///
+/// Returned by [Process::wait] method.
+///
+/// Same to the [`std::process::ExitStatus`], but for arbitrary processes
+/// and not only those spawned by the `std::process::Command`.
+/// Unix-specific details, as the terminating signal, are available
+/// via [ExitStatusExt] extension trait.
+///
+/// [Process::wait]: ./struct.Process.html#method.wait
+/// [ExitStatusExt]: ./os/unix/trait.ExitStatusExt.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExitStatus(pub(crate) process::ExitStatus);
+
+impl ExitStatus {
+    /// Returns `true` if process terminated successfully,
+    /// i.e. it exited normally with a zero exit code.
+    pub fn success(&self) -> bool {
+        self.0.success()
+    }
+
+    /// Returns the exit code of the process, if any.
+    ///
+    /// For *nix systems `None` is returned if process was terminated by a signal.
+    pub fn code(&self) -> Option<i32> {
+        self.0.code()
+    }
+}
+
+impl fmt::Display for ExitStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<process::ExitStatus> for ExitStatus {
+    fn from(status: process::ExitStatus) -> ExitStatus {
+        ExitStatus(status)
+    }
+}
+
+impl From<ExitStatus> for process::ExitStatus {
+    fn from(status: ExitStatus) -> process::ExitStatus {
+        status.0
+    }
+}
@@ -0,0 +1,105 @@
+use std::ops::Deref;
+
+use crate::{Process, ProcessError};
+
+/// Action taken by [ProcessGuard] when it is dropped.
+///
+/// [ProcessGuard]: ./struct.ProcessGuard.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GuardAction {
+    /// Terminate process gracefully, same to the [Process::terminate] method.
+    ///
+    /// [Process::terminate]: ./struct.Process.html#method.terminate
+    Terminate,
+    /// Kill process, same to the [Process::kill] method.
+    ///
+    /// [Process::kill]: ./struct.Process.html#method.kill
+    Kill,
+}
+
+/// Process wrapper, which terminates the process when dropped.
+///
+/// Useful for the test harnesses and supervisors to guarantee that the spawned processes
+/// are cleaned up even if the panic or early return happened.
+/// Wrapped [Process] is available via `Deref`, call [into_inner] to disarm the guard.
+///
+/// Since the `Drop` can't be async, process is signaled synchronously
+/// and without the PID reuse check, which is done by the [Process::kill] method;
+/// child processes of the current process are not affected by that,
+/// as their PIDs are not reused until they are reaped.
+/// Errors are ignored, as there is no way to report them from the `Drop`.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # use heim_process::{GuardAction, ProcessGuard, ProcessResult};
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> ProcessResult<()> {
+/// let child = std::process::Command::new("yes").spawn()?;
+/// let process = heim_process::get(heim_process::Pid::from(child.id() as heim_process::RawPid)).await?;
+/// let guard = ProcessGuard::with_action(process, GuardAction::Kill);
+/// println!("Child process is {:?}", guard.status().await?);
+///
+/// // Process is killed at this point
+/// drop(guard);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Process]: ./struct.Process.html
+/// [Process::kill]: ./struct.Process.html#method.kill
+/// [into_inner]: #method.into_inner
+#[derive(Debug)]
+pub struct ProcessGuard {
+    process: Option<Process>,
+    action: GuardAction,
+}
+
+impl ProcessGuard {
+    /// Creates a new guard, which terminates the `process` when dropped.
+    pub fn new(process: Process) -> ProcessGuard {
+        ProcessGuard::with_action(process, GuardAction::Terminate)
+    }
+
+    /// Creates a new guard, which applies `action` to the `process` when dropped.
+    pub fn with_action(process: Process, action: GuardAction) -> ProcessGuard {
+        ProcessGuard {
+            process: Some(process),
+            action,
+        }
+    }
+
+    /// Returns the action taken when guard is dropped.
+    pub fn action(&self) -> GuardAction {
+        self.action
+    }
+
+    /// Disarms the guard and returns the wrapped process, which is left running.
+    pub fn into_inner(mut self) -> Process {
+        self.process
+            .take()
+            .expect("Process is taken from guard only once")
+    }
+}
+
+impl Deref for ProcessGuard {
+    type Target = Process;
+
+    fn deref(&self) -> &Self::Target {
+        self.process
+            .as_ref()
+            .expect("Process is present until guard is dropped or disarmed")
+    }
+}
+
+impl Drop for ProcessGuard {
+    fn drop(&mut self) {
+        if let Some(process) = self.process.take() {
+            match process.as_ref().kill_sync(self.action) {
+                Ok(()) | Err(ProcessError::NoSuchProcess(..)) => {}
+                Err(e) => log::debug!("Unable to stop process {}: {}", process.pid(), e),
+            }
+        }
+    }
+}
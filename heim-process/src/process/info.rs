@@ -0,0 +1,63 @@
+use heim_common::units::Time;
+
+use crate::{Pid, Status};
+
+/// Commonly used process information.
+///
+/// Returned by the [processes_with_info] function along with the [Process] itself.
+///
+/// [processes_with_info]: ./fn.processes_with_info.html
+/// [Process]: ./struct.Process.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessInfo {
+    parent_pid: Pid,
+    name: String,
+    status: Status,
+    create_time: Time,
+}
+
+heim_common::serde!(ProcessInfo {
+    parent_pid,
+    name,
+    status,
+    create_time
+});
+
+impl ProcessInfo {
+    pub(crate) fn new(parent_pid: Pid, name: String, status: Status, create_time: Time) -> Self {
+        ProcessInfo {
+            parent_pid,
+            name,
+            status,
+            create_time,
+        }
+    }
+
+    /// Returns the process parent pid.
+    ///
+    /// See [Process::parent_pid](./struct.Process.html#method.parent_pid) for details.
+    pub fn parent_pid(&self) -> Pid {
+        self.parent_pid
+    }
+
+    /// Returns the process name.
+    ///
+    /// See [Process::name](./struct.Process.html#method.name) for details.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the process status.
+    ///
+    /// See [Process::status](./struct.Process.html#method.status) for details.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns the process creation time, expressed as a [Time] amount since the UNIX epoch.
+    ///
+    /// [Time]: ../units/type.Time.html
+    pub fn create_time(&self) -> Time {
+        self.create_time
+    }
+}
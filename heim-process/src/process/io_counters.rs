@@ -11,7 +11,40 @@ pub struct IoCounters(sys::IoCounters);
 
 wrap!(IoCounters, sys::IoCounters);
 
+heim_common::serde!(IoCounters {
+    read_count,
+    write_count,
+    bytes_read,
+    bytes_written
+});
+
 impl IoCounters {
+    /// Returns the number of read operations performed by this process.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux it is an amount of the `read`-like syscalls, see [IoCountersExt::read_syscalls].
+    ///
+    /// macOS does not provide this information, so `0` is returned.
+    ///
+    /// [IoCountersExt::read_syscalls]: ./os/linux/trait.IoCountersExt.html#tymethod.read_syscalls
+    pub fn read_count(&self) -> u64 {
+        self.as_ref().read_count()
+    }
+
+    /// Returns the number of write operations performed by this process.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux it is an amount of the `write`-like syscalls, see [IoCountersExt::write_syscalls].
+    ///
+    /// macOS does not provide this information, so `0` is returned.
+    ///
+    /// [IoCountersExt::write_syscalls]: ./os/linux/trait.IoCountersExt.html#tymethod.write_syscalls
+    pub fn write_count(&self) -> u64 {
+        self.as_ref().write_count()
+    }
+
     /// Attempt to count the number of bytes which this process really did cause to
     /// be fetched from the storage layer.
     pub fn bytes_read(&self) -> Information {
@@ -28,6 +61,8 @@ impl IoCounters {
 impl fmt::Debug for IoCounters {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("IoCounters")
+            .field("read_count", &self.read_count())
+            .field("write_count", &self.write_count())
             .field("bytes_read", &self.bytes_read())
             .field("bytes_written", &self.bytes_written())
             .finish()
@@ -12,7 +12,18 @@ pub struct Memory(sys::Memory);
 
 wrap!(Memory, sys::Memory);
 
+heim_common::serde!(Memory { rss, vms });
+
 impl Memory {
+    /// Creates a new `Memory` from the resident set and virtual memory sizes.
+    ///
+    /// Mostly useful for testing the code which aggregates process memory usage
+    /// without the real processes; platform-specific values
+    /// provided by the OS extension traits are zeroed.
+    pub fn new(rss: Information, vms: Information) -> Memory {
+        sys::Memory::new(rss, vms).into()
+    }
+
     /// Returns resident set size, amount of non-swapped physical memory used by the process.
     pub fn rss(&self) -> Information {
         self.as_ref().rss()
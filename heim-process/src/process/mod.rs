@@ -1,35 +1,80 @@
+use std::cmp;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
 
 use heim_common::prelude::*;
-use heim_common::units::Time;
+use heim_common::units::{information, ratio, Time};
+use heim_runtime as rt;
 
-use crate::{sys, Pid, ProcessResult};
+use crate::{sys, Pid, ProcessError, ProcessResult};
+
+pub(crate) use self::oneshot::attribute;
 
 mod command;
 mod cpu_times;
 mod cpu_usage;
+mod ctx_switches;
 mod env;
+mod exit_status;
+mod guard;
+mod info;
 mod io_counters;
 mod memory;
+mod oneshot;
 mod status;
+mod thread;
 
 pub use self::command::{Command, CommandIter};
 pub use self::cpu_times::CpuTime;
 pub use self::cpu_usage::CpuUsage;
+pub use self::ctx_switches::CtxSwitches;
 pub use self::env::{Environment, EnvironmentIter, IntoEnvironmentIter};
+pub use self::exit_status::ExitStatus;
+pub use self::guard::{GuardAction, ProcessGuard};
+pub use self::info::ProcessInfo;
 pub use self::io_counters::IoCounters;
 pub use self::memory::Memory;
+pub use self::oneshot::{ProcessFields, ProcessOneShot};
 pub use self::status::Status;
+pub use self::thread::Thread;
 
 /// System process.
 ///
 /// Some extra methods can be found in the [OS extensions](./os/index.html)
-#[derive(Eq, PartialEq, Hash)]
-pub struct Process(sys::Process);
+///
+/// ## Equality
+///
+/// Processes are compared and hashed by both their pid and [creation time],
+/// so equal `Process` values are referring to the same process instance
+/// and not just to the same pid, which might be reused by the OS after process exit.
+///
+/// [creation time]: #method.create_time
+pub struct Process {
+    inner: sys::Process,
+    // Previous measurement for the `Process::cpu_percent(None)` calls
+    last_cpu_usage: Mutex<Option<CpuUsage>>,
+}
 
-wrap!(Process, sys::Process);
+#[doc(hidden)]
+impl AsRef<sys::Process> for Process {
+    fn as_ref(&self) -> &sys::Process {
+        &self.inner
+    }
+}
+
+#[doc(hidden)]
+impl From<sys::Process> for Process {
+    fn from(inner: sys::Process) -> Process {
+        Process {
+            inner,
+            last_cpu_usage: Mutex::new(None),
+        }
+    }
+}
 
 impl Process {
     /// Returns the process pid.
@@ -44,11 +89,67 @@ impl Process {
 
     /// Returns parent [Process].
     ///
+    /// `None` is returned if process has no parent (as for the kernel processes,
+    /// which [parent pid] is `0`) or if parent process had exited already.
+    /// In order to protect from the PID reuse, parent process should be created
+    /// not later than this process, otherwise `None` is returned too.
+    ///
+    /// Note that orphaned processes are re-parented by OS
+    /// (usually to the `init` process with pid `1`) and that new parent is returned in this case.
+    ///
     /// [Process]: ./struct.Process.html
-    pub async fn parent(&self) -> ProcessResult<Process> {
+    /// [parent pid]: #method.parent_pid
+    pub async fn parent(&self) -> ProcessResult<Option<Process>> {
         let ppid = self.parent_pid().await?;
+        if ppid == 0 || ppid == self.pid() {
+            return Ok(None);
+        }
+
+        let parent = match get(ppid).await {
+            Ok(parent) => parent,
+            Err(ProcessError::NoSuchProcess(..)) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let create_time = self.create_time().await?;
+        match parent.create_time().await {
+            Ok(parent_create_time) if parent_create_time <= create_time => Ok(Some(parent)),
+            Ok(..) | Err(ProcessError::NoSuchProcess(..)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
 
-        get(ppid).await
+    /// Returns a stream over the direct children of this process.
+    ///
+    /// All system processes are enumerated once with [processes_with_info]
+    /// and only the ones with a [parent pid] equal to this process pid are yielded.
+    /// In order to protect from the PID reuse, child process
+    /// should also be created strictly later than this process.
+    ///
+    /// Processes which are gone or can't be queried during the enumeration are skipped.
+    ///
+    /// [processes_with_info]: ./fn.processes_with_info.html
+    /// [parent pid]: ./struct.ProcessInfo.html#method.parent_pid
+    pub async fn children(&self) -> ProcessResult<impl Stream<Item = ProcessResult<Process>>> {
+        let pid = self.pid();
+        let create_time = self.create_time().await?;
+        let processes = processes_with_info().await?;
+
+        let stream = processes.filter_map(move |result| async move {
+            match result {
+                Ok((process, info))
+                    if info.parent_pid() == pid && info.create_time() > create_time =>
+                {
+                    Some(Ok(process))
+                }
+                Ok(..) => None,
+                Err(ProcessError::Load(e)) => Some(Err(ProcessError::Load(e))),
+                // It is not possible to tell if this process is a child
+                Err(..) => None,
+            }
+        });
+
+        Ok(stream)
     }
 
     /// Returns process name.
@@ -57,6 +158,23 @@ impl Process {
     }
 
     /// Returns process executable as an absolute path.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux the `/proc/{pid}/exe` symlink is read first;
+    /// if it is missing, the first [command line] argument is used instead,
+    /// resolved against the process [working directory] if it is relative
+    /// and canonicalized if it exists.
+    /// [`NoExecutable`] error is returned for the kernel threads, which have neither of them.
+    ///
+    /// If the executable was removed or replaced on disk after the process start,
+    /// Linux kernel adds the `" (deleted)"` suffix to the symlink target,
+    /// which is stripped; use [`ProcessExt::exe_deleted`] to check for that case.
+    ///
+    /// [command line]: #method.command
+    /// [working directory]: #method.cwd
+    /// [`NoExecutable`]: ./enum.ProcessError.html#variant.NoExecutable
+    /// [`ProcessExt::exe_deleted`]: ./os/linux/trait.ProcessExt.html#tymethod.exe_deleted
     pub async fn exe(&self) -> ProcessResult<PathBuf> {
         self.as_ref().exe().await
     }
@@ -84,6 +202,25 @@ impl Process {
         self.as_ref().command().await.map(Into::into)
     }
 
+    /// Returns process command line arguments.
+    ///
+    /// Arguments are returned in the same order as they are reported by the OS,
+    /// empty arguments (including the trailing ones) are preserved.
+    /// Processes without a command line (ex. kernel threads) yield an empty `Vec`.
+    ///
+    /// Arguments which are not valid UTF-8 are converted lossy;
+    /// use [`Process::command`] if the raw `OsStr` values are needed.
+    ///
+    /// [`Process::command`]: #method.command
+    pub async fn cmdline(&self) -> ProcessResult<Vec<String>> {
+        let command = self.command().await?;
+
+        Ok(command
+            .into_iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect())
+    }
+
     /// Returns process current working directory.
     ///
     /// ## Compatibility
@@ -95,18 +232,89 @@ impl Process {
         self.as_ref().cwd().await
     }
 
+    /// Returns path to the controlling terminal device of this process,
+    /// as the `/dev/pts/3` or `/dev/ttys003`.
+    ///
+    /// `None` is returned if process has no controlling terminal, as the daemons.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux terminal device number is read from the `/proc/<pid>/stat` file,
+    /// for macOS it is loaded from the `kinfo_proc` struct.
+    /// Device number is resolved then into a path by scanning the `/dev/pts/`
+    /// and `/dev/` directories for a character device with the same number.
+    ///
+    /// Windows has no controlling terminals and `None` is always returned.
+    pub async fn terminal(&self) -> ProcessResult<Option<PathBuf>> {
+        self.as_ref().terminal().await
+    }
+
     /// Returns current process status.
     pub async fn status(&self) -> ProcessResult<Status> {
         self.as_ref().status().await
     }
 
+    /// Checks if this process is a [zombie], i.e. it is terminated,
+    /// but was not reaped by its parent yet.
+    ///
+    /// Unlike most of the other methods, which might fail for zombie processes,
+    /// this one relies only on the process status and succeeds for them.
+    ///
+    /// ## Compatibility
+    ///
+    /// Windows has no zombie processes, so `false` is always returned for running processes.
+    ///
+    /// [zombie]: ./enum.Status.html#variant.Zombie
+    pub async fn is_zombie(&self) -> ProcessResult<bool> {
+        match self.status().await {
+            Ok(status) => Ok(status == Status::Zombie),
+            Err(ProcessError::ZombieProcess(..)) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Returns process environment.
     pub async fn environment(&self) -> ProcessResult<Environment> {
         self.as_ref().environment().await.map(Into::into)
     }
 
+    /// Returns process environment variables as a `String` key-value map.
+    ///
+    /// Variables are split on the first `=` symbol only,
+    /// so values containing `=` and empty values are kept as is.
+    /// Keys and values which are not valid UTF-8 are converted lossy;
+    /// use [`Process::environment`] if the raw `OsStr` values are needed.
+    ///
+    /// [`Process::environment`]: #method.environment
+    pub async fn environ(&self) -> ProcessResult<HashMap<String, String>> {
+        let environment = self.environment().await?;
+
+        Ok(environment
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    key.to_string_lossy().into_owned(),
+                    value.to_string_lossy().into_owned(),
+                )
+            })
+            .collect())
+    }
+
     /// Returns process creation time, expressed as a [Time] amount since the UNIX epoch.
     ///
+    /// Creation time is loaded once, when the `Process` is created,
+    /// and it is used then to tell apart the processes with re-used PIDs.
+    /// Different `Process` handles for the same process are always returning the same value.
+    ///
+    /// ## Precision
+    ///
+    /// * Linux: clock ticks (usually 10 milliseconds) since the system boot time,
+    ///   which is cached after the first load
+    /// * macOS: microseconds; processes which creation time can't be determined,
+    ///   as the `kernel_task`, are considered to be started at the system boot time
+    /// * Windows: 100 nanoseconds; "System Idle Process" and "System" processes
+    ///   are considered to be started at the system boot time
+    ///
     /// [Time]: ../units/type.Time.html
     pub async fn create_time(&self) -> ProcessResult<Time> {
         self.as_ref().create_time().await
@@ -163,16 +371,267 @@ impl Process {
         })
     }
 
+    /// Returns process CPU utilization as a percentage.
+    ///
+    /// If `interval` is given, two [CPU usage] measurements are made
+    /// with the `interval` delay between them and their difference is returned.
+    ///
+    /// If `interval` is `None`, CPU utilization is calculated since the previous call
+    /// of this method for the same `Process` value.
+    /// The first call with `None` has nothing to compare with and returns `0.0`,
+    /// which should be ignored by callers.
+    ///
+    /// Same to the [`CpuUsage`] subtraction,
+    /// returned value might exceed 100 % if the process is running
+    /// multiple threads on different CPU cores.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use heim_process::{self as process, Process, ProcessResult};
+    /// #
+    /// # #[heim_derive::main]
+    /// # async fn main() -> ProcessResult<()> {
+    /// let process = process::current().await?;
+    /// let usage = process.cpu_percent(Some(Duration::from_millis(100))).await?;
+    ///
+    /// println!("CPU usage: {} %", usage);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [CPU usage]: #method.cpu_usage
+    /// [`CpuUsage`]: ./struct.CpuUsage.html
+    pub async fn cpu_percent(&self, interval: Option<Duration>) -> ProcessResult<f32> {
+        let previous = match interval {
+            Some(interval) => {
+                let measurement = self.cpu_usage().await?;
+                rt::time::sleep(interval).await;
+
+                Some(measurement)
+            }
+            None => {
+                let mut last = self.last_cpu_usage();
+                last.take()
+            }
+        };
+        let current = self.cpu_usage().await?;
+        *self.last_cpu_usage() = Some(current.clone());
+
+        match previous {
+            Some(previous) => Ok((current - previous).get::<ratio::percent>()),
+            None => Ok(0.0),
+        }
+    }
+
+    fn last_cpu_usage(&self) -> MutexGuard<'_, Option<CpuUsage>> {
+        // Cached value is replaced as a whole, so it is safe to ignore the poisoning
+        self.last_cpu_usage
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the number of context switches made by this process.
+    pub async fn num_ctx_switches(&self) -> ProcessResult<CtxSwitches> {
+        self.as_ref().num_ctx_switches().await
+    }
+
+    /// Returns a stream over the threads of this process.
+    ///
+    /// Threads might start or exit while this stream is being consumed;
+    /// the ones which are gone before their information was loaded are skipped.
+    ///
+    /// ## Compatibility
+    ///
+    /// On macOS, inspecting threads of the processes owned by other users
+    /// requires root privileges, [`ProcessError::AccessDenied`] is returned otherwise.
+    pub async fn threads(&self) -> ProcessResult<impl Stream<Item = ProcessResult<Thread>>> {
+        let inner = self.as_ref().threads().await?;
+
+        Ok(inner.map_ok(Into::into))
+    }
+
+    /// Returns the number of threads used by this process.
+    ///
+    /// It is a much cheaper call than the [threads] stream counting,
+    /// as the threads are not enumerated one by one:
+    /// `/proc/<pid>/status` file is read for Linux,
+    /// `proc_pidinfo` call is used for macOS
+    /// and a system processes snapshot for Windows.
+    ///
+    /// Returned value is a point-in-time snapshot,
+    /// threads might start or exit right after it was loaded.
+    ///
+    /// [threads]: #method.threads
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        self.as_ref().num_threads().await
+    }
+
+    /// Returns the process status and the number of threads, loaded together.
+    ///
+    /// Calling [status] and [num_threads] separately might observe the process
+    /// in different states, ex. it might be stopped or become a zombie in between;
+    /// this method reads both values from the same data source where the OS allows that:
+    /// `/proc/<pid>/stat` file for Linux and a system processes snapshot for Windows.
+    ///
+    /// ## Compatibility
+    ///
+    /// macOS has no single call returning both values, so the `kinfo_proc` struct
+    /// and the `proc_pidinfo` call results are combined, same as the separate calls would do.
+    ///
+    /// [status]: #method.status
+    /// [num_threads]: #method.num_threads
+    pub async fn status_and_threads(&self) -> ProcessResult<(Status, u64)> {
+        self.as_ref().status_and_threads().await
+    }
+
     /// Returns memory usage information for this process.
     pub async fn memory(&self) -> ProcessResult<Memory> {
         self.as_ref().memory().await.map(Into::into)
     }
 
+    /// Returns process resident set size as a percentage of the total physical memory.
+    ///
+    /// Due to the shared memory accounting, RSS might be reported bigger than
+    /// the total physical memory amount, in that case returned value is clamped to `100.0`.
+    pub async fn memory_percent(&self) -> ProcessResult<f32> {
+        let (memory, system) =
+            future::try_join(self.memory(), heim_memory::memory().map_err(Into::into)).await?;
+
+        let rss = memory.rss().get::<information::byte>();
+        let total = system.total().get::<information::byte>();
+        if total == 0 {
+            return Ok(0.0);
+        }
+
+        let percent = rss as f64 / total as f64 * 100.0;
+        if percent > 100.0 {
+            log::debug!(
+                "Process {} RSS ({} bytes) exceeds total physical memory ({} bytes)",
+                self.pid(),
+                rss,
+                total
+            );
+
+            Ok(100.0)
+        } else {
+            Ok(percent as f32)
+        }
+    }
+
+    /// Returns the selected process attributes, gathered at once.
+    ///
+    /// Underlying system calls are shared between the attributes where possible,
+    /// and the ones needed only for attributes not requested in `fields` are not made at all,
+    /// which makes this method the preferred way to build the processes tables.
+    ///
+    /// Failure to load one attribute, as in case when access to it is denied,
+    /// does not fail the whole call; the corresponding [ProcessOneShot] getter
+    /// returns `None` in that case.
+    /// [`ProcessError::NoSuchProcess`] error is still returned if process is gone.
+    ///
+    /// ## Consistency
+    ///
+    /// Attributes loaded by the same system call are consistent with each other,
+    /// while the separate calls might observe the process in different moments:
+    ///
+    /// * Linux: parent pid, name, status and CPU time are read from the `/proc/<pid>/stat` file,
+    ///   memory is read from the `/proc/<pid>/statm` file and IO counters from the `/proc/<pid>/io` file
+    /// * macOS: parent pid, name and status are loaded from the `kinfo_proc` struct,
+    ///   CPU time and memory are loaded by the `proc_pidinfo` call
+    /// * Windows: CPU time and memory are queried via the same process handle,
+    ///   while parent pid, name and status are loaded separately
+    ///
+    /// Creation time is cached in the `Process` value and does not require any calls at all.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use heim_process::{ProcessFields, ProcessResult};
+    /// # #[heim_derive::main]
+    /// # async fn main() -> ProcessResult<()> {
+    /// let process = heim_process::current().await?;
+    /// let info = process.oneshot(ProcessFields::NAME | ProcessFields::MEMORY).await?;
+    ///
+    /// println!("{:?} uses {:?}", info.name(), info.memory().map(|memory| memory.rss()));
+    /// assert!(info.status().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [ProcessOneShot]: ./struct.ProcessOneShot.html
+    /// [`ProcessError::NoSuchProcess`]: ./enum.ProcessError.html#variant.NoSuchProcess
+    pub async fn oneshot(&self, fields: ProcessFields) -> ProcessResult<ProcessOneShot> {
+        self.as_ref().oneshot(fields).await
+    }
+
+    /// Returns indexes of the logical CPUs this process is allowed to run on.
+    ///
+    /// ## Compatibility
+    ///
+    /// Implemented only for Linux, for other platforms this method returns
+    /// [`ProcessError::Load`] error saying that CPU affinity is not supported.
+    pub async fn cpu_affinity(&self) -> ProcessResult<Vec<usize>> {
+        self.as_ref().cpu_affinity().await
+    }
+
+    /// Restricts this process to run only on the logical CPUs with `cpus` indexes.
+    ///
+    /// [`ProcessError::Load`] error with `EINVAL` OS error code is returned
+    /// if `cpus` contains no CPUs available in the system, or if there is an index
+    /// out of the bounds supported by OS.
+    ///
+    /// ## Compatibility
+    ///
+    /// Implemented only for Linux, for other platforms this method returns
+    /// [`ProcessError::Load`] error saying that CPU affinity is not supported.
+    pub async fn set_cpu_affinity(&self, cpus: &[usize]) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
+        self.as_ref().set_cpu_affinity(cpus).await
+    }
+
     /// Checks if this `Process` is still running.
+    ///
+    /// Returns `false` both if there is no process with the same PID anymore
+    /// and if the PID was reused by another process.
+    /// Processes are considered to be the same if both their PIDs and
+    /// [creation times] are equal; creation time precision is platform-dependent,
+    /// but PID reuse within the same time tick is very unlikely.
+    ///
+    /// Zombie processes are considered to be running.
+    ///
+    /// [creation times]: #method.create_time
     pub async fn is_running(&self) -> ProcessResult<bool> {
         self.as_ref().is_running().await
     }
 
+    /// Checks that this `Process` handle still refers to the same process.
+    ///
+    /// Useful for handles cached for a long time, as the process might exit
+    /// and its PID can be reused by another process meanwhile.
+    /// [`NoSuchProcess`] error is returned in that case.
+    ///
+    /// All methods which are modifying the process, ex. [kill] or [suspend],
+    /// are doing the same check before the modification.
+    ///
+    /// [`NoSuchProcess`]: ./enum.ProcessError.html#variant.NoSuchProcess
+    /// [kill]: #method.kill
+    /// [suspend]: #method.suspend
+    pub async fn validate(&self) -> ProcessResult<()> {
+        self.ensure_same_process().await
+    }
+
+    /// Re-checks the process creation time to protect mutating operations
+    /// from acting on a reused PID.
+    pub(crate) async fn ensure_same_process(&self) -> ProcessResult<()> {
+        if self.is_running().await? {
+            Ok(())
+        } else {
+            Err(ProcessError::NoSuchProcess(self.pid()))
+        }
+    }
+
     /// Suspends the current process.
     ///
     /// Before the signal send, it checks whether process PID has been reused,
@@ -184,6 +643,7 @@ impl Process {
     ///
     /// [`NoSuchProcess`]: ./enum.ProcessError.html#variant.NoSuchProcess
     pub async fn suspend(&self) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
         self.as_ref().suspend().await
     }
 
@@ -198,6 +658,7 @@ impl Process {
     ///
     /// [`NoSuchProcess`]: ./enum.ProcessError.html#variant.NoSuchProcess
     pub async fn resume(&self) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
         self.as_ref().resume().await
     }
 
@@ -215,6 +676,7 @@ impl Process {
     /// [`NoSuchProcess`]: ./enum.ProcessError.html#variant.NoSuchProcess
     /// [`Process::kill`]: #method.kill
     pub async fn terminate(&self) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
         self.as_ref().terminate().await
     }
 
@@ -233,16 +695,74 @@ impl Process {
     /// [`NoSuchProcess`]: ./enum.ProcessError.html#variant.NoSuchProcess
     /// [`TerminateProcess`]: https://docs.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-terminateprocess
     pub async fn kill(&self) -> ProcessResult<()> {
+        self.ensure_same_process().await?;
         self.as_ref().kill().await
     }
 
+    /// Terminates the current process and kills it if it is still running after `grace` period.
+    ///
+    /// Process is checked for termination with an increasing interval between checks;
+    /// zombie processes are considered as terminated.
+    /// Same as [terminate] and [kill] methods, PID reuse check is done before each signal,
+    /// so process which had exited and which PID was reused in the meantime is not killed.
+    ///
+    /// `Ok(())` is returned once the process is gone, including the case
+    /// when it was already terminated before this call.
+    ///
+    /// [terminate]: #method.terminate
+    /// [kill]: #method.kill
+    pub async fn kill_with_timeout(&self, grace: Duration) -> ProcessResult<()> {
+        match self.terminate().await {
+            Ok(()) => {}
+            Err(ProcessError::NoSuchProcess(..)) => return Ok(()),
+            Err(e) => return Err(e),
+        }
+
+        if let Ok(result) = rt::timeout(grace, self.poll_gone()).await {
+            return result;
+        }
+
+        match self.kill().await {
+            Ok(()) | Err(ProcessError::NoSuchProcess(..)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn poll_gone(&self) -> ProcessResult<()> {
+        let mut delay = sys::POLL_MIN_DELAY;
+        loop {
+            if !self.is_running().await? {
+                return Ok(());
+            }
+            match self.is_zombie().await {
+                Ok(true) | Err(ProcessError::NoSuchProcess(..)) => return Ok(()),
+                Ok(false) => {}
+                Err(e) => return Err(e),
+            }
+
+            rt::time::sleep(delay).await;
+            delay = cmp::min(delay * 2, sys::POLL_MAX_DELAY);
+        }
+    }
+
     /// Wait for the current process termination.
     ///
     /// ## Returns
     ///
-    /// If the process is already terminated, this method returns `Ok(())`.
-    pub async fn wait(&self) -> ProcessResult<()> {
-        self.as_ref().wait().await
+    /// If the process is a child of the current process, its exit status is returned
+    /// and the process is reaped.
+    ///
+    /// For other processes exit status is not available and `Ok(None)` is returned
+    /// once process terminates: Linux implementation polls the process state
+    /// with an increasing interval between checks,
+    /// macOS and Windows are notified by the OS directly.
+    ///
+    /// If the process is already terminated, this method returns `Ok(None)`.
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        self.as_ref()
+            .wait()
+            .await
+            .map(|status| status.map(ExitStatus::from))
     }
 
     /// Returns future which resolves into process IO counters.
@@ -251,6 +771,20 @@ impl Process {
     }
 }
 
+impl hash::Hash for Process {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl PartialEq for Process {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for Process {}
+
 impl fmt::Debug for Process {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Process").field("pid", &self.pid()).finish()
@@ -258,17 +792,65 @@ impl fmt::Debug for Process {
 }
 
 /// Returns a stream over the currently running processes.
+///
+/// Stream can be dropped at any moment, ex. after the `.take(n)` combinator,
+/// all the resources it holds are released at that moment.
 pub async fn processes() -> Result<impl Stream<Item = ProcessResult<Process>>> {
     let inner = sys::processes().await?;
 
     Ok(inner.map_ok(Into::into))
 }
 
+/// Returns a stream over the currently running processes,
+/// each of them paired with the commonly used [process information].
+///
+/// This is cheaper than calling the [Process] methods for each yielded process,
+/// as the information is fetched in batch where possible; for example,
+/// on macOS it is loaded for all processes at once with a single `sysctl` call.
+///
+/// Processes are yielded in the same order as [processes] function does.
+/// Zombie processes and processes exited during the enumeration are skipped.
+///
+/// [process information]: ./struct.ProcessInfo.html
+/// [Process]: ./struct.Process.html
+/// [processes]: ./fn.processes.html
+pub async fn processes_with_info(
+) -> Result<impl Stream<Item = ProcessResult<(Process, ProcessInfo)>>> {
+    let inner = sys::processes_with_info().await?;
+
+    let stream = inner
+        .filter(|result| {
+            let skip = match result {
+                Ok((_, info)) => info.status() == Status::Zombie,
+                Err(ProcessError::NoSuchProcess(..)) | Err(ProcessError::ZombieProcess(..)) => true,
+                Err(..) => false,
+            };
+
+            future::ready(!skip)
+        })
+        .map_ok(|(process, info)| (process.into(), info));
+
+    Ok(stream)
+}
+
 /// Loads the process information with `pid` given.
 pub async fn get(pid: Pid) -> ProcessResult<Process> {
     sys::get(pid).await.map(Into::into)
 }
 
+/// Loads the processes with `pids` given.
+///
+/// Lookups are executed concurrently, but each result is yielded paired with its pid
+/// in the same order as `pids` are provided.
+/// Processes which do not exist are yielded as [NoSuchProcess] errors.
+///
+/// [NoSuchProcess]: ./enum.ProcessError.html#variant.NoSuchProcess
+pub fn get_many(pids: &[Pid]) -> impl Stream<Item = (Pid, ProcessResult<Process>)> {
+    pids.iter()
+        .map(|&pid| async move { (pid, get(pid).await) })
+        .collect::<stream::FuturesOrdered<_>>()
+}
+
 /// Returns the `Process` matching the currently running program.
 pub async fn current() -> ProcessResult<Process> {
     sys::current().await.map(Into::into)
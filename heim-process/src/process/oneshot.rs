@@ -0,0 +1,176 @@
+use std::io;
+
+use heim_common::units::Time;
+
+use super::IoCounters;
+use crate::{CpuTime, Memory, Pid, ProcessError, ProcessResult, Status};
+
+bitflags::bitflags! {
+    /// Set of the process attributes to be fetched by the [Process::oneshot] method.
+    ///
+    /// [Process::oneshot]: ./struct.Process.html#method.oneshot
+    pub struct ProcessFields: u32 {
+        /// Process parent pid.
+        const PARENT_PID = 0b0000_0001;
+        /// Process name.
+        const NAME = 0b0000_0010;
+        /// Process status.
+        const STATUS = 0b0000_0100;
+        /// Process creation time.
+        const CREATE_TIME = 0b0000_1000;
+        /// Accumulated process CPU time.
+        const CPU_TIME = 0b0001_0000;
+        /// Process memory usage.
+        const MEMORY = 0b0010_0000;
+        /// Process IO counters.
+        const IO_COUNTERS = 0b0100_0000;
+    }
+}
+
+/// Process attributes gathered at once by the [Process::oneshot] method.
+///
+/// Each attribute is `None` if it was not requested
+/// or if it failed to load, as in case of the insufficient permissions.
+///
+/// For example, on Linux `/proc/<pid>/io` file is readable by the process owner only,
+/// so the [io_counters] are `None` for the processes of other users,
+/// while the rest of attributes are still loaded from the world-readable files.
+///
+/// [io_counters]: #method.io_counters
+///
+/// [Process::oneshot]: ./struct.Process.html#method.oneshot
+#[derive(Debug, Default)]
+pub struct ProcessOneShot {
+    pub(crate) parent_pid: Option<Pid>,
+    pub(crate) name: Option<String>,
+    pub(crate) status: Option<Status>,
+    pub(crate) create_time: Option<Time>,
+    pub(crate) cpu_time: Option<CpuTime>,
+    pub(crate) memory: Option<Memory>,
+    pub(crate) io_counters: Option<IoCounters>,
+}
+
+impl ProcessOneShot {
+    /// Returns the process parent pid.
+    ///
+    /// See [Process::parent_pid](./struct.Process.html#method.parent_pid) for details.
+    pub fn parent_pid(&self) -> Option<Pid> {
+        self.parent_pid
+    }
+
+    /// Returns the process name.
+    ///
+    /// See [Process::name](./struct.Process.html#method.name) for details.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the process status.
+    ///
+    /// See [Process::status](./struct.Process.html#method.status) for details.
+    pub fn status(&self) -> Option<Status> {
+        self.status
+    }
+
+    /// Returns the process creation time, expressed as a [Time] amount since the UNIX epoch.
+    ///
+    /// [Time]: ../units/type.Time.html
+    pub fn create_time(&self) -> Option<Time> {
+        self.create_time
+    }
+
+    /// Returns the accumulated process CPU time.
+    ///
+    /// See [Process::cpu_time](./struct.Process.html#method.cpu_time) for details.
+    pub fn cpu_time(&self) -> Option<&CpuTime> {
+        self.cpu_time.as_ref()
+    }
+
+    /// Returns the process memory usage.
+    ///
+    /// See [Process::memory](./struct.Process.html#method.memory) for details.
+    pub fn memory(&self) -> Option<&Memory> {
+        self.memory.as_ref()
+    }
+
+    /// Returns the process IO counters.
+    ///
+    /// See [Process::io_counters](./struct.Process.html#method.io_counters) for details.
+    pub fn io_counters(&self) -> Option<&IoCounters> {
+        self.io_counters.as_ref()
+    }
+}
+
+/// Turns the attribute loading error into the missing value
+/// if the attribute is forbidden for the current user.
+///
+/// Errors are split into two groups here:
+///
+/// * only this attribute is unavailable, as in case of the insufficient permissions
+///   to one of the `/proc/<pid>/` files, so it is degraded into `None`
+///   and the gathering continues
+/// * process is gone or the attribute can't be loaded for any other reason,
+///   so the whole gathering is aborted with this error
+pub(crate) fn attribute<T>(result: ProcessResult<T>) -> ProcessResult<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(e @ ProcessError::NoSuchProcess(..)) => Err(e),
+        Err(e) if is_forbidden(&e) => {
+            log::trace!("Access to the process attribute is denied: {:?}", e);
+
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Checks if the attribute is forbidden for the current user,
+/// while the process itself is still there.
+fn is_forbidden(e: &ProcessError) -> bool {
+    match e {
+        ProcessError::AccessDenied(..) => true,
+        ProcessError::Load(e) => e.kind() == io::ErrorKind::PermissionDenied,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::attribute;
+    use crate::{Pid, ProcessError};
+
+    #[test]
+    fn test_attribute_degrades_forbidden() {
+        let pid = Pid::new(1);
+
+        let denied: Result<(), _> = Err(ProcessError::AccessDenied(pid));
+        assert!(matches!(attribute(denied), Ok(None)));
+
+        let e = io::Error::from(io::ErrorKind::PermissionDenied);
+        let denied: Result<(), _> = Err(ProcessError::from(e));
+        assert!(matches!(attribute(denied), Ok(None)));
+    }
+
+    #[test]
+    fn test_attribute_aborts_on_gone_process() {
+        let gone: Result<(), _> = Err(ProcessError::NoSuchProcess(Pid::new(1)));
+
+        assert!(matches!(
+            attribute(gone),
+            Err(ProcessError::NoSuchProcess(..))
+        ));
+    }
+
+    #[test]
+    fn test_attribute_aborts_on_load_error() {
+        let e = io::Error::from(io::ErrorKind::InvalidData);
+        let invalid: Result<(), _> = Err(ProcessError::from(e));
+
+        match attribute(invalid) {
+            Err(ProcessError::Load(e)) => assert_eq!(io::ErrorKind::InvalidData, e.kind()),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+}
@@ -1,46 +1,64 @@
-/// Process status.
-///
-/// Returned by [Process::status] method.
-///
-/// [Process::status]: ./struct.Process.html#method.status
-#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
-pub enum Status {
-    /// Running
-    Running,
-
-    /// Sleeping in an interruptible wait
-    Sleeping,
-
-    /// Waiting in uninterruptible disk sleep
-    Waiting,
-
-    /// Zombie
-    Zombie,
-
-    /// Stopped (on a signal)
+heim_common::serde! {
+    /// Process status.
     ///
-    /// Or before Linux 2.6.33, trace stopped
-    Stopped,
+    /// Returned by [Process::status] method.
+    ///
+    /// Platform-specific process states are mapped to the nearest variant;
+    /// states which are not known to `heim` are represented
+    /// by the [Unknown](#variant.Unknown) variant instead of an error.
+    ///
+    /// [Process::status]: ./struct.Process.html#method.status
+    #[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
+    pub enum Status {
+        /// Running
+        Running,
 
-    /// Tracing stop (Linux 2.6.33 onward)
-    Tracing,
+        /// Sleeping in an interruptible wait
+        Sleeping,
 
-    /// Dead
-    Dead,
+        /// Waiting in uninterruptible disk sleep
+        Waiting,
 
-    /// Wakekill (Linux 2.6.33 to 3.13 only)
-    Wakekill,
+        /// Zombie, terminated but not reaped by its parent yet
+        ///
+        /// ## Compatibility
+        ///
+        /// Not applicable for Windows, which has no zombie processes.
+        Zombie,
 
-    /// Waking (Linux 2.6.33 to 3.13 only)
-    Waking,
+        /// Stopped (on a signal)
+        ///
+        /// Or before Linux 2.6.33, trace stopped
+        Stopped,
 
-    /// Parked (P) (Linux 3.9 to 3.13 only)
-    Parked,
+        /// Tracing stop (Linux 2.6.33 onward)
+        Tracing,
 
-    /// Idle
-    ///
-    /// ## Compatibility
-    ///
-    /// Applicable for Linux and macOS only.
-    Idle,
+        /// Dead
+        Dead,
+
+        /// Wakekill (Linux 2.6.33 to 3.13 only)
+        Wakekill,
+
+        /// Waking (Linux 2.6.33 to 3.13 only)
+        Waking,
+
+        /// Parked (P) (Linux 3.9 to 3.13 only)
+        Parked,
+
+        /// Idle
+        ///
+        /// ## Compatibility
+        ///
+        /// Applicable for Linux and macOS only.
+        /// For macOS it is a process being created by fork (`SIDL`).
+        Idle,
+
+        /// Unknown state with its raw code
+        ///
+        /// Might appear if process is in a state introduced by a newer OS version.
+        /// For Linux raw code is a state character from the `/proc/<pid>/stat` file
+        /// and for macOS it is a `p_stat` value written as a decimal digit.
+        Unknown(char),
+    }
 }
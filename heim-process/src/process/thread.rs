@@ -0,0 +1,35 @@
+use std::fmt;
+
+use heim_common::prelude::wrap;
+
+use crate::sys;
+use crate::CpuTime;
+
+/// Process thread.
+pub struct Thread(sys::Thread);
+
+wrap!(Thread, sys::Thread);
+
+impl Thread {
+    /// Returns thread ID.
+    ///
+    /// It is an OS-specific value: thread ID (`tid`) for Linux,
+    /// system-wide unique thread ID for macOS and thread ID for Windows.
+    pub fn id(&self) -> u64 {
+        self.as_ref().id()
+    }
+
+    /// Returns accumulated CPU time for this thread.
+    pub fn cpu_time(&self) -> CpuTime {
+        self.as_ref().cpu_time().into()
+    }
+}
+
+impl fmt::Debug for Thread {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Thread")
+            .field("id", &self.id())
+            .field("cpu_time", &self.cpu_time())
+            .finish()
+    }
+}
@@ -0,0 +1,84 @@
+use std::pin::Pin;
+
+use heim_common::prelude::futures::task::{Context, Poll};
+use heim_common::prelude::{futures, Stream};
+use pin_utils::unsafe_pinned;
+
+use crate::ProcessResult;
+
+/// Stream for the [`skip_transient`](trait.ProcessStreamExt.html#method.skip_transient) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SkipTransient<S> {
+    stream: S,
+}
+
+impl<S> SkipTransient<S> {
+    unsafe_pinned!(stream: S);
+}
+
+impl<S, T> Stream for SkipTransient<S>
+where
+    S: Stream<Item = ProcessResult<T>>,
+{
+    type Item = ProcessResult<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match futures::ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(Err(e)) if e.is_transient() => {
+                    log::debug!("Skipping transient process error: {}", e);
+                }
+                item => return Poll::Ready(item),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.stream.size_hint();
+
+        (0, upper)
+    }
+}
+
+/// Extensions for the streams of process-related results,
+/// such as the ones returned by [processes] or [Process::threads].
+///
+/// [processes]: ./fn.processes.html
+/// [Process::threads]: ./struct.Process.html#method.threads
+pub trait ProcessStreamExt<T>: Stream<Item = ProcessResult<T>> {
+    /// Skips the [transient] errors, yielding the successful results
+    /// and the errors which are not expected during the enumeration.
+    ///
+    /// It is a common "best-effort enumeration" pattern, where the processes
+    /// exiting or not accessible in the middle of the iteration should be ignored,
+    /// but the data loading failures should still be propagated.
+    ///
+    /// Skipped errors are logged with a `debug` level.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use heim_common::prelude::*;
+    /// # use heim_process::{ProcessResult, ProcessStreamExt};
+    /// # #[heim_derive::main]
+    /// # async fn main() -> ProcessResult<()> {
+    /// let processes = heim_process::processes()
+    ///     .await?
+    ///     .skip_transient()
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [transient]: ./enum.ProcessError.html#method.is_transient
+    fn skip_transient(self) -> SkipTransient<Self>
+    where
+        Self: Sized,
+    {
+        SkipTransient { stream: self }
+    }
+}
+
+impl<S, T> ProcessStreamExt<T> for S where S: Stream<Item = ProcessResult<T>> {}
@@ -1,8 +1,18 @@
+use std::time::Duration;
+
 use ordered_float::NotNan;
 
+use crate::sys::CpuTime;
 use crate::Pid;
 use heim_common::units::{time, Time};
 
+/// Minimal and maximal delays between the checks
+/// when waiting for the process termination by polling its state.
+///
+/// Same delays as `psutil` uses.
+pub const POLL_MIN_DELAY: Duration = Duration::from_micros(100);
+pub const POLL_MAX_DELAY: Duration = Duration::from_millis(40);
+
 /// Process unique ID.
 ///
 /// Processes can't be compared just by their PIDs,
@@ -47,3 +57,51 @@ impl UniqueId {
         Time::new::<time::second>(*self.create_time)
     }
 }
+
+/// Process thread information.
+///
+/// This struct is shared across multiple OS-specific implementations,
+/// only the way to fetch it differs.
+#[derive(Debug, Clone)]
+pub struct Thread {
+    id: u64,
+    cpu_time: CpuTime,
+}
+
+impl Thread {
+    pub fn new(id: u64, cpu_time: CpuTime) -> Thread {
+        Thread { id, cpu_time }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn cpu_time(&self) -> CpuTime {
+        self.cpu_time.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use heim_common::units::{time, Time};
+
+    use super::UniqueId;
+    use crate::Pid;
+
+    #[test]
+    fn test_unique_id_reused_pid() {
+        let first = UniqueId::new(Pid::new(42), Time::new::<time::second>(1_600_000_000.0));
+        let reused = UniqueId::new(Pid::new(42), Time::new::<time::second>(1_600_000_100.0));
+
+        assert_ne!(first, reused);
+        assert_eq!(first, first.clone());
+
+        let set = vec![first.clone(), reused, first]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(2, set.len());
+    }
+}
@@ -0,0 +1,46 @@
+use std::mem;
+
+use heim_common::prelude::*;
+
+use super::map_error;
+use crate::{Pid, ProcessResult};
+
+/// Safe wrapper for `sched_getaffinity`.
+pub fn cpu_affinity(pid: Pid) -> ProcessResult<Vec<usize>> {
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    let result =
+        unsafe { libc::sched_getaffinity(*pid, mem::size_of::<libc::cpu_set_t>(), &mut set) };
+    if result != 0 {
+        let e = Error::last_os_error().with_ffi("sched_getaffinity");
+        return Err(map_error(pid, e));
+    }
+
+    let cpus = (0..libc::CPU_SETSIZE as usize)
+        .filter(|cpu| unsafe { libc::CPU_ISSET(*cpu, &set) })
+        .collect();
+
+    Ok(cpus)
+}
+
+/// Safe wrapper for `sched_setaffinity`.
+pub fn set_cpu_affinity(pid: Pid, cpus: &[usize]) -> ProcessResult<()> {
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    for cpu in cpus {
+        // `CPU_SET` silently ignores indexes out of the set bounds
+        if *cpu >= libc::CPU_SETSIZE as usize {
+            let e = Error::from_raw_os_error(libc::EINVAL)
+                .with_message(format!("CPU index {} is out of bounds", cpu));
+            return Err(e.into());
+        }
+        unsafe { libc::CPU_SET(*cpu, &mut set) };
+    }
+
+    let result = unsafe { libc::sched_setaffinity(*pid, mem::size_of::<libc::cpu_set_t>(), &set) };
+    if result != 0 {
+        // `EINVAL` is returned if mask contains no CPUs available in the system
+        let e = Error::last_os_error().with_ffi("sched_setaffinity");
+        return Err(map_error(pid, e));
+    }
+
+    Ok(())
+}
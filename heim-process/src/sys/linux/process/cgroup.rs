@@ -0,0 +1,257 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use heim_common::prelude::*;
+use heim_common::units::{information, time, Information, Time};
+use heim_runtime as rt;
+
+use crate::os::linux::CgroupLimits;
+use crate::sys::linux::process::procfs::process_file_path;
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// cgroup v1 reports "unlimited" memory as the `LONG_MAX` rounded down to the page size,
+/// so all the values above this one are considered as unlimited.
+const V1_MEMORY_UNLIMITED: u64 = 0x7FFF_FFFF_0000_0000;
+
+/// Entry of the `/proc/<pid>/cgroup` file.
+#[derive(Debug, PartialEq)]
+struct Membership<'a> {
+    /// Empty for the cgroup v2 hierarchy.
+    controllers: &'a str,
+    path: &'a str,
+}
+
+fn parse_memberships(contents: &str) -> Result<Vec<Membership<'_>>> {
+    contents
+        .lines()
+        .map(|line| {
+            let mut parts = line.splitn(3, ':');
+            let _hierarchy_id = parts.next();
+            match (parts.next(), parts.next()) {
+                (Some(controllers), Some(path)) => Ok(Membership { controllers, path }),
+                _ => {
+                    let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+                    Err(Error::from(inner)
+                        .with_message(format!("Invalid cgroup membership: {:?}", line)))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns cgroup directory for the `path` and all its ancestors, up to the hierarchy `root`.
+///
+/// Limits are applied to the whole subtree, so the ancestors should be checked too.
+/// Directories which are not visible in the current cgroup namespace are skipped.
+fn hierarchy(root: &Path, path: &str) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let mut path = Path::new(path.trim_start_matches('/'));
+    loop {
+        let dir = root.join(path);
+        if dir.is_dir() {
+            dirs.push(dir);
+        }
+        match path.parent() {
+            Some(parent) => path = parent,
+            None => break,
+        }
+    }
+
+    dirs
+}
+
+/// Reads the limit file contents, `None` is returned if it does not exist.
+async fn read_limit(path: PathBuf) -> Result<Option<String>> {
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => Ok(Some(contents.trim().to_string())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(Error::from(e).with_file(path)),
+    }
+}
+
+fn min_memory(current: Option<Information>, value: Information) -> Option<Information> {
+    match current {
+        Some(current) if current <= value => Some(current),
+        _ => Some(value),
+    }
+}
+
+fn min_cpu(current: Option<(Time, Time)>, value: (Time, Time)) -> Option<(Time, Time)> {
+    let ratio =
+        |(quota, period): (Time, Time)| quota.get::<time::second>() / period.get::<time::second>();
+    match current {
+        Some(current) if ratio(current) <= ratio(value) => Some(current),
+        _ => Some(value),
+    }
+}
+
+/// Parses the cgroup v2 `cpu.max` file contents, which is looking like `max 100000`
+/// or `50000 100000`, where the values are in microseconds.
+fn parse_cpu_max(contents: &str) -> Result<Option<(Time, Time)>> {
+    let mut parts = contents.split_whitespace();
+    let quota = parts.next().unwrap_or("max");
+    if quota == "max" {
+        return Ok(None);
+    }
+    let quota: u64 = quota.parse()?;
+    let period: u64 = match parts.next() {
+        Some(period) => period.parse()?,
+        None => 100_000,
+    };
+
+    Ok(Some((
+        Time::new::<time::microsecond>(quota as f64),
+        Time::new::<time::microsecond>(period as f64),
+    )))
+}
+
+async fn limits_v2(root: &Path, path: &str) -> Result<CgroupLimits> {
+    let mut limits = CgroupLimits::default();
+
+    for dir in hierarchy(root, path) {
+        if let Some(value) = read_limit(dir.join("memory.max")).await? {
+            if value != "max" {
+                let bytes = value.parse::<u64>()?;
+                limits.memory_max = min_memory(
+                    limits.memory_max,
+                    Information::new::<information::byte>(bytes),
+                );
+            }
+        }
+        if let Some(value) = read_limit(dir.join("cpu.max")).await? {
+            if let Some(cpu_max) = parse_cpu_max(&value)? {
+                limits.cpu_max = min_cpu(limits.cpu_max, cpu_max);
+            }
+        }
+    }
+
+    Ok(limits)
+}
+
+async fn limits_v1(root: &Path, memberships: &[Membership<'_>]) -> Result<CgroupLimits> {
+    let mut limits = CgroupLimits::default();
+    let find = |controller: &str| {
+        memberships.iter().find(|membership| {
+            membership
+                .controllers
+                .split(',')
+                .any(|name| name == controller)
+        })
+    };
+
+    if let Some(membership) = find("memory") {
+        for dir in hierarchy(&root.join("memory"), membership.path) {
+            if let Some(value) = read_limit(dir.join("memory.limit_in_bytes")).await? {
+                let bytes = value.parse::<u64>()?;
+                if bytes < V1_MEMORY_UNLIMITED {
+                    limits.memory_max = min_memory(
+                        limits.memory_max,
+                        Information::new::<information::byte>(bytes),
+                    );
+                }
+            }
+        }
+    }
+
+    if let Some(membership) = find("cpu") {
+        // Controllers might be co-mounted, ex. at the `/sys/fs/cgroup/cpu,cpuacct`
+        let mut mount = root.join(membership.controllers);
+        if !mount.is_dir() {
+            mount = root.join("cpu");
+        }
+        for dir in hierarchy(&mount, membership.path) {
+            let quota = read_limit(dir.join("cpu.cfs_quota_us")).await?;
+            let period = read_limit(dir.join("cpu.cfs_period_us")).await?;
+            if let (Some(quota), Some(period)) = (quota, period) {
+                // Negative quota means no limit
+                let quota = quota.parse::<i64>()?;
+                let period = period.parse::<u64>()?;
+                if quota > 0 && period > 0 {
+                    let cpu_max = (
+                        Time::new::<time::microsecond>(quota as f64),
+                        Time::new::<time::microsecond>(period as f64),
+                    );
+                    limits.cpu_max = min_cpu(limits.cpu_max, cpu_max);
+                }
+            }
+        }
+    }
+
+    Ok(limits)
+}
+
+pub async fn cgroup_limits(pid: Pid) -> ProcessResult<CgroupLimits> {
+    let path = process_file_path(pid, "cgroup");
+    let contents = match rt::fs::read_to_string(&path).await {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(ProcessError::NoSuchProcess(pid))
+        }
+        Err(e) => return Err(Error::from(e).with_file(path).into()),
+    };
+    let memberships = parse_memberships(&contents)?;
+    let root = rt::linux::sysfs_root().join("fs/cgroup");
+
+    // Unified hierarchy is mounted right at the `/sys/fs/cgroup`,
+    // otherwise controllers are either in the legacy or in the hybrid mode
+    let limits = if root.join("cgroup.controllers").exists() {
+        let path = memberships
+            .iter()
+            .find(|membership| membership.controllers.is_empty())
+            .map(|membership| membership.path)
+            .unwrap_or("/");
+
+        limits_v2(&root, path).await?
+    } else {
+        limits_v1(&root, &memberships).await?
+    };
+
+    Ok(limits)
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::{time, Time};
+
+    use super::{parse_cpu_max, parse_memberships, Membership};
+
+    #[test]
+    fn test_parse_memberships() {
+        let contents = "4:memory:/docker/abc\n3:cpu,cpuacct:/docker/abc\n0::/\n";
+        let memberships = parse_memberships(contents).unwrap();
+
+        assert_eq!(
+            vec![
+                Membership {
+                    controllers: "memory",
+                    path: "/docker/abc"
+                },
+                Membership {
+                    controllers: "cpu,cpuacct",
+                    path: "/docker/abc"
+                },
+                Membership {
+                    controllers: "",
+                    path: "/"
+                },
+            ],
+            memberships
+        );
+
+        assert!(parse_memberships("invalid").is_err());
+    }
+
+    #[test]
+    fn test_parse_cpu_max() {
+        assert_eq!(None, parse_cpu_max("max 100000").unwrap());
+        assert_eq!(
+            Some((
+                Time::new::<time::microsecond>(50_000.0),
+                Time::new::<time::microsecond>(100_000.0)
+            )),
+            parse_cpu_max("50000 100000").unwrap()
+        );
+        assert!(parse_cpu_max("fifty 100000").is_err());
+    }
+}
@@ -1,21 +1,35 @@
 use std::cmp;
+use std::ffi::OsStr;
 use std::hash;
 use std::io;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
 
 use heim_common::prelude::*;
 use heim_common::units::Time;
 use heim_runtime as rt;
 
 use super::{pid_exists, pids};
-use crate::os::unix::Signal;
+use crate::os::linux::{CgroupLimits, MemoryMap, Resource, SchedPolicy};
+use crate::os::unix::{Connection, ConnectionKind, Gids, OpenFile, Signal, Uids};
+use crate::process::attribute;
 use crate::sys::common::UniqueId;
 use crate::sys::linux::process::procfs::process_file_path;
-use crate::sys::unix::{pid_kill, pid_priority, pid_setpriority, pid_wait};
-use crate::{Pid, ProcessError, ProcessResult, Status};
-
+use crate::sys::unix::{
+    pid_kill, pid_priority, pid_setpriority, pid_wait, poll_exit, terminal_path, uid_username,
+};
+use crate::sys::Thread;
+use crate::{
+    CtxSwitches, GuardAction, Pid, ProcessError, ProcessFields, ProcessInfo, ProcessOneShot,
+    ProcessResult, Status,
+};
+
+mod affinity;
+mod cgroup;
 mod procfs;
+mod rlimit;
+mod sched;
 
 pub use self::procfs::{Command, CommandIter, CpuTime, Environment, IoCounters, Memory};
 
@@ -39,38 +53,24 @@ impl Process {
     pub async fn name(&self) -> ProcessResult<String> {
         let procfs::Stat { name, .. } = procfs::stat(self.pid).await?;
 
-        // TODO: Move `15` to the const
-        if name.len() >= 15 {
-            let command = match procfs::command(self.pid).await {
-                Ok(command) => command,
-                // Reading process command might fail, so we should better fall back to what we got
-                Err(..) => return Ok(name),
-            };
-
-            // There might be an absolute path to executable
-            let path = command
-                .into_iter()
-                .next()
-                .map(Path::new)
-                .and_then(Path::file_name);
-
-            match path {
-                // We can assume that on Linux paths and filenames are UTF-8,
-                // and since OsStr does not has the `starts_with` method,
-                // we could compare raw bytes
-                Some(exe) if exe.as_bytes().starts_with(name.as_bytes()) => {
-                    Ok(exe.to_string_lossy().into_owned())
-                }
-                _ => Ok(name),
-            }
-        } else {
-            Ok(name)
+        Ok(full_name(self.pid, name).await)
+    }
+
+    pub async fn terminal(&self) -> ProcessResult<Option<PathBuf>> {
+        let procfs::Stat { tty_nr, .. } = procfs::stat(self.pid).await?;
+        if tty_nr == 0 {
+            return Ok(None);
         }
+
+        // `tty_nr` is encoded the same way as the `dev_t` values are
+        let rdev = tty_nr as u32 as u64;
+        Ok(rt::spawn_blocking(move || terminal_path(rdev)).await)
     }
 
     pub async fn exe(&self) -> ProcessResult<PathBuf> {
         match rt::fs::read_link(process_file_path(self.pid, "exe")).await {
-            Ok(path) => Ok(path),
+            Ok(link) => Ok(exe_link_target(link).await.0),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => self.exe_fallback().await,
             Err(..) => {
                 // log::trace!() ?
 
@@ -84,10 +84,51 @@ impl Process {
         }
     }
 
+    pub async fn exe_deleted(&self) -> ProcessResult<bool> {
+        match rt::fs::read_link(process_file_path(self.pid, "exe")).await {
+            Ok(link) => Ok(exe_link_target(link).await.1),
+            // Kernel threads have no executable at all
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                self.exe_fallback().await.map(|_| false)
+            }
+            Err(..) if pid_exists(self.pid).await? => Err(ProcessError::AccessDenied(self.pid)),
+            Err(..) => Err(ProcessError::NoSuchProcess(self.pid)),
+        }
+    }
+
     pub async fn command(&self) -> ProcessResult<Command> {
         procfs::command(self.pid).await
     }
 
+    /// `/proc/{pid}/exe` link is missing for kernel threads and zombies,
+    /// and also might be unavailable for some sandboxed processes,
+    /// so the first command line argument is used instead.
+    async fn exe_fallback(&self) -> ProcessResult<PathBuf> {
+        let command = self.command().await?;
+        let path = match command.into_iter().next() {
+            Some(arg) if !arg.is_empty() => PathBuf::from(arg),
+            _ => {
+                return match self.status().await? {
+                    Status::Zombie => Err(ProcessError::ZombieProcess(self.pid)),
+                    _ => Err(ProcessError::NoExecutable(self.pid)),
+                }
+            }
+        };
+
+        let path = if path.is_absolute() {
+            path
+        } else {
+            self.cwd().await?.join(path)
+        };
+
+        // Argument might be a symlink or contain `..` components,
+        // but it also might be missing at all, if process changed its `argv[0]`
+        match rt::fs::canonicalize(&path).await {
+            Ok(canonical) => Ok(canonical),
+            Err(..) => Ok(path),
+        }
+    }
+
     pub async fn cwd(&self) -> ProcessResult<PathBuf> {
         match rt::fs::read_link(process_file_path(self.pid, "cwd")).await {
             Ok(path) => Ok(path),
@@ -122,6 +163,35 @@ impl Process {
         procfs::stat(self.pid).await.map(Into::into)
     }
 
+    pub async fn num_ctx_switches(&self) -> ProcessResult<CtxSwitches> {
+        let procfs::ProcStatus {
+            voluntary_ctxt_switches,
+            nonvoluntary_ctxt_switches,
+            ..
+        } = procfs::status(self.pid).await?;
+
+        Ok(CtxSwitches::new(
+            voluntary_ctxt_switches,
+            nonvoluntary_ctxt_switches,
+        ))
+    }
+
+    pub async fn threads(&self) -> ProcessResult<impl Stream<Item = ProcessResult<Thread>>> {
+        procfs::threads(self.pid).await
+    }
+
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        procfs::status(self.pid).await.map(|status| status.threads)
+    }
+
+    pub async fn status_and_threads(&self) -> ProcessResult<(Status, u64)> {
+        let procfs::Stat {
+            state, num_threads, ..
+        } = procfs::stat(self.pid).await?;
+
+        Ok((state, num_threads))
+    }
+
     pub async fn memory(&self) -> ProcessResult<Memory> {
         procfs::stat_memory(self.pid).await
     }
@@ -134,20 +204,67 @@ impl Process {
         pid_setpriority(self.pid, value)
     }
 
-    pub async fn is_running(&self) -> ProcessResult<bool> {
-        let other = get(self.pid).await?;
+    pub async fn cpu_affinity(&self) -> ProcessResult<Vec<usize>> {
+        affinity::cpu_affinity(self.pid)
+    }
 
-        Ok(other == *self)
+    pub async fn set_cpu_affinity(&self, cpus: &[usize]) -> ProcessResult<()> {
+        affinity::set_cpu_affinity(self.pid, cpus)
     }
 
-    pub async fn _signal(&self, signal: Signal) -> ProcessResult<()> {
-        if self.is_running().await? {
-            pid_kill(self.pid, signal)
-        } else {
-            Err(ProcessError::NoSuchProcess(self.pid))
+    pub async fn uids(&self) -> ProcessResult<Uids> {
+        let procfs::ProcStatus { uids, .. } = procfs::status(self.pid).await?;
+
+        Ok(uids)
+    }
+
+    pub async fn gids(&self) -> ProcessResult<Gids> {
+        let procfs::ProcStatus { gids, .. } = procfs::status(self.pid).await?;
+
+        Ok(gids)
+    }
+
+    pub async fn groups(&self) -> ProcessResult<Vec<u32>> {
+        let procfs::ProcStatus { groups, .. } = procfs::status(self.pid).await?;
+
+        Ok(groups)
+    }
+
+    pub async fn username(&self) -> ProcessResult<String> {
+        let uids = self.uids().await?;
+
+        uid_username(uids.effective()).await
+    }
+
+    pub async fn num_fds(&self) -> ProcessResult<u64> {
+        procfs::num_fds(self.pid).await
+    }
+
+    pub async fn open_files(&self) -> ProcessResult<BoxStream<'static, ProcessResult<OpenFile>>> {
+        procfs::open_files(self.pid).await
+    }
+
+    pub async fn connections(
+        &self,
+        kind: ConnectionKind,
+    ) -> ProcessResult<BoxStream<'static, ProcessResult<Connection>>> {
+        let connections = procfs::connections(self.pid, kind).await?;
+
+        Ok(stream::iter(connections).map(Ok).boxed())
+    }
+
+    pub async fn is_running(&self) -> ProcessResult<bool> {
+        match get(self.pid).await {
+            Ok(other) => Ok(other == *self),
+            Err(ProcessError::NoSuchProcess(..)) => Ok(false),
+            Err(e) => Err(e),
         }
     }
 
+    pub async fn _signal(&self, signal: Signal) -> ProcessResult<()> {
+        pid_kill(self.pid, signal)
+    }
+
     pub fn signal(&self, signal: Signal) -> BoxFuture<ProcessResult<()>> {
         self._signal(signal).boxed()
     }
@@ -168,8 +285,65 @@ impl Process {
         self.signal(Signal::Kill).await
     }
 
-    pub async fn wait(&self) -> ProcessResult<()> {
-        pid_wait(self.pid).await
+    pub fn kill_sync(&self, action: GuardAction) -> ProcessResult<()> {
+        let signal = match action {
+            GuardAction::Terminate => Signal::Term,
+            GuardAction::Kill => Signal::Kill,
+        };
+
+        pid_kill(self.pid, signal)
+    }
+
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        if !self.is_running().await? {
+            return Ok(None);
+        }
+
+        match pid_wait(self.pid).await? {
+            Some(status) => Ok(Some(status)),
+            // Not a child of the current process, exit status is not available
+            None => {
+                poll_exit(|| self.is_running()).await?;
+                Ok(None)
+            }
+        }
+    }
+
+    pub async fn oneshot(&self, fields: ProcessFields) -> ProcessResult<ProcessOneShot> {
+        let mut oneshot = ProcessOneShot::default();
+
+        // `/proc/{pid}/stat` is read once for all of these
+        let stat_fields = ProcessFields::PARENT_PID
+            | ProcessFields::NAME
+            | ProcessFields::STATUS
+            | ProcessFields::CPU_TIME;
+        if fields.intersects(stat_fields) {
+            if let Some(stat) = attribute(procfs::stat(self.pid).await)? {
+                if fields.contains(ProcessFields::PARENT_PID) {
+                    oneshot.parent_pid = Some(stat.ppid);
+                }
+                if fields.contains(ProcessFields::STATUS) {
+                    oneshot.status = Some(stat.state);
+                }
+                if fields.contains(ProcessFields::NAME) {
+                    oneshot.name = Some(full_name(self.pid, stat.name.clone()).await);
+                }
+                if fields.contains(ProcessFields::CPU_TIME) {
+                    oneshot.cpu_time = Some(CpuTime::from(stat).into());
+                }
+            }
+        }
+        if fields.contains(ProcessFields::CREATE_TIME) {
+            oneshot.create_time = Some(self.unique_id.create_time());
+        }
+        if fields.contains(ProcessFields::MEMORY) {
+            oneshot.memory = attribute(procfs::stat_memory(self.pid).await)?.map(Into::into);
+        }
+        if fields.contains(ProcessFields::IO_COUNTERS) {
+            oneshot.io_counters = attribute(procfs::io(self.pid).await)?.map(Into::into);
+        }
+
+        Ok(oneshot)
     }
 
     // Linux-specific methods
@@ -195,6 +369,74 @@ impl Process {
 
         Ok(stream.map_err(Into::into).boxed())
     }
+
+    pub async fn memory_maps(&self) -> ProcessResult<BoxStream<'static, ProcessResult<MemoryMap>>> {
+        procfs::memory_maps(self.pid).await
+    }
+
+    pub async fn rlimit(&self, resource: Resource) -> ProcessResult<(u64, u64)> {
+        rlimit::rlimit(self.pid, resource)
+    }
+
+    pub async fn set_rlimit(&self, resource: Resource, limits: (u64, u64)) -> ProcessResult<()> {
+        rlimit::set_rlimit(self.pid, resource, limits)
+    }
+
+    pub async fn cgroup_limits(&self) -> ProcessResult<CgroupLimits> {
+        cgroup::cgroup_limits(self.pid).await
+    }
+
+    pub async fn sched_policy(&self) -> ProcessResult<(SchedPolicy, i32)> {
+        sched::sched_policy(self.pid)
+    }
+
+    pub async fn set_sched_policy(&self, policy: SchedPolicy, priority: i32) -> ProcessResult<()> {
+        sched::set_sched_policy(self.pid, policy, priority)
+    }
+}
+
+/// Maps the error of the syscalls operating with the process by its `pid`,
+/// ex. `sched_setaffinity` or `prlimit64`.
+fn map_error(pid: Pid, e: Error) -> ProcessError {
+    match e.raw_os_error() {
+        Some(libc::ESRCH) => ProcessError::NoSuchProcess(pid),
+        // Process owner differs from the caller,
+        // or the caller lacks the required capability (ex. `CAP_SYS_NICE`)
+        Some(libc::EPERM) => ProcessError::AccessDenied(pid),
+        _ => e.into(),
+    }
+}
+
+/// Process name from the `/proc/{pid}/stat` is truncated to 15 chars,
+/// so the full one is restored from the process command if possible.
+async fn full_name(pid: Pid, name: String) -> String {
+    // TODO: Move `15` to the const
+    if name.len() < 15 {
+        return name;
+    }
+
+    let command = match procfs::command(pid).await {
+        Ok(command) => command,
+        // Reading process command might fail, so we should better fall back to what we got
+        Err(..) => return name,
+    };
+
+    // There might be an absolute path to executable
+    let path = command
+        .into_iter()
+        .next()
+        .map(Path::new)
+        .and_then(Path::file_name);
+
+    match path {
+        // We can assume that on Linux paths and filenames are UTF-8,
+        // and since OsStr does not has the `starts_with` method,
+        // we could compare raw bytes
+        Some(exe) if exe.as_bytes().starts_with(name.as_bytes()) => {
+            exe.to_string_lossy().into_owned()
+        }
+        _ => name,
+    }
 }
 
 impl hash::Hash for Process {
@@ -211,6 +453,28 @@ impl cmp::PartialEq for Process {
 
 impl cmp::Eq for Process {}
 
+/// Kernel appends the " (deleted)" suffix to the `/proc/{pid}/exe` link target
+/// if the process executable was removed or replaced on disk.
+///
+/// Returns the link target with the suffix stripped and whether it was there.
+async fn exe_link_target(link: PathBuf) -> (PathBuf, bool) {
+    const DELETED_SUFFIX: &[u8] = b" (deleted)";
+
+    let bytes = link.as_os_str().as_bytes();
+    if !bytes.ends_with(DELETED_SUFFIX) {
+        return (link, false);
+    }
+
+    // Executable file might be named with that suffix for real
+    if rt::fs::path_exists(link.clone()).await {
+        return (link, false);
+    }
+
+    let stripped = OsStr::from_bytes(&bytes[..bytes.len() - DELETED_SUFFIX.len()]);
+
+    (PathBuf::from(stripped), true)
+}
+
 pub async fn processes() -> Result<impl Stream<Item = ProcessResult<Process>>> {
     let pids = pids().await?;
 
@@ -218,6 +482,30 @@ pub async fn processes() -> Result<impl Stream<Item = ProcessResult<Process>>> {
     Ok(stream)
 }
 
+pub async fn processes_with_info(
+) -> Result<impl Stream<Item = ProcessResult<(Process, ProcessInfo)>>> {
+    let pids = pids().await?;
+
+    let stream = pids.map_err(Into::into).and_then(|pid| async move {
+        let procfs::Stat {
+            name,
+            state,
+            ppid,
+            create_time,
+            ..
+        } = procfs::stat(pid).await?;
+        let process = Process {
+            pid,
+            unique_id: UniqueId::new(pid, create_time),
+        };
+        let name = full_name(pid, name).await;
+
+        Ok((process, ProcessInfo::new(ppid, name, state, create_time)))
+    });
+
+    Ok(stream)
+}
+
 pub async fn get(pid: Pid) -> ProcessResult<Process> {
     let procfs::Stat { create_time, .. } = procfs::stat(pid).await?;
 
@@ -228,7 +516,45 @@ pub async fn get(pid: Pid) -> ProcessResult<Process> {
 }
 
 pub async fn current() -> ProcessResult<Process> {
-    let pid = unsafe { libc::getpid() };
+    let pid = Pid::from(unsafe { libc::getpid() });
 
     get(pid).await
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use heim_common::units::{time, Time};
+
+    use super::Process;
+    use crate::sys::common::UniqueId;
+
+    fn process(pid: libc::pid_t, create_time: f64) -> crate::Process {
+        let pid = crate::Pid::from(pid);
+        crate::Process::from(Process {
+            pid,
+            unique_id: UniqueId::new(pid, Time::new::<time::second>(create_time)),
+        })
+    }
+
+    fn hash(process: &crate::Process) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        process.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_process_reused_pid() {
+        let first = process(42, 1_600_000_000.0);
+        let same = process(42, 1_600_000_000.0);
+        let reused = process(42, 1_600_000_100.0);
+
+        assert_eq!(first.pid(), reused.pid());
+        assert_eq!(first, same);
+        assert_eq!(hash(&first), hash(&same));
+        assert_ne!(first, reused);
+        assert_ne!(hash(&first), hash(&reused));
+    }
+}
@@ -140,14 +140,20 @@ impl<'a> Iterator for CommandIter<'a> {
 
                 Some(OsStr::from_bytes(slice))
             }
-            None => None,
+            // Process might rewrite its own command line without the trailing delimiter,
+            // in that case, the rest of the line is the last argument
+            None => {
+                self.position = self.line.len();
+
+                Some(OsStr::from_bytes(bytes))
+            }
         }
     }
 }
 
 pub async fn command(pid: Pid) -> ProcessResult<Command> {
-    match rt::fs::read_to_string(process_file_path(pid, "cmdline")).await {
-        Ok(contents) => Ok(Command::from(contents)),
+    match rt::fs::read(process_file_path(pid, "cmdline")).await {
+        Ok(contents) => Ok(Command::from(OsString::from_vec(contents))),
         Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
         Err(e) => Err(e.into()),
     }
@@ -185,6 +191,28 @@ mod tests {
         assert_eq!(None, iter.next());
     }
 
+    #[test]
+    fn test_iter_without_trailing_delimiter() {
+        let line = OsString::from("nginx: worker process");
+        let command = Command::from(line);
+        let iter = &mut command.into_iter();
+
+        assert_eq!(Some(OsStr::new("nginx: worker process")), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn test_iter_empty_trailing_args() {
+        let line = OsString::from("/usr/bin/foo\0\0\0");
+        let command = Command::from(line);
+        let iter = &mut command.into_iter();
+
+        assert_eq!(Some(OsStr::new("/usr/bin/foo")), iter.next());
+        assert_eq!(Some(OsStr::new("")), iter.next());
+        assert_eq!(Some(OsStr::new("")), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
     #[test]
     fn test_iter_empty() {
         let command = Command::from("");
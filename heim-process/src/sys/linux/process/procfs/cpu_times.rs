@@ -6,11 +6,20 @@ use super::Stat;
 pub struct CpuTime {
     utime: Time,
     stime: Time,
-    children_utime: Time,
-    children_stime: Time,
+    children_utime: Option<Time>,
+    children_stime: Option<Time>,
 }
 
 impl CpuTime {
+    pub fn new(user: Time, system: Time) -> CpuTime {
+        CpuTime {
+            utime: user,
+            stime: system,
+            children_utime: None,
+            children_stime: None,
+        }
+    }
+
     pub fn user(&self) -> Time {
         self.utime
     }
@@ -18,6 +27,14 @@ impl CpuTime {
     pub fn system(&self) -> Time {
         self.stime
     }
+
+    pub fn children_user(&self) -> Option<Time> {
+        self.children_utime
+    }
+
+    pub fn children_system(&self) -> Option<Time> {
+        self.children_stime
+    }
 }
 
 impl From<Stat> for CpuTime {
@@ -25,8 +42,8 @@ impl From<Stat> for CpuTime {
         CpuTime {
             utime: stat.utime,
             stime: stat.stime,
-            children_utime: stat.cutime,
-            children_stime: stat.cstime,
+            children_utime: Some(stat.cutime),
+            children_stime: Some(stat.cstime),
         }
     }
 }
@@ -2,9 +2,9 @@ use std::io;
 
 use heim_runtime as rt;
 
-use crate::{Pid, ProcessError, ProcessResult};
+use crate::{Pid, ProcessError, ProcessResult, Status};
 
-use crate::sys::linux::process::procfs::process_file_path;
+use crate::sys::linux::process::procfs::{process_file_path, stat, Stat};
 pub use crate::sys::unix::{Environment, IntoEnvironmentIter};
 
 pub async fn environment(pid: Pid) -> ProcessResult<Environment> {
@@ -16,6 +16,16 @@ pub async fn environment(pid: Pid) -> ProcessResult<Environment> {
         Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
             Err(ProcessError::AccessDenied(pid))
         }
+        // Processes without an address space (kernel threads and zombies)
+        // have no environment at all and reading fails with `ESRCH`
+        Err(e) if e.raw_os_error() == Some(libc::ESRCH) => match stat(pid).await {
+            Ok(Stat {
+                state: Status::Zombie,
+                ..
+            }) => Err(ProcessError::ZombieProcess(pid)),
+            Ok(..) => Ok(Environment::from_bytes(&[])),
+            Err(e) => Err(e),
+        },
         Err(e) => Err(e.into()),
     }
 }
@@ -0,0 +1,166 @@
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use super::process_file_path;
+use crate::os::unix::{FileMode, OpenFile};
+use crate::{Pid, ProcessError, ProcessResult};
+
+pub(super) fn catch_fd_error(e: io::Error, pid: Pid, path: &Path) -> ProcessError {
+    match e.kind() {
+        io::ErrorKind::NotFound => ProcessError::NoSuchProcess(pid),
+        io::ErrorKind::PermissionDenied => ProcessError::AccessDenied(pid),
+        _ => Error::from(e).with_file(path).into(),
+    }
+}
+
+pub async fn num_fds(pid: Pid) -> ProcessResult<u64> {
+    let path = process_file_path(pid, "fd");
+    let entries = rt::fs::read_dir(&path)
+        .await
+        .map_err(|e| catch_fd_error(e, pid, &path))?;
+
+    entries
+        .try_fold(0, |count, _| future::ok(count + 1))
+        .await
+        .map_err(|e| catch_fd_error(e, pid, &path))
+}
+
+/// Parsed `/proc/<pid>/fdinfo/<fd>` file.
+///
+/// Only the fields used by `heim` are loaded,
+/// see `proc(5)` for the rest of them.
+#[derive(Debug, Default, PartialEq)]
+struct FdInfo {
+    position: u64,
+    flags: libc::c_int,
+}
+
+impl FdInfo {
+    fn mode(&self) -> FileMode {
+        let append = self.flags & libc::O_APPEND != 0;
+        match self.flags & libc::O_ACCMODE {
+            libc::O_RDONLY => FileMode::Read,
+            _ if append => FileMode::Append,
+            libc::O_WRONLY => FileMode::Write,
+            _ => FileMode::ReadWrite,
+        }
+    }
+}
+
+impl FromStr for FdInfo {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut info = FdInfo::default();
+        for line in s.lines() {
+            let mut parts = line.split_ascii_whitespace();
+            match parts.try_next()? {
+                "pos:" => {
+                    info.position = parts.try_parse_next()?;
+                }
+                // Flags are represented as an octal number
+                "flags:" => {
+                    info.flags = libc::c_int::from_str_radix(parts.try_next()?, 8)?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+/// Loads the `fdinfo` for the file descriptor, `None` is returned if it was closed already.
+async fn fd_info(pid: Pid, fd: libc::c_int) -> ProcessResult<Option<FdInfo>> {
+    let path = process_file_path(pid, "fdinfo").join(fd.to_string());
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => FdInfo::from_str(&contents)
+            .map(Some)
+            .map_err(|e| e.with_file(path).into()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(catch_fd_error(e, pid, &path)),
+    }
+}
+
+pub async fn open_files(pid: Pid) -> ProcessResult<BoxStream<'static, ProcessResult<OpenFile>>> {
+    let path = process_file_path(pid, "fd");
+    let entries = rt::fs::read_dir(&path)
+        .await
+        .map_err(|e| catch_fd_error(e, pid, &path))?;
+
+    let stream = entries
+        .map_err(move |e| catch_fd_error(e, pid, &path))
+        .try_filter_map(move |entry| async move {
+            let fd = match entry.file_name().to_str().map(str::parse::<libc::c_int>) {
+                Some(Ok(fd)) => fd,
+                _ => return Ok(None),
+            };
+
+            // File descriptor might be closed at any moment,
+            // so the `NotFound` errors are simply skipped below
+            let link = entry.path();
+            let target = match rt::fs::read_link(&link).await {
+                Ok(target) => target,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(catch_fd_error(e, pid, &link)),
+            };
+
+            // Sockets, pipes and anonymous inodes are represented
+            // as a `type:[inode]` strings instead of an absolute path
+            if !target.is_absolute() {
+                return Ok(None);
+            }
+
+            match rt::fs::metadata(&link).await {
+                Ok(metadata) if metadata.is_file() => {}
+                Ok(..) => return Ok(None),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(catch_fd_error(e, pid, &link)),
+            }
+
+            match fd_info(pid, fd).await? {
+                Some(info) => Ok(Some(OpenFile::new(
+                    target,
+                    fd,
+                    Some(info.mode()),
+                    Some(info.position),
+                ))),
+                None => Ok(None),
+            }
+        });
+
+    Ok(stream.boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::FdInfo;
+    use crate::os::unix::FileMode;
+
+    #[test]
+    fn test_parse_fd_info() {
+        let contents = "pos:\t4096\nflags:\t0102001\nmnt_id:\t26\nino:\t1052\n";
+        let info = FdInfo::from_str(contents).unwrap();
+
+        assert_eq!(info.position, 4096);
+        assert_eq!(info.mode(), FileMode::Append);
+    }
+
+    #[test]
+    fn test_fd_info_mode() {
+        let mode = |flags| FdInfo { position: 0, flags }.mode();
+
+        assert_eq!(mode(libc::O_RDONLY | libc::O_CLOEXEC), FileMode::Read);
+        assert_eq!(mode(libc::O_WRONLY), FileMode::Write);
+        assert_eq!(mode(libc::O_RDWR), FileMode::ReadWrite);
+        assert_eq!(mode(libc::O_WRONLY | libc::O_APPEND), FileMode::Append);
+        assert_eq!(mode(libc::O_RDWR | libc::O_APPEND), FileMode::Append);
+    }
+}
@@ -1,3 +1,4 @@
+use std::io;
 use std::str::FromStr;
 
 use heim_common::prelude::*;
@@ -42,6 +43,14 @@ impl fmt::Debug for IoCounters {
 }
 
 impl IoCounters {
+    pub fn read_count(&self) -> u64 {
+        self.syscr
+    }
+
+    pub fn write_count(&self) -> u64 {
+        self.syscw
+    }
+
     /// The number of bytes which this task has caused to be read from storage.
     pub fn chars_read(&self) -> Information {
         Information::new::<information::byte>(self.rchar)
@@ -110,9 +119,13 @@ impl FromStr for IoCounters {
 
 pub async fn io(pid: Pid) -> ProcessResult<IoCounters> {
     let path = process_file_path(pid, "io");
-    match rt::fs::read_to_string(path).await {
+    match rt::fs::read_to_string(&path).await {
         Ok(contents) => IoCounters::from_str(&contents).map_err(Into::into),
-        Err(e) if e.raw_os_error() == Some(libc::EACCES) => Err(ProcessError::AccessDenied(pid)),
-        Err(e) => Err(e.into()),
+        // `io` file is readable by the process owner only
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+            Err(ProcessError::AccessDenied(pid))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
+        Err(e) => Err(Error::from(e).with_file(path).into()),
     }
 }
@@ -1,15 +1,25 @@
 mod command;
 mod cpu_times;
 mod env;
+mod fd;
 mod io;
+mod net;
 mod paths;
+mod smaps;
 mod stat;
 mod statm;
+mod status;
+mod threads;
 
 pub use self::command::{command, Command, CommandIter};
 pub use self::cpu_times::CpuTime;
 pub use self::env::{environment, Environment, IntoEnvironmentIter};
+pub use self::fd::{num_fds, open_files};
 pub use self::io::{io, IoCounters};
+pub use self::net::connections;
 pub use self::paths::process_file_path;
+pub use self::smaps::memory_maps;
 pub use self::stat::{stat, Stat};
 pub use self::statm::{stat_memory, Memory};
+pub use self::status::{status, ProcStatus};
+pub use self::threads::threads;
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::io;
+
+use heim_common::prelude::*;
+use heim_net::os::linux::ConnectionExt;
+use heim_runtime as rt;
+
+use super::fd::catch_fd_error;
+use super::process_file_path;
+use crate::os::unix::{Connection, ConnectionKind};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Returns socket inodes opened by process, mapped to their file descriptors.
+async fn socket_inodes(pid: Pid) -> ProcessResult<HashMap<u64, libc::c_int>> {
+    let path = process_file_path(pid, "fd");
+    let mut entries = rt::fs::read_dir(&path)
+        .await
+        .map_err(|e| catch_fd_error(e, pid, &path))?;
+
+    let mut inodes = HashMap::new();
+    while let Some(entry) = entries.next().await {
+        let entry = entry.map_err(|e| catch_fd_error(e, pid, &path))?;
+        let fd = match entry.file_name().to_str().map(str::parse::<libc::c_int>) {
+            Some(Ok(fd)) => fd,
+            _ => continue,
+        };
+
+        // File descriptor might be closed at any moment, skipping it in that case
+        let target = match rt::fs::read_link(entry.path()).await {
+            Ok(target) => target,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(catch_fd_error(e, pid, &entry.path())),
+        };
+
+        // Socket links are looking like `socket:[12345]`
+        let inode = target
+            .to_str()
+            .and_then(|target| target.strip_prefix("socket:["))
+            .and_then(|target| target.strip_suffix(']'))
+            .and_then(|inode| inode.parse::<u64>().ok());
+        if let Some(inode) = inode {
+            let _ = inodes.insert(inode, fd);
+        }
+    }
+
+    Ok(inodes)
+}
+
+pub async fn connections(pid: Pid, kind: ConnectionKind) -> ProcessResult<Vec<Connection>> {
+    let inodes = socket_inodes(pid).await?;
+    if inodes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let entries = heim_net::os::linux::connections_for_pid(pid, kind)
+        .await
+        .map_err(|e| catch_net_error(e, pid))?;
+
+    entries
+        .map_err(|e| catch_net_error(e, pid))
+        .try_filter_map(|entry| {
+            let connection = inodes.get(&entry.inode()).map(|fd| {
+                Connection::new(
+                    *fd,
+                    entry.protocol(),
+                    entry.local_address(),
+                    entry.remote_address(),
+                    entry.state(),
+                )
+            });
+
+            future::ok(connection)
+        })
+        .try_collect()
+        .await
+}
+
+fn catch_net_error(e: Error, pid: Pid) -> ProcessError {
+    match e.kind() {
+        io::ErrorKind::NotFound => ProcessError::NoSuchProcess(pid),
+        io::ErrorKind::PermissionDenied => ProcessError::AccessDenied(pid),
+        _ => e.into(),
+    }
+}
@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use super::fd::catch_fd_error;
+use super::process_file_path;
+use crate::os::linux::MemoryMap;
+use crate::{Pid, ProcessResult};
+
+/// Parses the mapping header line, ex.
+/// `7f2c4a3f1000-7f2c4a3f3000 rw-p 00000000 00:00 0      [heap]`.
+///
+/// Header line is recognized by the address range in the first column,
+/// all the other lines are `Key:    value` pairs.
+fn parse_header(line: &str) -> Option<MemoryMap> {
+    let mut rest = line;
+    // Address range, permissions, offset, device and inode
+    for idx in 0..5 {
+        rest = rest.trim_start();
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if idx == 0 && (rest[..end].ends_with(':') || !rest[..end].contains('-')) {
+            return None;
+        }
+        rest = &rest[end..];
+    }
+
+    let path = match rest.trim() {
+        "" => None,
+        path => Some(PathBuf::from(path)),
+    };
+    let zero = Information::new::<information::byte>(0);
+
+    Some(MemoryMap {
+        path,
+        rss: zero,
+        pss: zero,
+        shared_clean: zero,
+        shared_dirty: zero,
+        private_clean: zero,
+        private_dirty: zero,
+        swap: zero,
+    })
+}
+
+/// Parses the `Key:    value kB` line into the `map` field.
+fn parse_field(map: &mut MemoryMap, line: &str) -> Result<()> {
+    let mut parts = line.split_ascii_whitespace();
+    let field = match parts.try_next()? {
+        "Rss:" => &mut map.rss,
+        "Pss:" => &mut map.pss,
+        "Shared_Clean:" => &mut map.shared_clean,
+        "Shared_Dirty:" => &mut map.shared_dirty,
+        "Private_Clean:" => &mut map.private_clean,
+        "Private_Dirty:" => &mut map.private_dirty,
+        "Swap:" => &mut map.swap,
+        _ => return Ok(()),
+    };
+    // Kernel reports values in the `kB` units, which are kibibytes in fact
+    *field = Information::new::<information::kibibyte>(parts.try_parse_next()?);
+
+    Ok(())
+}
+
+/// Parses `/proc/{pid}/smaps` line by line,
+/// yielding each memory mapping as soon as the next one starts.
+pub async fn memory_maps(pid: Pid) -> ProcessResult<BoxStream<'static, ProcessResult<MemoryMap>>> {
+    let path = process_file_path(pid, "smaps");
+    let lines = match rt::fs::read_lines(path.clone()).await {
+        Ok(lines) => lines.boxed(),
+        Err(e) => return Err(catch_fd_error(e, pid, &path)),
+    };
+
+    let stream = stream::try_unfold(
+        (lines, None, path),
+        move |(mut lines, mut current, path)| async move {
+            loop {
+                let line = match lines.next().await {
+                    Some(Ok(line)) => line,
+                    Some(Err(e)) => return Err(catch_fd_error(e, pid, &path)),
+                    None => break,
+                };
+
+                match parse_header(&line) {
+                    Some(next) => {
+                        if let Some(map) = current.replace(next) {
+                            return Ok(Some((map, (lines, current, path))));
+                        }
+                    }
+                    None => {
+                        if let Some(map) = current.as_mut() {
+                            parse_field(map, &line).map_err(|e| e.with_file(&path))?;
+                        }
+                    }
+                }
+            }
+
+            Ok(current.take().map(|map| (map, (lines, None, path))))
+        },
+    );
+
+    Ok(stream.boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use heim_common::units::information;
+
+    use super::{parse_field, parse_header};
+
+    #[test]
+    fn test_parse_header() {
+        let map = parse_header("55d4a6e7e000-55d4a6e9f000 rw-p 00000000 00:00 0    [heap]");
+        assert_eq!(Some(Path::new("[heap]")), map.unwrap().path());
+
+        let map =
+            parse_header("7f2c4a3f1000-7f2c4a3f3000 r--p 00000000 08:01 1234  /usr/lib/my lib.so");
+        assert_eq!(Some(Path::new("/usr/lib/my lib.so")), map.unwrap().path());
+
+        let map = parse_header("7f2c4a3f1000-7f2c4a3f3000 rw-p 00000000 00:00 0 ");
+        assert_eq!(None, map.unwrap().path());
+
+        assert!(parse_header("Rss:                   4 kB").is_none());
+        assert!(parse_header("VmFlags: rd wr mr mw me ac sd").is_none());
+        assert!(parse_header("").is_none());
+    }
+
+    #[test]
+    fn test_parse_field() {
+        let mut map = parse_header("7f2c4a3f1000-7f2c4a3f3000 rw-p 00000000 00:00 0").unwrap();
+
+        parse_field(&mut map, "Rss:                  12 kB").unwrap();
+        parse_field(&mut map, "Private_Dirty:         8 kB").unwrap();
+        parse_field(&mut map, "THPeligible:    0").unwrap();
+        parse_field(&mut map, "VmFlags: rd wr mr mw me ac sd").unwrap();
+
+        assert_eq!(12, map.rss().get::<information::kibibyte>());
+        assert_eq!(8, map.private_dirty().get::<information::kibibyte>());
+        assert_eq!(0, map.swap().get::<information::kibibyte>());
+        assert!(parse_field(&mut map, "Swap: invalid kB").is_err());
+    }
+}
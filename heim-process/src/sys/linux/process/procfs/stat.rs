@@ -13,24 +13,20 @@ use crate::{Pid, ProcessError, ProcessResult, Status};
 impl Status {
     // Previously there were `TryFrom<char>` and `FromStr` implementations,
     // but they were leaking into the public API. See #260
-    fn try_from_char(value: char) -> Result<Status> {
+    fn from_char(value: char) -> Status {
         match value {
-            'R' => Ok(Status::Running),
-            'S' => Ok(Status::Sleeping),
-            'D' => Ok(Status::Waiting),
-            'Z' => Ok(Status::Zombie),
-            'T' => Ok(Status::Stopped),
-            't' => Ok(Status::Tracing),
-            'X' | 'x' => Ok(Status::Dead),
-            'K' => Ok(Status::Wakekill),
-            'W' => Ok(Status::Waking),
-            'P' => Ok(Status::Parked),
-            'I' => Ok(Status::Idle),
-            other => {
-                let inner = io::Error::from(io::ErrorKind::InvalidData);
-
-                Err(Error::from(inner).with_message(format!("Unknown process state {}", other)))
-            }
+            'R' => Status::Running,
+            'S' => Status::Sleeping,
+            'D' => Status::Waiting,
+            'Z' => Status::Zombie,
+            'T' => Status::Stopped,
+            't' => Status::Tracing,
+            'X' | 'x' => Status::Dead,
+            'K' => Status::Wakekill,
+            'W' => Status::Waking,
+            'P' => Status::Parked,
+            'I' => Status::Idle,
+            other => Status::Unknown(other),
         }
     }
 }
@@ -41,6 +37,8 @@ pub struct Stat {
     pub name: String,
     pub state: Status,
     pub ppid: Pid,
+    pub tty_nr: i32,
+    pub num_threads: u64,
     pub create_time: Time,
     pub utime: Time,
     pub stime: Time,
@@ -66,12 +64,12 @@ impl FromStr for Stat {
                 .chars()
                 .next()
                 .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))?;
-            Status::try_from_char(chr)
+            Ok(Status::from_char(chr))
         })?;
         let ppid: Pid = parts.try_parse_next()?;
         let _pgrp: i32 = parts.try_parse_next()?;
         let _session_id: i32 = parts.try_parse_next()?;
-        let _tty_nr: i32 = parts.try_parse_next()?;
+        let tty_nr: i32 = parts.try_parse_next()?;
         let _tpgid: i32 = parts.try_parse_next()?;
         let _flags: u32 = parts.try_parse_next()?;
         let _minflt: u64 = parts.try_parse_next()?;
@@ -84,7 +82,7 @@ impl FromStr for Stat {
         let cstime: u64 = parts.try_parse_next()?;
         let _priority: i64 = parts.try_parse_next()?;
         let _nice: i64 = parts.try_parse_next()?;
-        let _num_threads: i64 = parts.try_parse_next()?;
+        let num_threads: u64 = parts.try_parse_next()?;
         let _itrealvalue: i64 = parts.try_parse_next()?;
         let start_time: u64 = parts.try_parse_next()?;
         let _vsize: i64 = parts.try_parse_next()?;
@@ -107,6 +105,8 @@ impl FromStr for Stat {
             name,
             state,
             ppid,
+            tty_nr,
+            num_threads,
             create_time: Time::new::<time::second>(start_time),
             // TODO: Possible precision loss during the `as f64` cast
             utime: Time::new::<time::second>(utime as f64 / ticks),
@@ -135,3 +135,30 @@ pub async fn stat(pid: Pid) -> ProcessResult<Stat> {
 
     Ok(stats)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Stat;
+    use crate::Status;
+
+    const STAT: &str = "1234 (cat (1)) R 1 1234 1234 34816 1234 4194304 90 0 0 0 0 0 0 0 20 0 1 0 \
+                        4226958 5550080 128 18446744073709551615 1 1 0 0 0 0 0 0 0 0 0 0 17 3 0 0 0 0 0";
+
+    #[test]
+    fn test_parse_stat() {
+        let stat = Stat::from_str(STAT).unwrap();
+
+        assert_eq!("cat (1)", stat.name);
+        assert_eq!(Status::Running, stat.state);
+        assert_eq!(1, stat.num_threads);
+    }
+
+    #[test]
+    fn test_parse_unknown_state() {
+        let stat = Stat::from_str(&STAT.replacen(") R ", ") Q ", 1)).unwrap();
+
+        assert_eq!(Status::Unknown('Q'), stat.state);
+    }
+}
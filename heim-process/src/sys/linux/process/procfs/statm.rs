@@ -27,6 +27,16 @@ pub struct Memory {
 }
 
 impl Memory {
+    pub fn new(rss: Information, vms: Information) -> Memory {
+        Memory {
+            size: vms,
+            resident: rss,
+            shared: Information::default(),
+            text: Information::default(),
+            data: Information::default(),
+        }
+    }
+
     pub fn rss(&self) -> Information {
         self.resident
     }
@@ -65,6 +75,7 @@ impl FromStr for Memory {
         let text = parts
             .try_parse_next::<u64, _>()
             .map(|value| Information::new::<information::byte>(value * *PAGE_SIZE))?;
+        // `lib` and `dirty` fields are always zero since Linux 2.6
         let _lib = parts.next();
         let data = parts
             .try_parse_next::<u64, _>()
@@ -85,3 +96,24 @@ pub async fn stat_memory(pid: Pid) -> ProcessResult<Memory> {
         .await
         .map_err(Into::into)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::units::information;
+
+    use super::{Memory, PAGE_SIZE};
+
+    #[test]
+    fn test_parse_statm() {
+        let memory = Memory::from_str("5000 1200 600 20 0 900 0\n").unwrap();
+        let pages = |count: u64| count * *PAGE_SIZE;
+
+        assert_eq!(pages(5000), memory.vms().get::<information::byte>());
+        assert_eq!(pages(1200), memory.rss().get::<information::byte>());
+        assert_eq!(pages(600), memory.shared().get::<information::byte>());
+        assert_eq!(pages(20), memory.text().get::<information::byte>());
+        assert_eq!(pages(900), memory.data().get::<information::byte>());
+    }
+}
@@ -0,0 +1,133 @@
+use std::io;
+use std::result;
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use super::process_file_path;
+use crate::os::unix::{Gids, Uids};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Parsed `/proc/<pid>/status` file.
+///
+/// Only the fields used by `heim` are loaded,
+/// see `proc(5)` for the rest of them.
+#[derive(Debug, Default)]
+pub struct ProcStatus {
+    pub uids: Uids,
+    pub gids: Gids,
+    pub groups: Vec<u32>,
+    pub threads: u64,
+    pub voluntary_ctxt_switches: u64,
+    pub nonvoluntary_ctxt_switches: u64,
+}
+
+impl FromStr for ProcStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut status = ProcStatus::default();
+        for line in s.lines() {
+            let mut parts = line.split_ascii_whitespace();
+            match parts.try_next()? {
+                "Uid:" => {
+                    let (real, effective, saved) = parse_ids(&mut parts)?;
+                    status.uids = Uids::new(real, effective, saved);
+                }
+                "Gid:" => {
+                    let (real, effective, saved) = parse_ids(&mut parts)?;
+                    status.gids = Gids::new(real, effective, saved);
+                }
+                "Groups:" => {
+                    status.groups = parts
+                        .map(u32::from_str)
+                        .collect::<result::Result<Vec<_>, _>>()?;
+                }
+                "Threads:" => {
+                    status.threads = parts.try_parse_next()?;
+                }
+                "voluntary_ctxt_switches:" => {
+                    status.voluntary_ctxt_switches = parts.try_parse_next()?;
+                }
+                "nonvoluntary_ctxt_switches:" => {
+                    status.nonvoluntary_ctxt_switches = parts.try_parse_next()?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(status)
+    }
+}
+
+/// Parses `real effective saved fs` IDs line.
+fn parse_ids<'a, T>(parts: &mut T) -> Result<(u32, u32, u32)>
+where
+    T: Iterator<Item = &'a str>,
+{
+    Ok((
+        parts.try_parse_next()?,
+        parts.try_parse_next()?,
+        parts.try_parse_next()?,
+    ))
+}
+
+pub async fn status(pid: Pid) -> ProcessResult<ProcStatus> {
+    let path = process_file_path(pid, "status");
+    match rt::fs::read_to_string(&path).await {
+        Ok(contents) => ProcStatus::from_str(&contents).map_err(Into::into),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
+        Err(e) => Err(Error::from(e).with_file(path).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::ProcStatus;
+
+    #[test]
+    fn test_parse_ctx_switches() {
+        let contents = "Name:\tcat\nState:\tR (running)\nThreads:\t1\n\
+                        voluntary_ctxt_switches:\t12\nnonvoluntary_ctxt_switches:\t3\n";
+        let status = ProcStatus::from_str(contents).unwrap();
+
+        assert_eq!(status.voluntary_ctxt_switches, 12);
+        assert_eq!(status.nonvoluntary_ctxt_switches, 3);
+    }
+
+    #[test]
+    fn test_parse_threads() {
+        let contents = "Name:\tcat\nState:\tR (running)\nThreads:\t17\n";
+        let status = ProcStatus::from_str(contents).unwrap();
+
+        assert_eq!(status.threads, 17);
+    }
+
+    #[test]
+    fn test_parse_ids() {
+        let contents = "Name:\tsudo\nUid:\t1000\t0\t0\t0\nGid:\t1000\t1000\t27\t1000\n";
+        let status = ProcStatus::from_str(contents).unwrap();
+
+        assert_eq!(status.uids.real(), 1000);
+        assert_eq!(status.uids.effective(), 0);
+        assert_eq!(status.uids.saved(), 0);
+        assert_eq!(status.gids.real(), 1000);
+        assert_eq!(status.gids.effective(), 1000);
+        assert_eq!(status.gids.saved(), 27);
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        let contents = "Name:\tbash\nGid:\t1000\t1000\t1000\t1000\nGroups:\t4 24 27 1000 \n";
+        let status = ProcStatus::from_str(contents).unwrap();
+        assert_eq!(status.groups, vec![4, 24, 27, 1000]);
+
+        let contents = "Name:\tinit\nGroups:\t\n";
+        let status = ProcStatus::from_str(contents).unwrap();
+        assert!(status.groups.is_empty());
+    }
+}
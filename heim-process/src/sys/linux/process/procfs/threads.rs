@@ -0,0 +1,50 @@
+use std::io;
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use super::{process_file_path, CpuTime, Stat};
+use crate::sys::Thread;
+use crate::{Pid, ProcessError, ProcessResult};
+
+pub async fn threads(pid: Pid) -> ProcessResult<impl Stream<Item = ProcessResult<Thread>>> {
+    let path = process_file_path(pid, "task");
+    let entries = match rt::fs::read_dir(&path).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(ProcessError::NoSuchProcess(pid))
+        }
+        Err(e) => return Err(Error::from(e).with_file(path).into()),
+    };
+
+    let stream = entries.filter_map(move |entry| async move {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => return Some(Err(Error::from(e).into())),
+        };
+        // Skipping anything which is not a thread directory
+        let tid = entry.file_name().to_str()?.parse::<Pid>().ok()?;
+
+        match thread_stat(pid, tid).await {
+            Ok(Some(stat)) => Some(Ok(Thread::new(*tid as u64, CpuTime::from(stat)))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    });
+
+    Ok(stream)
+}
+
+/// Returns `Ok(None)` if the thread is gone already.
+async fn thread_stat(pid: Pid, tid: Pid) -> ProcessResult<Option<Stat>> {
+    let path = process_file_path(pid, format!("task/{}/stat", tid));
+    let contents = match rt::fs::read_to_string(&path).await {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) if e.raw_os_error() == Some(libc::ESRCH) => return Ok(None),
+        Err(e) => return Err(Error::from(e).with_file(path).into()),
+    };
+
+    Stat::from_str(&contents).map(Some).map_err(Into::into)
+}
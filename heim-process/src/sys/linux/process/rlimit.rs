@@ -0,0 +1,72 @@
+use std::ptr;
+
+use heim_common::prelude::*;
+
+use super::map_error;
+use crate::os::linux::Resource;
+use crate::{Pid, ProcessResult};
+
+#[cfg(target_env = "musl")]
+type RawResource = libc::c_int;
+#[cfg(not(target_env = "musl"))]
+type RawResource = libc::__rlimit_resource_t;
+
+impl From<Resource> for RawResource {
+    fn from(value: Resource) -> RawResource {
+        match value {
+            Resource::AddressSpace => libc::RLIMIT_AS,
+            Resource::Core => libc::RLIMIT_CORE,
+            Resource::Cpu => libc::RLIMIT_CPU,
+            Resource::Data => libc::RLIMIT_DATA,
+            Resource::FileSize => libc::RLIMIT_FSIZE,
+            Resource::Locks => libc::RLIMIT_LOCKS,
+            Resource::MemLock => libc::RLIMIT_MEMLOCK,
+            Resource::MsgQueue => libc::RLIMIT_MSGQUEUE,
+            Resource::Nice => libc::RLIMIT_NICE,
+            Resource::NoFile => libc::RLIMIT_NOFILE,
+            Resource::NProc => libc::RLIMIT_NPROC,
+            Resource::Rss => libc::RLIMIT_RSS,
+            Resource::RtPrio => libc::RLIMIT_RTPRIO,
+            Resource::RtTime => libc::RLIMIT_RTTIME,
+            Resource::SigPending => libc::RLIMIT_SIGPENDING,
+            Resource::Stack => libc::RLIMIT_STACK,
+        }
+    }
+}
+
+// `prlimit64` is always operating with the 64-bit `RLIM64_INFINITY` (`!0`) value,
+// which is the same as `u64::MAX`, so the limits are passed as-is in both directions.
+fn prlimit(pid: Pid, resource: Resource, new: Option<libc::rlimit64>) -> ProcessResult<(u64, u64)> {
+    let new_ptr: *const libc::rlimit64 = match new.as_ref() {
+        Some(limit) => limit,
+        None => ptr::null(),
+    };
+    let mut old = libc::rlimit64 {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+
+    let result = unsafe { libc::prlimit64(*pid, resource.into(), new_ptr, &mut old) };
+    if result != 0 {
+        let e = Error::last_os_error().with_ffi("prlimit64");
+        return Err(map_error(pid, e));
+    }
+
+    Ok((old.rlim_cur, old.rlim_max))
+}
+
+/// Safe wrapper for `prlimit64`, fetching the soft and hard limits.
+pub fn rlimit(pid: Pid, resource: Resource) -> ProcessResult<(u64, u64)> {
+    prlimit(pid, resource, None)
+}
+
+/// Safe wrapper for `prlimit64`, replacing the soft and hard limits.
+pub fn set_rlimit(pid: Pid, resource: Resource, limits: (u64, u64)) -> ProcessResult<()> {
+    let (soft, hard) = limits;
+    let new = libc::rlimit64 {
+        rlim_cur: soft,
+        rlim_max: hard,
+    };
+
+    prlimit(pid, resource, Some(new)).map(|_| ())
+}
@@ -0,0 +1,121 @@
+use std::convert::TryFrom;
+use std::io;
+use std::mem;
+
+use heim_common::prelude::*;
+
+use super::map_error;
+use crate::os::linux::SchedPolicy;
+use crate::{Pid, ProcessResult};
+
+// Not declared by the `libc` crate for all Linux targets
+const SCHED_DEADLINE: libc::c_int = 6;
+// Might be OR-ed into the `sched_getscheduler` result
+const SCHED_RESET_ON_FORK: libc::c_int = 0x4000_0000;
+
+impl TryFrom<libc::c_int> for SchedPolicy {
+    type Error = Error;
+
+    fn try_from(value: libc::c_int) -> Result<SchedPolicy> {
+        match value & !SCHED_RESET_ON_FORK {
+            libc::SCHED_OTHER => Ok(SchedPolicy::Normal),
+            libc::SCHED_FIFO => Ok(SchedPolicy::Fifo),
+            libc::SCHED_RR => Ok(SchedPolicy::Rr),
+            libc::SCHED_BATCH => Ok(SchedPolicy::Batch),
+            libc::SCHED_IDLE => Ok(SchedPolicy::Idle),
+            SCHED_DEADLINE => Ok(SchedPolicy::Deadline),
+            other => {
+                let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+                Err(Error::from(inner)
+                    .with_message(format!("Unknown scheduling policy: {}", other)))
+            }
+        }
+    }
+}
+
+impl From<SchedPolicy> for libc::c_int {
+    fn from(value: SchedPolicy) -> libc::c_int {
+        match value {
+            SchedPolicy::Normal => libc::SCHED_OTHER,
+            SchedPolicy::Fifo => libc::SCHED_FIFO,
+            SchedPolicy::Rr => libc::SCHED_RR,
+            SchedPolicy::Batch => libc::SCHED_BATCH,
+            SchedPolicy::Idle => libc::SCHED_IDLE,
+            SchedPolicy::Deadline => SCHED_DEADLINE,
+        }
+    }
+}
+
+/// Safe wrapper for `sched_getscheduler` and `sched_getparam`,
+/// fetching the scheduling policy and its static priority.
+pub fn sched_policy(pid: Pid) -> ProcessResult<(SchedPolicy, i32)> {
+    let policy = unsafe { libc::sched_getscheduler(*pid) };
+    if policy < 0 {
+        let e = Error::last_os_error().with_ffi("sched_getscheduler");
+        return Err(map_error(pid, e));
+    }
+
+    let mut param: libc::sched_param = unsafe { mem::zeroed() };
+    let result = unsafe { libc::sched_getparam(*pid, &mut param) };
+    if result != 0 {
+        let e = Error::last_os_error().with_ffi("sched_getparam");
+        return Err(map_error(pid, e));
+    }
+
+    Ok((SchedPolicy::try_from(policy)?, param.sched_priority))
+}
+
+/// Safe wrapper for `sched_setscheduler`, replacing the scheduling policy and its static priority.
+pub fn set_sched_policy(pid: Pid, policy: SchedPolicy, priority: i32) -> ProcessResult<()> {
+    // `SCHED_DEADLINE` parameters can be set with `sched_setattr` only
+    if policy == SchedPolicy::Deadline {
+        let inner = io::Error::from(io::ErrorKind::InvalidInput);
+
+        return Err(Error::from(inner)
+            .with_message("SCHED_DEADLINE policy can't be set by sched_setscheduler")
+            .into());
+    }
+
+    let mut param: libc::sched_param = unsafe { mem::zeroed() };
+    param.sched_priority = priority;
+
+    let result = unsafe { libc::sched_setscheduler(*pid, policy.into(), &param) };
+    if result != 0 {
+        let e = Error::last_os_error().with_ffi("sched_setscheduler");
+        return Err(map_error(pid, e));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::{SCHED_DEADLINE, SCHED_RESET_ON_FORK};
+    use crate::os::linux::SchedPolicy;
+
+    #[test]
+    fn test_policy_conversion() {
+        for policy in &[
+            SchedPolicy::Normal,
+            SchedPolicy::Fifo,
+            SchedPolicy::Rr,
+            SchedPolicy::Batch,
+            SchedPolicy::Idle,
+            SchedPolicy::Deadline,
+        ] {
+            let raw = libc::c_int::from(*policy);
+            assert_eq!(*policy, SchedPolicy::try_from(raw).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_policy_reset_on_fork() {
+        let raw = libc::SCHED_FIFO | SCHED_RESET_ON_FORK;
+        assert_eq!(SchedPolicy::Fifo, SchedPolicy::try_from(raw).unwrap());
+
+        assert!(SchedPolicy::try_from(SCHED_DEADLINE + 1).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+#![allow(non_camel_case_types)]
+
+use std::mem;
+
+pub use darwin_libproc_sys::{
+    proc_fdinfo, proc_pidfdinfo, proc_pidinfo, vinfo_stat, vnode_info_path, PROC_PIDLISTFDS,
+};
+
+// Declared at `bsd/sys/proc_info.h`
+pub const PROX_FDTYPE_VNODE: u32 = 1;
+pub const PROC_PIDFDVNODEPATHINFO: libc::c_int = 2;
+pub const PROC_PIDFDVNODEPATHINFO_SIZE: libc::c_int =
+    mem::size_of::<vnode_fdinfowithpath>() as libc::c_int;
+
+// `proc_fileinfo.fi_openflags` values, declared at `bsd/sys/fcntl.h`
+pub const FREAD: u32 = 0x0001;
+pub const FWRITE: u32 = 0x0002;
+pub const FAPPEND: u32 = 0x0008;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct proc_fileinfo {
+    pub fi_openflags: u32,
+    pub fi_status: u32,
+    pub fi_offset: libc::off_t,
+    pub fi_type: i32,
+    pub fi_guardflags: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct vnode_fdinfowithpath {
+    pub pfi: proc_fileinfo,
+    pub pvip: vnode_info_path,
+}
+
+pub const PROX_FDTYPE_SOCKET: u32 = 2;
+pub const PROC_PIDFDSOCKETINFO: libc::c_int = 3;
+pub const PROC_PIDFDSOCKETINFO_SIZE: libc::c_int = mem::size_of::<socket_fdinfo>() as libc::c_int;
+
+// `socket_info.soi_kind` values
+pub const SOCKINFO_IN: libc::c_int = 1;
+pub const SOCKINFO_TCP: libc::c_int = 2;
+
+// TCP states, declared at `bsd/netinet/tcp_fsm.h`
+pub const TCPS_CLOSED: libc::c_int = 0;
+pub const TCPS_LISTEN: libc::c_int = 1;
+pub const TCPS_SYN_SENT: libc::c_int = 2;
+pub const TCPS_SYN_RECEIVED: libc::c_int = 3;
+pub const TCPS_ESTABLISHED: libc::c_int = 4;
+pub const TCPS_CLOSE_WAIT: libc::c_int = 5;
+pub const TCPS_FIN_WAIT_1: libc::c_int = 6;
+pub const TCPS_CLOSING: libc::c_int = 7;
+pub const TCPS_LAST_ACK: libc::c_int = 8;
+pub const TCPS_FIN_WAIT_2: libc::c_int = 9;
+pub const TCPS_TIME_WAIT: libc::c_int = 10;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct sockbuf_info {
+    pub sbi_cc: u32,
+    pub sbi_hiwat: u32,
+    pub sbi_mbcnt: u32,
+    pub sbi_mbmax: u32,
+    pub sbi_lowat: u32,
+    pub sbi_flags: libc::c_short,
+    pub sbi_timeo: libc::c_short,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct in4in6_addr {
+    pub i46a_pad32: [u32; 3],
+    pub i46a_addr4: libc::in_addr,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union in_sockinfo_addr {
+    pub ina_46: in4in6_addr,
+    pub ina_6: libc::in6_addr,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct in_sockinfo_v4 {
+    pub in4_tos: libc::c_uchar,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct in_sockinfo_v6 {
+    pub in6_hlim: u8,
+    pub in6_cksum: libc::c_int,
+    pub in6_ifindex: libc::c_ushort,
+    pub in6_hops: libc::c_short,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct in_sockinfo {
+    pub insi_fport: libc::c_int,
+    pub insi_lport: libc::c_int,
+    pub insi_gencnt: u64,
+    pub insi_flags: u32,
+    pub insi_flow: u32,
+    pub insi_vflag: u8,
+    pub insi_ip_ttl: u8,
+    pub rfu_1: u32,
+    pub insi_faddr: in_sockinfo_addr,
+    pub insi_laddr: in_sockinfo_addr,
+    pub insi_v4: in_sockinfo_v4,
+    pub insi_v6: in_sockinfo_v6,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct tcp_sockinfo {
+    pub tcpsi_ini: in_sockinfo,
+    pub tcpsi_state: libc::c_int,
+    pub tcpsi_timer: [libc::c_int; 4],
+    pub tcpsi_mss: libc::c_int,
+    pub tcpsi_flags: u32,
+    pub rfu_1: u32,
+    pub tcpsi_tp: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union socket_info_proto {
+    pub pri_in: in_sockinfo,
+    pub pri_tcp: tcp_sockinfo,
+    // Other members (unix domain sockets, kernel events and controls)
+    // are not used, but they are affecting the union size,
+    // `struct un_sockinfo` being the largest one
+    pub _pri_un: [u64; 66],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct socket_info {
+    pub soi_stat: vinfo_stat,
+    pub soi_so: u64,
+    pub soi_pcb: u64,
+    pub soi_type: libc::c_int,
+    pub soi_protocol: libc::c_int,
+    pub soi_family: libc::c_int,
+    pub soi_options: libc::c_short,
+    pub soi_linger: libc::c_short,
+    pub soi_state: libc::c_short,
+    pub soi_qlen: libc::c_short,
+    pub soi_incqlen: libc::c_short,
+    pub soi_qlimit: libc::c_short,
+    pub soi_timeo: libc::c_short,
+    pub soi_error: libc::c_ushort,
+    pub soi_oobmark: u32,
+    pub soi_rcv: sockbuf_info,
+    pub soi_snd: sockbuf_info,
+    pub soi_kind: libc::c_int,
+    pub rfu_1: u32,
+    pub soi_proto: socket_info_proto,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct socket_fdinfo {
+    pub pfi: proc_fileinfo,
+    pub psi: socket_info,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    #[test]
+    fn test_socket_fdinfo_size() {
+        assert_eq!(mem::size_of::<in_sockinfo>(), 80);
+        assert_eq!(mem::size_of::<socket_info>(), 768);
+        assert_eq!(mem::size_of::<socket_fdinfo>(), 792);
+    }
+}
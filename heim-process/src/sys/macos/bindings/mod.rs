@@ -1,5 +1,11 @@
+mod fd;
 mod proc_args;
 mod process;
+mod sysctl;
+mod thread;
 
+pub use self::fd::*;
 pub use self::proc_args::*;
 pub use self::process::*;
+pub use self::sysctl::*;
+pub use self::thread::*;
@@ -1,12 +1,12 @@
-use std::convert::TryFrom;
-use std::io;
 use std::mem;
 use std::ptr;
+use std::vec;
 
 use mach::{boolean, vm_types};
 
 use heim_common::prelude::Error;
 
+use super::SysctlBuffer;
 use crate::{Pid, ProcessError, Status};
 
 // Process status values, declared at `bsd/sys/proc.h`
@@ -24,21 +24,17 @@ pub const SSTOP: libc::c_char = 4;
 /// Awaiting collection by parent.
 pub const SZOMB: libc::c_char = 5;
 
-impl TryFrom<libc::c_char> for Status {
-    type Error = Error;
-
-    fn try_from(value: libc::c_char) -> Result<Status, Self::Error> {
+impl Status {
+    /// Converts `extern_proc.p_stat` value into the process status.
+    pub(crate) fn from_p_stat(value: libc::c_char) -> Status {
         match value {
-            SIDL => Ok(Status::Idle),
-            SRUN => Ok(Status::Running),
-            SSLEEP => Ok(Status::Sleeping),
-            SSTOP => Ok(Status::Stopped),
-            SZOMB => Ok(Status::Zombie),
+            SIDL => Status::Idle,
+            SRUN => Status::Running,
+            SSLEEP => Status::Sleeping,
+            SSTOP => Status::Stopped,
+            SZOMB => Status::Zombie,
             other => {
-                let inner = io::Error::from(io::ErrorKind::InvalidData);
-
-                Err(Error::from(inner)
-                    .with_message(format!("Unknown process p_stat value: {:?}", other,)))
+                Status::Unknown(std::char::from_digit(u32::from(other as u8), 10).unwrap_or('?'))
             }
         }
     }
@@ -170,70 +166,38 @@ pub struct kinfo_proc_eproc {
     pub e_spare: [i32; 4],
 }
 
-pub fn processes() -> Result<Vec<kinfo_proc>, Error> {
-    let mut name: [i32; 3] = [libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_ALL];
-    let mut size: libc::size_t = 0;
-    let mut processes: Vec<kinfo_proc> = vec![];
-
-    loop {
-        // Dry-run to get the size required for the process list
-        let result = unsafe {
-            libc::sysctl(
-                name.as_mut_ptr(),
-                3,
-                ptr::null_mut(),
-                &mut size,
-                ptr::null_mut(),
-                0,
-            )
-        };
-        if result < 0 {
-            return Err(Error::last_os_error().with_sysctl(name.as_ref()));
-        }
+/// Process list loaded with one `KERN_PROC_ALL` sysctl call.
+///
+/// Iterator owns the sysctl buffer, so the structs which were not consumed yet
+/// are freed together with it, when iterator (or a stream wrapping it) is dropped.
+pub struct Processes(vec::IntoIter<kinfo_proc>);
 
-        // Reserve enough room to store the whole process list
-        let num_processes = size / mem::size_of::<kinfo_proc>();
-        if num_processes > processes.capacity() {
-            processes.reserve_exact(num_processes - processes.capacity());
-        }
+// `kinfo_proc` is full of raw pointers, but they are pointing to the kernel memory
+// and never dereferenced, so it is safe to move the buffer between threads.
+unsafe impl Send for Processes {}
 
-        // Attempt to store the process list in `processes`
-        let result = unsafe {
-            libc::sysctl(
-                name.as_mut_ptr(),
-                3,
-                processes.as_mut_ptr() as *mut libc::c_void,
-                &mut size,
-                ptr::null_mut(),
-                0,
-            )
-        };
-
-        if result < 0 {
-            // `libc::ENOMEM` indicates there was not enough space in `processes` to store the whole
-            // process list which can occur when a new process spawns between getting the size and
-            // storing. If this is the case then simply try again.
-            let err = Error::last_os_error();
-            if let Some(libc::ENOMEM) = err.raw_os_error() {
-                continue;
-            } else {
-                return Err(err.with_sysctl(name.as_ref()));
-            }
-        } else {
-            // Getting the list succeeded so let `processes` know how many processes it holds
-            let length = size / mem::size_of::<kinfo_proc>();
-            unsafe {
-                processes.set_len(length);
-            }
-            debug_assert!(!processes.is_empty());
+impl Iterator for Processes {
+    type Item = kinfo_proc;
 
-            return Ok(processes);
-        }
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+pub fn processes() -> Result<Processes, Error> {
+    let mut name: [i32; 3] = [libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_ALL];
+    let buffer = SysctlBuffer::<kinfo_proc>::load(&mut name)?;
+    debug_assert!(!buffer.is_empty());
+
+    Ok(Processes(buffer.into_iter()))
 }
 
 pub fn process(pid: Pid) -> Result<kinfo_proc, ProcessError> {
-    let mut name: [i32; 4] = [libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_PID, pid];
+    let mut name: [i32; 4] = [libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_PID, *pid];
     let mut size: libc::size_t = mem::size_of::<kinfo_proc>();
     let mut info = mem::MaybeUninit::<kinfo_proc>::uninit();
 
@@ -264,7 +228,8 @@ pub fn process(pid: Pid) -> Result<kinfo_proc, ProcessError> {
 mod tests {
     use std::mem;
 
-    use super::{kinfo_proc, kinfo_proc_eproc, pcred, vmspace};
+    use super::{kinfo_proc, kinfo_proc_eproc, pcred, vmspace, SSTOP};
+    use crate::Status;
 
     #[test]
     fn test_layout() {
@@ -280,4 +245,10 @@ mod tests {
         assert_eq!(mem::size_of::<kinfo_proc_eproc>(), 352);
         assert_eq!(mem::align_of::<kinfo_proc_eproc>(), 8);
     }
+
+    #[test]
+    fn test_p_stat() {
+        assert_eq!(Status::Stopped, Status::from_p_stat(SSTOP));
+        assert_eq!(Status::Unknown('7'), Status::from_p_stat(7));
+    }
 }
@@ -0,0 +1,144 @@
+use std::io;
+use std::mem;
+use std::ptr;
+use std::vec;
+
+use heim_common::{Error, Result};
+
+/// How many times the size-probe and load calls are repeated
+/// if the data grows between them.
+const MAX_RETRIES: usize = 8;
+
+/// Owned buffer filled by the `sysctl` call with an array of `T` structs.
+///
+/// Buffer memory is freed when it is dropped,
+/// or when iterator (or a stream wrapping it) created from it is dropped.
+pub struct SysctlBuffer<T> {
+    data: Vec<T>,
+}
+
+impl<T: Copy> SysctlBuffer<T> {
+    /// Loads the data for `name` MIB, when the data size is not known beforehand.
+    ///
+    /// Required size is requested first; since the data might grow before the second call,
+    /// buffer is allocated with some headroom and the whole procedure is repeated
+    /// if `sysctl` still reports `ENOMEM`.
+    pub fn load(name: &mut [libc::c_int]) -> Result<Self> {
+        for _ in 0..MAX_RETRIES {
+            let mut size: libc::size_t = 0;
+            // Dry-run to get the size required
+            let result = unsafe {
+                libc::sysctl(
+                    name.as_mut_ptr(),
+                    name.len() as libc::c_uint,
+                    ptr::null_mut(),
+                    &mut size,
+                    ptr::null_mut(),
+                    0,
+                )
+            };
+            if result < 0 {
+                return Err(Error::last_os_error().with_sysctl(name));
+            }
+
+            let capacity = Self::len_for(size) + Self::len_for(size) / 8 + 1;
+            match Self::load_with_capacity(name, capacity) {
+                // Data grew too much since the dry-run, trying again
+                Err(e) if e.raw_os_error() == Some(libc::ENOMEM) => continue,
+                other => return other,
+            }
+        }
+
+        let inner = io::Error::from_raw_os_error(libc::ENOMEM);
+        Err(Error::from(inner).with_sysctl(name))
+    }
+
+    /// Loads the data for `name` MIB with one `sysctl` call
+    /// into the buffer which is able to hold `capacity` of `T` structs.
+    pub fn load_with_capacity(name: &mut [libc::c_int], capacity: usize) -> Result<Self> {
+        let mut data: Vec<T> = Vec::with_capacity(capacity);
+        let mut size: libc::size_t = capacity * mem::size_of::<T>();
+
+        let result = unsafe {
+            libc::sysctl(
+                name.as_mut_ptr(),
+                name.len() as libc::c_uint,
+                data.as_mut_ptr() as *mut libc::c_void,
+                &mut size,
+                ptr::null_mut(),
+                0,
+            )
+        };
+        if result < 0 {
+            return Err(Error::last_os_error().with_sysctl(name));
+        }
+
+        // `sysctl` changes the `size` value to what length were stored,
+        // which never exceeds the provided buffer size.
+        debug_assert!(size <= capacity * mem::size_of::<T>());
+        unsafe {
+            data.set_len(size / mem::size_of::<T>());
+        }
+
+        Ok(SysctlBuffer { data })
+    }
+
+    fn len_for(size: libc::size_t) -> usize {
+        size / mem::size_of::<T>()
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns loaded data, releasing the unused part of the buffer.
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.data.shrink_to_fit();
+        self.data
+    }
+}
+
+impl<T> IntoIterator for SysctlBuffer<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SysctlBuffer;
+    use crate::sys::macos::bindings::kinfo_proc;
+
+    #[test]
+    fn test_load() {
+        let mut name = [libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_ALL];
+        let buffer = SysctlBuffer::<kinfo_proc>::load(&mut name).unwrap();
+
+        assert!(!buffer.is_empty());
+        let pid = std::process::id() as libc::pid_t;
+        assert!(buffer.into_iter().any(|proc| proc.kp_proc.p_pid == pid));
+    }
+
+    #[test]
+    fn test_load_with_small_capacity() {
+        let mut name = [libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_ALL];
+        let result = SysctlBuffer::<kinfo_proc>::load_with_capacity(&mut name, 1);
+
+        assert_eq!(Some(libc::ENOMEM), result.err().unwrap().raw_os_error());
+    }
+
+    #[test]
+    fn test_load_unknown_name() {
+        let mut name = [libc::CTL_KERN, -1];
+        let result = SysctlBuffer::<u8>::load(&mut name);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,64 @@
+#![allow(non_camel_case_types)]
+
+use std::mem;
+
+use mach::kern_return::kern_return_t;
+use mach::mach_types::thread_act_t;
+use mach::message::mach_msg_type_number_t;
+use mach::vm_types::{integer_t, natural_t, vm_address_t, vm_map_t, vm_size_t};
+
+pub type thread_flavor_t = natural_t;
+pub type thread_info_t = *mut integer_t;
+
+// Declared at `osfmk/mach/thread_info.h`
+pub const THREAD_BASIC_INFO: thread_flavor_t = 3;
+pub const THREAD_IDENTIFIER_INFO: thread_flavor_t = 4;
+
+pub const THREAD_BASIC_INFO_COUNT: mach_msg_type_number_t =
+    (mem::size_of::<thread_basic_info>() / mem::size_of::<natural_t>()) as mach_msg_type_number_t;
+pub const THREAD_IDENTIFIER_INFO_COUNT: mach_msg_type_number_t =
+    (mem::size_of::<thread_identifier_info>() / mem::size_of::<natural_t>())
+        as mach_msg_type_number_t;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct time_value_t {
+    pub seconds: integer_t,
+    pub microseconds: integer_t,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct thread_basic_info {
+    pub user_time: time_value_t,
+    pub system_time: time_value_t,
+    pub cpu_usage: integer_t,
+    pub policy: integer_t,
+    pub run_state: integer_t,
+    pub flags: integer_t,
+    pub suspend_count: integer_t,
+    pub sleep_time: integer_t,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct thread_identifier_info {
+    pub thread_id: u64,
+    pub thread_handle: u64,
+    pub dispatch_qaddr: u64,
+}
+
+extern "C" {
+    pub fn thread_info(
+        target_act: thread_act_t,
+        flavor: thread_flavor_t,
+        thread_info_out: thread_info_t,
+        thread_info_outCnt: *mut mach_msg_type_number_t,
+    ) -> kern_return_t;
+
+    pub fn vm_deallocate(
+        target_task: vm_map_t,
+        address: vm_address_t,
+        size: vm_size_t,
+    ) -> kern_return_t;
+}
@@ -5,13 +5,9 @@ use crate::sys::unix;
 use crate::{Pid, ProcessResult};
 
 pub async fn pids() -> Result<impl Stream<Item = Result<Pid>>> {
-    // `kinfo_proc` is not `Send`-able, so it would not be possible
-    // later to send it between threads (it's full of raw pointers),
-    // so for MVP we are just going to collect all the pids in-place.
-    let pids = bindings::processes()?
-        .into_iter()
-        .map(|proc| Ok(proc.kp_proc.p_pid))
-        .collect::<Vec<_>>();
+    // Stream owns the whole `kinfo_proc` buffer and converts structs lazily,
+    // dropping it in the middle frees the remaining ones too.
+    let pids = bindings::processes()?.map(|proc| Ok(Pid::from(proc.kp_proc.p_pid)));
 
     Ok(stream::iter(pids))
 }
@@ -1,9 +1,8 @@
 use std::ffi::{OsStr, OsString};
-use std::io;
 use std::os::unix::ffi::OsStrExt;
 
-use crate::sys::macos::{pid_exists, wrappers};
-use crate::{Pid, ProcessError, ProcessResult};
+use crate::sys::macos::{utils::catch_proc_args, wrappers};
+use crate::{Pid, ProcessResult};
 
 #[derive(Debug)]
 pub struct Command(wrappers::ProcArgs);
@@ -42,16 +41,6 @@ impl<'a> Iterator for CommandIter<'a> {
 pub async fn command(pid: Pid) -> ProcessResult<Command> {
     match wrappers::ProcArgs::get(pid) {
         Ok(proc_args) => Ok(Command(proc_args)),
-        Err(e) if e.raw_os_error() == Some(libc::EINVAL) => {
-            if pid_exists(pid).await? {
-                Err(ProcessError::ZombieProcess(pid))
-            } else {
-                Err(e.into())
-            }
-        }
-        Err(e) if e.as_inner().kind() == io::ErrorKind::PermissionDenied => {
-            Err(ProcessError::AccessDenied(pid))
-        }
-        Err(e) => Err(e.into()),
+        Err(e) => Err(catch_proc_args(e, pid)),
     }
 }
@@ -1,5 +1,7 @@
 use heim_common::units::{time, Time};
 
+use crate::sys::macos::bindings;
+
 #[derive(Debug, Clone)]
 pub struct CpuTime {
     utime: Time,
@@ -7,6 +9,13 @@ pub struct CpuTime {
 }
 
 impl CpuTime {
+    pub fn new(user: Time, system: Time) -> CpuTime {
+        CpuTime {
+            utime: user,
+            stime: system,
+        }
+    }
+
     pub fn user(&self) -> Time {
         self.utime
     }
@@ -14,6 +23,14 @@ impl CpuTime {
     pub fn system(&self) -> Time {
         self.stime
     }
+
+    pub fn children_user(&self) -> Option<Time> {
+        None
+    }
+
+    pub fn children_system(&self) -> Option<Time> {
+        None
+    }
 }
 
 impl From<darwin_libproc::proc_taskinfo> for CpuTime {
@@ -24,3 +41,17 @@ impl From<darwin_libproc::proc_taskinfo> for CpuTime {
         }
     }
 }
+
+impl From<bindings::thread_basic_info> for CpuTime {
+    fn from(info: bindings::thread_basic_info) -> CpuTime {
+        let into_time = |value: bindings::time_value_t| {
+            Time::new::<time::second>(f64::from(value.seconds))
+                + Time::new::<time::microsecond>(f64::from(value.microseconds))
+        };
+
+        CpuTime {
+            utime: into_time(info.user_time),
+            stime: into_time(info.system_time),
+        }
+    }
+}
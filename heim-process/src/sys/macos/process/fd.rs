@@ -0,0 +1,226 @@
+use std::convert::TryFrom;
+use std::ffi::{CStr, OsStr};
+use std::io;
+use std::mem;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+use std::ptr;
+
+use heim_common::Error;
+
+use crate::os::unix::{Connection, ConnectionKind, FileMode, OpenFile, Protocol, TcpState};
+use crate::sys::macos::{bindings, utils::catch_zombie};
+use crate::{Pid, ProcessError, ProcessResult};
+
+fn catch_fd_error(e: Error, pid: Pid) -> ProcessError {
+    if e.as_inner().kind() == io::ErrorKind::PermissionDenied {
+        ProcessError::AccessDenied(pid)
+    } else {
+        catch_zombie(e, pid)
+    }
+}
+
+fn fds(pid: Pid) -> ProcessResult<Vec<bindings::proc_fdinfo>> {
+    let entry_size = mem::size_of::<bindings::proc_fdinfo>();
+
+    // Dry-run to get the size required for the descriptors list
+    let size =
+        unsafe { bindings::proc_pidinfo(*pid, bindings::PROC_PIDLISTFDS, 0, ptr::null_mut(), 0) };
+    if size <= 0 {
+        let e = Error::last_os_error().with_ffi("proc_pidinfo");
+        return Err(catch_fd_error(e, pid));
+    }
+
+    let mut fds = Vec::<bindings::proc_fdinfo>::with_capacity(size as usize / entry_size);
+    let size = unsafe {
+        bindings::proc_pidinfo(
+            *pid,
+            bindings::PROC_PIDLISTFDS,
+            0,
+            fds.as_mut_ptr() as *mut libc::c_void,
+            (fds.capacity() * entry_size) as libc::c_int,
+        )
+    };
+    if size <= 0 {
+        let e = Error::last_os_error().with_ffi("proc_pidinfo");
+        return Err(catch_fd_error(e, pid));
+    }
+
+    unsafe {
+        fds.set_len(size as usize / entry_size);
+    }
+
+    Ok(fds)
+}
+
+pub fn num_fds(pid: Pid) -> ProcessResult<u64> {
+    fds(pid).map(|fds| fds.len() as u64)
+}
+
+#[allow(trivial_casts)]
+pub fn open_files(pid: Pid) -> ProcessResult<Vec<OpenFile>> {
+    let mut files = Vec::new();
+
+    for fd in fds(pid)? {
+        if fd.proc_fdtype != bindings::PROX_FDTYPE_VNODE {
+            continue;
+        }
+
+        let mut info = mem::MaybeUninit::<bindings::vnode_fdinfowithpath>::uninit();
+        let result = unsafe {
+            bindings::proc_pidfdinfo(
+                *pid,
+                fd.proc_fd,
+                bindings::PROC_PIDFDVNODEPATHINFO,
+                info.as_mut_ptr() as *mut libc::c_void,
+                bindings::PROC_PIDFDVNODEPATHINFO_SIZE,
+            )
+        };
+        if result <= 0 {
+            let e = Error::last_os_error().with_ffi("proc_pidfdinfo");
+            match e.raw_os_error() {
+                // File descriptor was closed after the list was fetched
+                Some(libc::EBADF) => continue,
+                _ => return Err(catch_fd_error(e, pid)),
+            }
+        }
+        let info = unsafe { info.assume_init() };
+
+        let mode = libc::mode_t::from(info.pvip.vip_vi.vi_stat.vst_mode);
+        if mode & libc::S_IFMT != libc::S_IFREG {
+            continue;
+        }
+
+        let path = unsafe { CStr::from_ptr(info.pvip.vip_path.as_ptr()) };
+        let path = PathBuf::from(OsStr::from_bytes(path.to_bytes()));
+
+        let position = u64::try_from(info.pfi.fi_offset).ok();
+
+        files.push(OpenFile::new(
+            path,
+            fd.proc_fd,
+            file_mode(info.pfi.fi_openflags),
+            position,
+        ));
+    }
+
+    Ok(files)
+}
+
+fn file_mode(flags: u32) -> Option<FileMode> {
+    match (flags & bindings::FREAD != 0, flags & bindings::FWRITE != 0) {
+        (_, true) if flags & bindings::FAPPEND != 0 => Some(FileMode::Append),
+        (true, true) => Some(FileMode::ReadWrite),
+        (false, true) => Some(FileMode::Write),
+        (true, false) => Some(FileMode::Read),
+        (false, false) => None,
+    }
+}
+
+fn tcp_state(value: libc::c_int) -> ProcessResult<TcpState> {
+    match value {
+        bindings::TCPS_CLOSED => Ok(TcpState::Closed),
+        bindings::TCPS_LISTEN => Ok(TcpState::Listen),
+        bindings::TCPS_SYN_SENT => Ok(TcpState::SynSent),
+        bindings::TCPS_SYN_RECEIVED => Ok(TcpState::SynReceived),
+        bindings::TCPS_ESTABLISHED => Ok(TcpState::Established),
+        bindings::TCPS_CLOSE_WAIT => Ok(TcpState::CloseWait),
+        bindings::TCPS_FIN_WAIT_1 => Ok(TcpState::FinWait1),
+        bindings::TCPS_CLOSING => Ok(TcpState::Closing),
+        bindings::TCPS_LAST_ACK => Ok(TcpState::LastAck),
+        bindings::TCPS_FIN_WAIT_2 => Ok(TcpState::FinWait2),
+        bindings::TCPS_TIME_WAIT => Ok(TcpState::TimeWait),
+        other => {
+            let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+            Err(Error::from(inner)
+                .with_message(format!("Unknown TCP state {}", other))
+                .into())
+        }
+    }
+}
+
+fn socket_address(
+    addr: &bindings::in_sockinfo_addr,
+    port: libc::c_int,
+    is_ipv6: bool,
+) -> SocketAddr {
+    let ip: IpAddr = if is_ipv6 {
+        Ipv6Addr::from(unsafe { addr.ina_6.s6_addr }).into()
+    } else {
+        Ipv4Addr::from(u32::from_be(unsafe { addr.ina_46.i46a_addr4.s_addr })).into()
+    };
+    // Port is stored in the network byte order
+    let port = u16::from_be(port as u16);
+
+    SocketAddr::new(ip, port)
+}
+
+#[allow(trivial_casts)]
+pub fn connections(pid: Pid, kind: ConnectionKind) -> ProcessResult<Vec<Connection>> {
+    let mut connections = Vec::new();
+
+    for fd in fds(pid)? {
+        if fd.proc_fdtype != bindings::PROX_FDTYPE_SOCKET {
+            continue;
+        }
+
+        let mut info = mem::MaybeUninit::<bindings::socket_fdinfo>::uninit();
+        let result = unsafe {
+            bindings::proc_pidfdinfo(
+                *pid,
+                fd.proc_fd,
+                bindings::PROC_PIDFDSOCKETINFO,
+                info.as_mut_ptr() as *mut libc::c_void,
+                bindings::PROC_PIDFDSOCKETINFO_SIZE,
+            )
+        };
+        if result <= 0 {
+            let e = Error::last_os_error().with_ffi("proc_pidfdinfo");
+            match e.raw_os_error() {
+                // File descriptor was closed after the list was fetched
+                Some(libc::EBADF) => continue,
+                _ => return Err(catch_fd_error(e, pid)),
+            }
+        }
+        let info = unsafe { info.assume_init() }.psi;
+
+        let is_ipv6 = match info.soi_family {
+            libc::AF_INET => false,
+            libc::AF_INET6 => true,
+            _ => continue,
+        };
+        let protocol = match info.soi_type {
+            libc::SOCK_STREAM => Protocol::Tcp,
+            libc::SOCK_DGRAM => Protocol::Udp,
+            _ => continue,
+        };
+        if !kind.contains(protocol, is_ipv6) {
+            continue;
+        }
+
+        let (in_info, state) = match info.soi_kind {
+            bindings::SOCKINFO_TCP => {
+                let tcp_info = unsafe { info.soi_proto.pri_tcp };
+                (tcp_info.tcpsi_ini, Some(tcp_state(tcp_info.tcpsi_state)?))
+            }
+            bindings::SOCKINFO_IN => (unsafe { info.soi_proto.pri_in }, None),
+            _ => continue,
+        };
+
+        connections.push(Connection::new(
+            fd.proc_fd,
+            protocol,
+            socket_address(&in_info.insi_laddr, in_info.insi_lport, is_ipv6),
+            heim_net::connected_address(socket_address(
+                &in_info.insi_faddr,
+                in_info.insi_fport,
+                is_ipv6,
+            )),
+            state,
+        ));
+    }
+
+    Ok(connections)
+}
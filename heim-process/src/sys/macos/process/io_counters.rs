@@ -18,6 +18,14 @@ impl fmt::Debug for IoCounters {
 }
 
 impl IoCounters {
+    pub fn read_count(&self) -> u64 {
+        0
+    }
+
+    pub fn write_count(&self) -> u64 {
+        0
+    }
+
     /// Attempt to count the number of bytes which this process really did cause to
     /// be fetched from the storage layer.
     pub fn bytes_read(&self) -> Information {
@@ -35,13 +43,13 @@ impl From<darwin_libproc::rusage_info_v2> for IoCounters {
     fn from(info: darwin_libproc::rusage_info_v2) -> IoCounters {
         IoCounters {
             read_bytes: info.ri_diskio_bytesread,
-            write_bytes: info.ri_diskio_bytesread,
+            write_bytes: info.ri_diskio_byteswritten,
         }
     }
 }
 
 pub async fn io(pid: Pid) -> ProcessResult<IoCounters> {
-    match darwin_libproc::pid_rusage::<darwin_libproc::rusage_info_v2>(pid) {
+    match darwin_libproc::pid_rusage::<darwin_libproc::rusage_info_v2>(*pid) {
         Ok(contents) => Ok(IoCounters::from(contents)),
         Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
             Err(ProcessError::AccessDenied(pid))
@@ -11,6 +11,15 @@ pub struct Memory {
 }
 
 impl Memory {
+    pub fn new(rss: Information, vms: Information) -> Memory {
+        Memory {
+            pti_resident_size: rss,
+            pti_virtual_size: vms,
+            pti_faults: 0,
+            pti_pageins: 0,
+        }
+    }
+
     pub fn rss(&self) -> Information {
         self.pti_resident_size
     }
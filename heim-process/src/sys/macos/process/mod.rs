@@ -1,28 +1,40 @@
 use std::cmp;
-use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::hash;
 use std::io;
 use std::path::PathBuf;
+use std::process::ExitStatus;
 
 use ::futures::future::BoxFuture;
 
 use heim_common::prelude::*;
 use heim_common::sys::IntoTime;
-use heim_common::units::Time;
+use heim_common::units::{time, Time};
+use heim_runtime as rt;
 
 use super::{bindings, pids, utils::catch_zombie};
-use crate::os::unix::Signal;
+use crate::os::macos::ProcessSnapshot;
+use crate::os::unix::{Connection, ConnectionKind, Gids, OpenFile, Signal, Uids};
+use crate::process::attribute;
 use crate::sys::common::UniqueId;
-use crate::sys::unix::{pid_kill, pid_priority, pid_setpriority, pid_wait};
+use crate::sys::unix::{
+    pid_kill, pid_priority, pid_setpriority, pid_wait, poll_exit, terminal_path, uid_username,
+};
 pub use crate::sys::unix::{Environment, EnvironmentIter, IntoEnvironmentIter};
-use crate::{Pid, ProcessError, ProcessResult, Status};
+use crate::sys::Thread;
+use crate::{
+    CtxSwitches, GuardAction, Pid, ProcessError, ProcessFields, ProcessInfo, ProcessOneShot,
+    ProcessResult, Status,
+};
 
 mod command;
 mod cpu_times;
 mod env;
+mod fd;
 mod io_counters;
 mod memory;
+mod threads;
+mod wait;
 
 pub use self::command::{Command, CommandIter};
 pub use self::cpu_times::CpuTime;
@@ -42,7 +54,7 @@ impl Process {
 
     pub async fn parent_pid(&self) -> ProcessResult<Pid> {
         match bindings::process(self.pid) {
-            Ok(kinfo_proc) => Ok(kinfo_proc.kp_eproc.e_ppid),
+            Ok(kinfo_proc) => Ok(Pid::from(kinfo_proc.kp_eproc.e_ppid)),
             Err(e) => Err(catch_zombie(e, self.pid)),
         }
     }
@@ -59,8 +71,22 @@ impl Process {
         }
     }
 
+    pub async fn terminal(&self) -> ProcessResult<Option<PathBuf>> {
+        let kinfo_proc = match bindings::process(self.pid) {
+            Ok(kinfo_proc) => kinfo_proc,
+            Err(e) => return Err(catch_zombie(e, self.pid)),
+        };
+
+        // `NODEV` is used for processes without controlling terminal
+        match kinfo_proc.kp_eproc.e_tdev {
+            -1 => Ok(None),
+            // Cast is the same as for `MetadataExt::rdev`
+            tdev => Ok(rt::spawn_blocking(move || terminal_path(tdev as u64)).await),
+        }
+    }
+
     pub async fn exe(&self) -> ProcessResult<PathBuf> {
-        match darwin_libproc::pid_path(self.pid) {
+        match darwin_libproc::pid_path(*self.pid) {
             Ok(path) => Ok(path),
             Err(..) if self.pid == 0 => Err(ProcessError::AccessDenied(self.pid)),
             Err(e) => Err(catch_zombie(e, self.pid)),
@@ -72,7 +98,7 @@ impl Process {
     }
 
     pub async fn cwd(&self) -> ProcessResult<PathBuf> {
-        match darwin_libproc::pid_cwd(self.pid) {
+        match darwin_libproc::pid_cwd(*self.pid) {
             Ok(path) => Ok(path),
             Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
                 Err(ProcessError::AccessDenied(self.pid))
@@ -83,7 +109,7 @@ impl Process {
 
     pub async fn status(&self) -> ProcessResult<Status> {
         match bindings::process(self.pid) {
-            Ok(kinfo_proc) => Status::try_from(kinfo_proc.kp_proc.p_stat).map_err(From::from),
+            Ok(kinfo_proc) => Ok(Status::from_p_stat(kinfo_proc.kp_proc.p_stat)),
             Err(e) => Err(catch_zombie(e, self.pid)),
         }
     }
@@ -97,7 +123,7 @@ impl Process {
     }
 
     pub async fn cpu_time(&self) -> ProcessResult<CpuTime> {
-        match darwin_libproc::task_info(self.pid) {
+        match darwin_libproc::task_info(*self.pid) {
             Ok(task_info) => Ok(CpuTime::from(task_info)),
             Err(ref e) if e.kind() == io::ErrorKind::PermissionDenied => {
                 Err(ProcessError::AccessDenied(self.pid))
@@ -106,8 +132,45 @@ impl Process {
         }
     }
 
+    pub async fn num_ctx_switches(&self) -> ProcessResult<CtxSwitches> {
+        match darwin_libproc::task_info(*self.pid) {
+            // Only the total amount is available
+            Ok(task_info) => Ok(CtxSwitches::new(task_info.pti_csw as u64, 0)),
+            Err(ref e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                Err(ProcessError::AccessDenied(self.pid))
+            }
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn threads(&self) -> ProcessResult<impl Stream<Item = ProcessResult<Thread>>> {
+        let threads = threads::threads(self.pid)?;
+
+        Ok(stream::iter(threads).map(Ok))
+    }
+
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        match darwin_libproc::task_info(*self.pid) {
+            Ok(task_info) => Ok(task_info.pti_threadnum as u64),
+            Err(ref e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                Err(ProcessError::AccessDenied(self.pid))
+            }
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn status_and_threads(&self) -> ProcessResult<(Status, u64)> {
+        // There is no single call returning both, `kinfo_proc` has no threads count
+        // and `proc_taskinfo` has no process state; process state is fetched first,
+        // so the zombie process is detected before the threads query fails for it
+        let status = self.status().await?;
+        let threads = self.num_threads().await?;
+
+        Ok((status, threads))
+    }
+
     pub async fn memory(&self) -> ProcessResult<Memory> {
-        match darwin_libproc::task_info(self.pid) {
+        match darwin_libproc::task_info(*self.pid) {
             Ok(task_info) => Ok(Memory::from(task_info)),
             Err(ref e) if e.kind() == io::ErrorKind::PermissionDenied => {
                 Err(ProcessError::AccessDenied(self.pid))
@@ -124,20 +187,96 @@ impl Process {
         pid_setpriority(self.pid, value)
     }
 
-    pub async fn is_running(&self) -> ProcessResult<bool> {
-        let other = get(self.pid).await?;
+    pub async fn uids(&self) -> ProcessResult<Uids> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) => {
+                let pcred = kinfo_proc.kp_eproc.e_pcred;
+                let ucred = kinfo_proc.kp_eproc.e_ucred;
 
-        Ok(other == *self)
+                Ok(Uids::new(pcred.p_ruid, ucred.cr_uid, pcred.p_svuid))
+            }
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn gids(&self) -> ProcessResult<Gids> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) => {
+                let pcred = kinfo_proc.kp_eproc.e_pcred;
+                let ucred = kinfo_proc.kp_eproc.e_ucred;
+
+                // First group in the credentials list is the effective one
+                Ok(Gids::new(pcred.p_rgid, ucred.cr_groups[0], pcred.p_svgid))
+            }
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn groups(&self) -> ProcessResult<Vec<u32>> {
+        match bindings::process(self.pid) {
+            Ok(kinfo_proc) => {
+                let ucred = kinfo_proc.kp_eproc.e_ucred;
+                let count = cmp::min(
+                    cmp::max(ucred.cr_ngroups, 0) as usize,
+                    ucred.cr_groups.len(),
+                );
+
+                // Effective group goes first and the supplementary ones are following it
+                Ok(ucred.cr_groups[..count].iter().skip(1).copied().collect())
+            }
+            Err(e) => Err(catch_zombie(e, self.pid)),
+        }
+    }
+
+    pub async fn username(&self) -> ProcessResult<String> {
+        let uids = self.uids().await?;
+
+        uid_username(uids.effective()).await
+    }
+
+    pub async fn num_fds(&self) -> ProcessResult<u64> {
+        fd::num_fds(self.pid)
+    }
+
+    pub async fn open_files(&self) -> ProcessResult<BoxStream<'static, ProcessResult<OpenFile>>> {
+        let files = fd::open_files(self.pid)?;
+
+        Ok(stream::iter(files).map(Ok).boxed())
+    }
+
+    pub async fn connections(
+        &self,
+        kind: ConnectionKind,
+    ) -> ProcessResult<BoxStream<'static, ProcessResult<Connection>>> {
+        let connections = fd::connections(self.pid, kind)?;
+
+        Ok(stream::iter(connections).map(Ok).boxed())
+    }
+
+    pub async fn cpu_affinity(&self) -> ProcessResult<Vec<usize>> {
+        let inner = io::Error::from(io::ErrorKind::Other);
+
+        Err(Error::from(inner)
+            .with_message("CPU affinity is not supported on macOS")
+            .into())
+    }
+
+    pub async fn set_cpu_affinity(&self, _cpus: &[usize]) -> ProcessResult<()> {
+        self.cpu_affinity().await.map(|_| ())
+    }
+
+    pub async fn is_running(&self) -> ProcessResult<bool> {
+        match get(self.pid).await {
+            Ok(other) => Ok(other == *self),
+            Err(ProcessError::NoSuchProcess(..)) => Ok(false),
+            Err(e) => Err(e),
+        }
     }
 
     // `Self::signal` needs to return `BoxFuture`,
     // but the `Self::kill` does not
     async fn _signal(&self, signal: Signal) -> ProcessResult<()> {
-        if self.is_running().await? {
-            pid_kill(self.pid, signal)
-        } else {
-            Err(ProcessError::NoSuchProcess(self.pid))
-        }
+        pid_kill(self.pid, signal)
     }
 
     pub fn signal(&self, signal: Signal) -> BoxFuture<ProcessResult<()>> {
@@ -160,13 +299,115 @@ impl Process {
         self._signal(Signal::Kill).await
     }
 
-    pub async fn wait(&self) -> ProcessResult<()> {
-        pid_wait(self.pid).await
+    pub fn kill_sync(&self, action: GuardAction) -> ProcessResult<()> {
+        let signal = match action {
+            GuardAction::Terminate => Signal::Term,
+            GuardAction::Kill => Signal::Kill,
+        };
+
+        pid_kill(self.pid, signal)
+    }
+
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        if !self.is_running().await? {
+            return Ok(None);
+        }
+
+        match pid_wait(self.pid).await? {
+            Some(status) => Ok(Some(status)),
+            // Not a child of the current process, exit status is not available,
+            // but `kqueue` still can notify us about the process exit
+            None => match wait::wait_exit(self.pid).await {
+                Ok(()) => Ok(None),
+                Err(ProcessError::NoSuchProcess(..)) => Ok(None),
+                Err(..) => {
+                    poll_exit(|| self.is_running()).await?;
+                    Ok(None)
+                }
+            },
+        }
     }
 
     pub async fn io_counters(&self) -> ProcessResult<IoCounters> {
         io_counters::io(self.pid).await
     }
+
+    pub async fn oneshot(&self, fields: ProcessFields) -> ProcessResult<ProcessOneShot> {
+        let mut oneshot = ProcessOneShot::default();
+
+        // One `kinfo_proc` struct serves the parent pid, name and status
+        let kinfo_fields = ProcessFields::PARENT_PID | ProcessFields::NAME | ProcessFields::STATUS;
+        if fields.intersects(kinfo_fields) {
+            let kinfo_proc =
+                attribute(bindings::process(self.pid).map_err(|e| catch_zombie(e, self.pid)))?;
+            if let Some(kinfo_proc) = kinfo_proc {
+                if fields.contains(ProcessFields::PARENT_PID) {
+                    oneshot.parent_pid = Some(Pid::from(kinfo_proc.kp_eproc.e_ppid));
+                }
+                if fields.contains(ProcessFields::NAME) {
+                    let raw_str = unsafe { CStr::from_ptr(kinfo_proc.kp_proc.p_comm.as_ptr()) };
+                    oneshot.name = Some(raw_str.to_string_lossy().into_owned());
+                }
+                if fields.contains(ProcessFields::STATUS) {
+                    oneshot.status = Some(Status::from_p_stat(kinfo_proc.kp_proc.p_stat));
+                }
+            }
+        }
+        if fields.contains(ProcessFields::CREATE_TIME) {
+            oneshot.create_time = Some(self.unique_id.create_time());
+        }
+        // And one `task_info` call serves the CPU time and memory
+        if fields.intersects(ProcessFields::CPU_TIME | ProcessFields::MEMORY) {
+            let task_info = attribute(match darwin_libproc::task_info(*self.pid) {
+                Ok(task_info) => Ok(task_info),
+                Err(ref e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                    Err(ProcessError::AccessDenied(self.pid))
+                }
+                Err(e) => Err(catch_zombie(e, self.pid)),
+            })?;
+            if let Some(task_info) = task_info {
+                if fields.contains(ProcessFields::CPU_TIME) {
+                    oneshot.cpu_time = Some(CpuTime::from(task_info).into());
+                }
+                if fields.contains(ProcessFields::MEMORY) {
+                    oneshot.memory = Some(Memory::from(task_info).into());
+                }
+            }
+        }
+
+        if fields.contains(ProcessFields::IO_COUNTERS) {
+            oneshot.io_counters = attribute(self.io_counters().await)?.map(Into::into);
+        }
+
+        Ok(oneshot)
+    }
+
+    // macOS-specific methods
+
+    pub async fn snapshot(&self) -> ProcessResult<ProcessSnapshot> {
+        let kinfo_proc = match bindings::process(self.pid) {
+            Ok(kinfo_proc) => kinfo_proc,
+            Err(e) => return Err(catch_zombie(e, self.pid)),
+        };
+
+        let raw_str = unsafe { CStr::from_ptr(kinfo_proc.kp_proc.p_comm.as_ptr()) };
+        let name = raw_str.to_string_lossy().into_owned();
+        let status = Status::from_p_stat(kinfo_proc.kp_proc.p_stat);
+        let pcred = kinfo_proc.kp_eproc.e_pcred;
+        let ucred = kinfo_proc.kp_eproc.e_ucred;
+        let uids = Uids::new(pcred.p_ruid, ucred.cr_uid, pcred.p_svuid);
+        // Same value as the `Process::create_time` returns
+        let create_time = self.unique_id.create_time();
+
+        Ok(ProcessSnapshot::new(
+            self.pid,
+            Pid::from(kinfo_proc.kp_eproc.e_ppid),
+            name,
+            status,
+            uids,
+            create_time,
+        ))
+    }
 }
 
 impl hash::Hash for Process {
@@ -189,15 +430,70 @@ pub async fn processes() -> Result<impl Stream<Item = ProcessResult<Process>>> {
     Ok(inner.map_err(Into::into).and_then(get))
 }
 
+pub async fn processes_with_info(
+) -> Result<impl Stream<Item = ProcessResult<(Process, ProcessInfo)>>> {
+    // All the processes information is fetched with one `KERN_PROC_ALL` sysctl call
+    // and converted lazily, same as `pids` does.
+    let boot_time = heim_host::boot_time().await?;
+    let processes =
+        bindings::processes()?.map(move |kinfo_proc| -> ProcessResult<(Process, ProcessInfo)> {
+            let pid = Pid::from(kinfo_proc.kp_proc.p_pid);
+            let raw_str = unsafe { CStr::from_ptr(kinfo_proc.kp_proc.p_comm.as_ptr()) };
+            let name = raw_str.to_string_lossy().into_owned();
+            let status = Status::from_p_stat(kinfo_proc.kp_proc.p_stat);
+            let create_time = create_time(&kinfo_proc, boot_time);
+            let process = Process {
+                pid,
+                unique_id: UniqueId::new(pid, create_time),
+            };
+            let info = ProcessInfo::new(
+                Pid::from(kinfo_proc.kp_eproc.e_ppid),
+                name,
+                status,
+                create_time,
+            );
+
+            Ok((process, info))
+        });
+
+    Ok(stream::iter(processes))
+}
+
+/// Returns the process creation time with a microseconds precision.
+///
+/// `p_starttime` is filled by the kernel when process is forked,
+/// but it is zero for the `kernel_task` (pid 0), which was never forked.
+/// Values which are zero or which are before the system boot are considered as implausible,
+/// and the `proc_pidinfo` call is used as a fallback then;
+/// if it fails too (ex. because of insufficient permissions), process is considered
+/// to be started together with the system.
+///
+/// Same value is returned for the same process on each call,
+/// as the same sources are checked in the same order.
+fn create_time(kinfo_proc: &bindings::kinfo_proc, boot_time: Time) -> Time {
+    let create_time = unsafe { kinfo_proc.kp_proc.p_un.p_starttime }.into_time();
+    if create_time >= boot_time {
+        return create_time;
+    }
+
+    if let Ok(info) = darwin_libproc::task_all_info(kinfo_proc.kp_proc.p_pid) {
+        let create_time = Time::new::<time::second>(info.pbsd.pbi_start_tvsec as f64)
+            + Time::new::<time::microsecond>(info.pbsd.pbi_start_tvusec as f64);
+        if create_time >= boot_time {
+            return create_time;
+        }
+    }
+
+    boot_time
+}
+
 pub async fn get(pid: Pid) -> ProcessResult<Process> {
+    // Loaded before the `kinfo_proc`, as it can't be held across the `.await`
+    let boot_time = heim_host::boot_time().await?;
+
     match bindings::process(pid) {
         Ok(kinfo_proc) => {
-            let create_time = unsafe {
-                // TODO: How can it be guaranteed that in this case
-                // `p_un.p_starttime` will be filled correctly?
-                kinfo_proc.kp_proc.p_un.p_starttime
-            };
-            let create_time = create_time.into_time();
+            let create_time = create_time(&kinfo_proc, boot_time);
             debug_assert!(!create_time.is_nan());
 
             Ok(Process {
@@ -210,7 +506,7 @@ pub async fn get(pid: Pid) -> ProcessResult<Process> {
 }
 
 pub async fn current() -> ProcessResult<Process> {
-    let pid = unsafe { libc::getpid() };
+    let pid = Pid::from(unsafe { libc::getpid() });
 
     get(pid).await
 }
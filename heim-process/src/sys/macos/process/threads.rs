@@ -0,0 +1,99 @@
+use std::ptr;
+use std::slice;
+
+use mach::kern_return::KERN_SUCCESS;
+use mach::mach_port::mach_port_deallocate;
+use mach::mach_types::{thread_act_array_t, thread_act_t};
+use mach::message::mach_msg_type_number_t;
+use mach::port::{mach_port_name_t, MACH_PORT_NULL};
+use mach::task::task_threads;
+use mach::traps::{mach_task_self, task_for_pid};
+use mach::vm_types::{vm_address_t, vm_size_t};
+
+use super::CpuTime;
+use crate::sys::macos::{bindings, utils::catch_task};
+use crate::sys::Thread;
+use crate::{Pid, ProcessResult};
+
+/// Task port for some process, deallocated on drop.
+struct TaskPort(mach_port_name_t);
+
+impl Drop for TaskPort {
+    fn drop(&mut self) {
+        let _ = unsafe { mach_port_deallocate(mach_task_self(), self.0) };
+    }
+}
+
+#[allow(trivial_casts)]
+pub fn threads(pid: Pid) -> ProcessResult<Vec<Thread>> {
+    let mut task: mach_port_name_t = MACH_PORT_NULL;
+    let result = unsafe { task_for_pid(mach_task_self(), *pid, &mut task) };
+    if result != KERN_SUCCESS {
+        return Err(catch_task(pid));
+    }
+    let task = TaskPort(task);
+
+    let mut list: thread_act_array_t = ptr::null_mut();
+    let mut count: mach_msg_type_number_t = 0;
+    let result = unsafe { task_threads(task.0, &mut list, &mut count) };
+    if result != KERN_SUCCESS {
+        return Err(catch_task(pid));
+    }
+
+    let ports = unsafe { slice::from_raw_parts(list, count as usize) };
+    let mut threads = Vec::with_capacity(ports.len());
+    for &port in ports {
+        // Thread might exit after the `task_threads` call, skipping it in that case
+        if let Some(thread) = thread(port) {
+            threads.push(thread);
+        }
+
+        let _ = unsafe { mach_port_deallocate(mach_task_self(), port) };
+    }
+
+    let _ = unsafe {
+        bindings::vm_deallocate(
+            mach_task_self(),
+            list as vm_address_t,
+            count as vm_size_t * std::mem::size_of::<thread_act_t>(),
+        )
+    };
+
+    Ok(threads)
+}
+
+#[allow(trivial_casts)]
+fn thread(port: thread_act_t) -> Option<Thread> {
+    let mut basic_info = bindings::thread_basic_info::default();
+    let mut count = bindings::THREAD_BASIC_INFO_COUNT;
+    let result = unsafe {
+        bindings::thread_info(
+            port,
+            bindings::THREAD_BASIC_INFO,
+            &mut basic_info as *mut _ as bindings::thread_info_t,
+            &mut count,
+        )
+    };
+    if result != KERN_SUCCESS {
+        return None;
+    }
+
+    let mut identifier_info = bindings::thread_identifier_info::default();
+    let mut count = bindings::THREAD_IDENTIFIER_INFO_COUNT;
+    let result = unsafe {
+        bindings::thread_info(
+            port,
+            bindings::THREAD_IDENTIFIER_INFO,
+            &mut identifier_info as *mut _ as bindings::thread_info_t,
+            &mut count,
+        )
+    };
+    if result != KERN_SUCCESS {
+        return None;
+    }
+
+    Some(Thread::new(
+        identifier_info.thread_id,
+        CpuTime::from(basic_info),
+    ))
+}
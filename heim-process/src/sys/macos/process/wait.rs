@@ -0,0 +1,59 @@
+use std::io;
+use std::mem;
+use std::ptr;
+
+use heim_common::Error;
+use heim_runtime as rt;
+
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Closes `kqueue` descriptor on drop.
+struct Kqueue(libc::c_int);
+
+impl Drop for Kqueue {
+    fn drop(&mut self) {
+        let _ = unsafe { libc::close(self.0) };
+    }
+}
+
+/// Blocks until the process with `pid` exits, using `kqueue` `EVFILT_PROC` filter.
+///
+/// Works for any process the caller is allowed to observe, not just for children.
+pub async fn wait_exit(pid: Pid) -> ProcessResult<()> {
+    rt::spawn_blocking(move || {
+        let fd = unsafe { libc::kqueue() };
+        if fd == -1 {
+            return Err(Error::last_os_error().with_ffi("kqueue").into());
+        }
+        let kq = Kqueue(fd);
+
+        let mut change: libc::kevent = unsafe { mem::zeroed() };
+        change.ident = *pid as libc::uintptr_t;
+        change.filter = libc::EVFILT_PROC;
+        change.flags = libc::EV_ADD | libc::EV_ONESHOT;
+        change.fflags = libc::NOTE_EXIT;
+
+        let mut event: libc::kevent = unsafe { mem::zeroed() };
+        loop {
+            let result = unsafe { libc::kevent(kq.0, &change, 1, &mut event, 1, ptr::null()) };
+            match result {
+                -1 => {
+                    let e = io::Error::last_os_error();
+                    match e.raw_os_error() {
+                        Some(libc::EINTR) => continue,
+                        Some(libc::ESRCH) => return Err(ProcessError::NoSuchProcess(pid)),
+                        _ => return Err(Error::from(e).with_ffi("kevent").into()),
+                    }
+                }
+                1 if event.flags & libc::EV_ERROR != 0 => {
+                    return match event.data as libc::c_int {
+                        libc::ESRCH => Err(ProcessError::NoSuchProcess(pid)),
+                        code => Err(Error::from_raw_os_error(code).with_ffi("kevent").into()),
+                    };
+                }
+                _ => return Ok(()),
+            }
+        }
+    })
+    .await
+}
@@ -1,4 +1,6 @@
-use std::convert::TryFrom;
+use std::io;
+
+use heim_common::Error;
 
 use super::bindings;
 use crate::{Pid, ProcessError, Status};
@@ -11,12 +13,38 @@ pub(crate) fn catch_zombie<T: Into<ProcessError>>(e: T, pid: Pid) -> ProcessErro
                 Err(e) => return e,
             };
 
-            match Status::try_from(kinfo_proc.kp_proc.p_stat) {
-                Ok(Status::Zombie) => ProcessError::ZombieProcess(pid),
-                Ok(_) => ProcessError::AccessDenied(pid),
-                Err(e) => e.into(),
+            match Status::from_p_stat(kinfo_proc.kp_proc.p_stat) {
+                Status::Zombie => ProcessError::ZombieProcess(pid),
+                _ => ProcessError::AccessDenied(pid),
             }
         }
         other => other,
     }
 }
+
+/// `KERN_PROCARGS2` sysctl fails with `EINVAL` both for zombie processes
+/// and for processes owned by other users, so the process status is used
+/// to tell one from another.
+pub(crate) fn catch_proc_args(e: Error, pid: Pid) -> ProcessError {
+    match e.raw_os_error() {
+        Some(libc::EINVAL) => catch_task(pid),
+        _ if e.as_inner().kind() == io::ErrorKind::PermissionDenied => {
+            ProcessError::AccessDenied(pid)
+        }
+        _ => catch_zombie(e, pid),
+    }
+}
+
+/// Mach task calls (`task_for_pid` and friends) are failing with the same
+/// generic `kern_return_t` both for zombie processes and for processes
+/// we are not allowed to inspect, so the process status is used to tell
+/// one from another.
+pub(crate) fn catch_task(pid: Pid) -> ProcessError {
+    match bindings::process(pid) {
+        Ok(kinfo_proc) => match Status::from_p_stat(kinfo_proc.kp_proc.p_stat) {
+            Status::Zombie => ProcessError::ZombieProcess(pid),
+            _ => ProcessError::AccessDenied(pid),
+        },
+        Err(e) => e,
+    }
+}
@@ -1,5 +1,7 @@
 mod common;
 
+pub use self::common::{Thread, POLL_MAX_DELAY, POLL_MIN_DELAY};
+
 #[cfg(unix)]
 mod unix;
 
@@ -176,6 +176,27 @@ mod tests {
         assert_eq!(None, env.next());
     }
 
+    #[test]
+    fn test_value_with_delimiter() {
+        let mut env = Environment::from_bytes(b"OPTS=-Dfoo=bar\0EMPTY=\0").into_iter();
+
+        assert_eq!(
+            Some((
+                OsStr::from_bytes(b"OPTS").into(),
+                OsStr::from_bytes(b"-Dfoo=bar").into()
+            )),
+            env.next(),
+        );
+        assert_eq!(
+            Some((
+                OsStr::from_bytes(b"EMPTY").into(),
+                OsStr::from_bytes(b"").into()
+            )),
+            env.next(),
+        );
+        assert_eq!(None, env.next());
+    }
+
     #[test]
     fn test_empty() {
         let mut env = Environment::from_bytes(b"").into_iter();
@@ -1,6 +1,10 @@
 mod bindings;
 mod env;
+mod passwd;
 mod process;
+mod tty;
 
 pub use self::env::{Environment, EnvironmentIter, IntoEnvironmentIter};
-pub use self::process::{pid_exists, pid_kill, pid_priority, pid_setpriority, pid_wait};
+pub use self::passwd::uid_username;
+pub use self::process::{pid_exists, pid_kill, pid_priority, pid_setpriority, pid_wait, poll_exit};
+pub use self::tty::terminal_path;
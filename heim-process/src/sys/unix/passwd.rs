@@ -0,0 +1,62 @@
+use std::ffi::CStr;
+use std::io;
+use std::mem;
+use std::ptr;
+
+use heim_common::Error;
+use heim_runtime as rt;
+
+use crate::ProcessResult;
+
+/// Buffer size used if `_SC_GETPW_R_SIZE_MAX` is indeterminate.
+const DEFAULT_BUFFER_SIZE: usize = 1024;
+
+/// Resolves `uid` into the user login name.
+///
+/// Numeric `uid` representation is returned if there is no passwd entry for it,
+/// which is common in containers.
+pub async fn uid_username(uid: u32) -> ProcessResult<String> {
+    // NSS lookup might go to the network (ex. LDAP), so it is not running
+    // at the async executor
+    rt::spawn_blocking(move || lookup(uid)).await
+}
+
+fn lookup(uid: libc::uid_t) -> ProcessResult<String> {
+    let size = match unsafe { libc::sysconf(libc::_SC_GETPW_R_SIZE_MAX) } {
+        size if size > 0 => size as usize,
+        _ => DEFAULT_BUFFER_SIZE,
+    };
+    let mut buffer: Vec<libc::c_char> = Vec::with_capacity(size);
+
+    loop {
+        let mut passwd = mem::MaybeUninit::<libc::passwd>::uninit();
+        let mut result: *mut libc::passwd = ptr::null_mut();
+        let code = unsafe {
+            libc::getpwuid_r(
+                uid,
+                passwd.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.capacity(),
+                &mut result,
+            )
+        };
+
+        match code {
+            0 if result.is_null() => return Ok(uid.to_string()),
+            0 => {
+                let name = unsafe { CStr::from_ptr((*result).pw_name) };
+
+                return Ok(name.to_string_lossy().into_owned());
+            }
+            libc::ERANGE => buffer.reserve(buffer.capacity() * 2),
+            // Some implementations are returning these codes instead of `0`
+            // for missing entries, see `getpwuid_r(3)`
+            libc::ENOENT | libc::ESRCH | libc::EBADF | libc::EPERM => return Ok(uid.to_string()),
+            other => {
+                let e = io::Error::from_raw_os_error(other);
+
+                return Err(Error::from(e).with_ffi("getpwuid_r").into());
+            }
+        }
+    }
+}
@@ -1,13 +1,15 @@
+use std::cmp;
+use std::future::Future;
 use std::io;
-use std::ptr;
-use std::time::Duration;
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
 
-use heim_common::prelude::StreamExt as _;
 use heim_common::Error;
 use heim_runtime as rt;
 
 use super::bindings::{errno, set_errno};
 use crate::os::unix::Signal;
+use crate::sys::{POLL_MAX_DELAY, POLL_MIN_DELAY};
 use crate::{Pid, ProcessError, ProcessResult};
 
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
@@ -21,8 +23,12 @@ pub fn pid_exists(pid: Pid) -> bool {
     if pid == 0 {
         return true;
     }
+    // Negative pids are referring to the process groups for `kill(2)`
+    if *pid < 0 {
+        return false;
+    }
 
-    let result = unsafe { libc::kill(pid, 0) };
+    let result = unsafe { libc::kill(*pid, 0) };
 
     if result == 0 {
         true
@@ -37,7 +43,7 @@ pub fn pid_exists(pid: Pid) -> bool {
 }
 
 pub fn pid_kill(pid: Pid, signal: Signal) -> ProcessResult<()> {
-    let result = unsafe { libc::kill(pid, signal.into()) };
+    let result = unsafe { libc::kill(*pid, signal.into()) };
 
     if result == 0 {
         Ok(())
@@ -51,45 +57,46 @@ pub fn pid_kill(pid: Pid, signal: Signal) -> ProcessResult<()> {
     }
 }
 
-/// Wait for the process termination.
-pub async fn pid_wait(pid: Pid) -> ProcessResult<()> {
-    // `waitpid` might block indefinitely,
-    // we need to handle that
-    let waited = rt::spawn_blocking(move || {
-        let result = unsafe { libc::waitpid(pid, ptr::null_mut(), 0) };
-        if result == -1 {
-            // Do not care about the error type at this point
-            Err(())
-        } else {
-            Ok(())
+/// Waits for the child process termination and returns its exit status.
+///
+/// `None` is returned if process is not a child of the current process,
+/// as it is not possible to fetch exit status in that case.
+pub async fn pid_wait(pid: Pid) -> ProcessResult<Option<ExitStatus>> {
+    // `waitpid` might block indefinitely, so it is not running at the async executor
+    rt::spawn_blocking(move || loop {
+        let mut status: libc::c_int = 0;
+        let result = unsafe { libc::waitpid(*pid, &mut status, 0) };
+        if result == *pid {
+            return Ok(Some(ExitStatus::from_raw(status)));
         }
-    })
-    .await;
 
-    // Task finished correctly and `waitpid` succeeded too
-    if waited.is_ok() {
-        return Ok(());
-    }
-    // If either task failed or `waitpid` failed,
-    // it could mean that either `pid` is not our child
-    // or there is no such a process at all.
-    // Same to `psutil`, there is nothing left to do,
-    // except for a naive checking for pid existence in a loop
-
-    let interval = rt::time::interval(Duration::from_millis(40));
-    futures::pin_mut!(interval);
-    while let Some(..) = interval.next().await {
-        if !pid_exists(pid) {
-            return Ok(());
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            Some(libc::EINTR) => continue,
+            // Either not our child or it was reaped already
+            Some(libc::ECHILD) => return Ok(None),
+            _ => return Err(Error::from(e).with_ffi("waitpid").into()),
         }
+    })
+    .await
+}
+
+/// Waits for the process termination by polling `is_running` with an exponential backoff.
+///
+/// Same to `psutil`, that's the only portable way to wait for processes
+/// which are not children of the current process.
+pub async fn poll_exit<F, Fut>(mut is_running: F) -> ProcessResult<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ProcessResult<bool>>,
+{
+    let mut delay = POLL_MIN_DELAY;
+    while is_running().await? {
+        rt::time::sleep(delay).await;
+        delay = cmp::min(delay * 2, POLL_MAX_DELAY);
     }
 
-    // Logically speaking, this branch is unreachable, as `time::interval`
-    // is infinite, but in order to make compiler happy,
-    // let's show some error
-    let e = Error::from(io::Error::from(io::ErrorKind::Other))
-        .with_message("Unable to await for process termination");
-    Err(e.into())
+    Ok(())
 }
 
 /// Safe wrapper for POSIX `getpriority`
@@ -98,7 +105,7 @@ pub fn pid_priority(pid: Pid) -> ProcessResult<libc::c_int> {
     // it is necessary to clear the external variable `errno` prior to the call,
     // then check it afterward to determine if `-1` is an error or a legitimate value.
     set_errno(0);
-    let result = unsafe { libc::getpriority(PRIO_PROCESS, pid as libc::id_t) };
+    let result = unsafe { libc::getpriority(PRIO_PROCESS, *pid as libc::id_t) };
 
     match errno() {
         0 => Ok(result),
@@ -112,10 +119,17 @@ pub fn pid_priority(pid: Pid) -> ProcessResult<libc::c_int> {
 
 /// Safe wrapper for POSIX `setpriority`
 pub fn pid_setpriority(pid: Pid, value: i32) -> ProcessResult<()> {
-    let result = unsafe { libc::setpriority(PRIO_PROCESS, pid as libc::id_t, value) };
+    let result = unsafe { libc::setpriority(PRIO_PROCESS, *pid as libc::id_t, value) };
     if result == 0 {
-        Ok(())
-    } else {
-        Err(Error::last_os_error().into())
+        return Ok(());
+    }
+
+    let e = Error::last_os_error().with_ffi("setpriority");
+    match e.raw_os_error() {
+        Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(pid)),
+        // `EPERM` is returned if process owner differs from the caller,
+        // `EACCES` is for attempts to lower the niceness without privileges
+        Some(libc::EACCES) | Some(libc::EPERM) => Err(ProcessError::AccessDenied(pid)),
+        _ => Err(e.into()),
     }
 }
@@ -0,0 +1,51 @@
+use std::fs;
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
+use std::path::PathBuf;
+
+/// Directories holding the terminal devices.
+///
+/// `/dev/pts/` is checked first, as the pseudo-terminals are the most common ones on Linux;
+/// it is missing on macOS, where pseudo-terminals are named as `/dev/ttys003`.
+const TERMINAL_DIRS: [&str; 2] = ["/dev/pts", "/dev"];
+
+/// Resolves the terminal device number into the device file path.
+///
+/// There is no reverse mapping provided by the system,
+/// so the device directories are scanned for the character device with the same `rdev`.
+/// Symlinks (ex. `/dev/stdin`) are not followed.
+pub fn terminal_path(rdev: u64) -> Option<PathBuf> {
+    for dir in TERMINAL_DIRS.iter() {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(..) => continue,
+        };
+
+        for entry in entries.flatten() {
+            let metadata = match entry.metadata() {
+                Ok(metadata) => metadata,
+                Err(..) => continue,
+            };
+            if metadata.file_type().is_char_device() && metadata.rdev() == rdev {
+                return Some(entry.path());
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::MetadataExt;
+    use std::path::Path;
+
+    use super::terminal_path;
+
+    #[test]
+    fn test_terminal_path() {
+        let rdev = fs::metadata("/dev/null").unwrap().rdev();
+
+        assert_eq!(Some(Path::new("/dev/null")), terminal_path(rdev).as_deref());
+    }
+}
@@ -26,6 +26,9 @@ use crate::os::windows::Priority;
 use crate::sys::windows::process::CpuTime;
 use crate::{Pid, ProcessError, ProcessResult};
 
+/// Maximum length of the extended-length (`\\?\` prefixed) path in UTF-16 chars.
+const EXTENDED_MAX_PATH: usize = 32_767;
+
 #[derive(Debug)]
 pub struct QueryLimitedInformation;
 impl ProcessHandlePermissions for QueryLimitedInformation {
@@ -64,19 +67,35 @@ impl ProcessHandle<QueryLimitedInformation> {
     }
 
     pub fn exe(&self) -> ProcessResult<PathBuf> {
-        let mut buffer: [wchar_t; MAX_PATH] = [0; MAX_PATH];
-        let mut size = MAX_PATH as DWORD;
+        let mut buffer: Vec<wchar_t> = vec![0; MAX_PATH];
 
-        let result = unsafe {
-            winbase::QueryFullProcessImageNameW(*self.handle, 0, buffer.as_mut_ptr(), &mut size)
-        };
+        loop {
+            let mut size = buffer.len() as DWORD;
+            let result = unsafe {
+                winbase::QueryFullProcessImageNameW(*self.handle, 0, buffer.as_mut_ptr(), &mut size)
+            };
 
-        if result == 0 {
-            Err(Error::last_os_error()
-                .with_ffi("QueryFullProcessImageNameW")
-                .into())
-        } else {
-            Ok(OsString::from_wide(&buffer[..(size as usize)]).into())
+            if result != 0 {
+                return Ok(OsString::from_wide(&buffer[..(size as usize)]).into());
+            }
+
+            let e = Error::last_os_error().with_ffi("QueryFullProcessImageNameW");
+            match e.raw_os_error() {
+                // Path is longer than `MAX_PATH`, extended-length paths
+                // are limited to the `UNICODE_STRING` capacity
+                Some(code)
+                    if code == winerror::ERROR_INSUFFICIENT_BUFFER as i32
+                        && buffer.len() < EXTENDED_MAX_PATH =>
+                {
+                    buffer.resize(EXTENDED_MAX_PATH, 0);
+                }
+                // Protected processes can be opened, but their image name
+                // is not available for the non-privileged users
+                _ if e.as_inner().kind() == io::ErrorKind::PermissionDenied => {
+                    return Err(ProcessError::AccessDenied(self.pid))
+                }
+                _ => return Err(e.into()),
+            }
         }
     }
 
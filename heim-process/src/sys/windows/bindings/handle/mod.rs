@@ -13,10 +13,12 @@ use crate::{Pid, ProcessError, ProcessResult};
 mod limited_info;
 mod set_information;
 mod suspend;
+mod synchronize;
 mod termination;
 
 pub use limited_info::QueryLimitedInformation;
 pub use suspend::SuspendResume;
+pub use synchronize::Synchronize;
 pub use termination::Termination;
 
 pub trait ProcessHandlePermissions {
@@ -25,7 +27,7 @@ pub trait ProcessHandlePermissions {
 
     /// Open the process handle with `Self::ACCESS` permissions.
     fn open(pid: Pid) -> ProcessResult<Handle> {
-        let handle = unsafe { processthreadsapi::OpenProcess(Self::ACCESS, 0, pid) };
+        let handle = unsafe { processthreadsapi::OpenProcess(Self::ACCESS, 0, *pid) };
 
         if handle.is_null() {
             let e = Error::last_os_error().with_ffi("OpenProcess");
@@ -0,0 +1,52 @@
+//! Process handle variant used for awaiting the process termination.
+
+use std::marker::PhantomData;
+
+use winapi::shared::minwindef::DWORD;
+use winapi::um::{minwinbase, processthreadsapi, synchapi, winbase, winnt};
+
+use heim_common::Error;
+
+use super::{ProcessHandle, ProcessHandlePermissions};
+use crate::{Pid, ProcessError, ProcessResult};
+
+#[derive(Debug)]
+pub struct Synchronize;
+impl ProcessHandlePermissions for Synchronize {
+    const ACCESS: DWORD = winnt::SYNCHRONIZE | winnt::PROCESS_QUERY_LIMITED_INFORMATION;
+}
+
+impl ProcessHandle<Synchronize> {
+    pub fn for_synchronize(pid: Pid) -> ProcessResult<ProcessHandle<Synchronize>> {
+        if pid == 0 {
+            return Err(ProcessError::AccessDenied(pid));
+        }
+
+        let handle = Synchronize::open(pid)?;
+
+        Ok(ProcessHandle {
+            handle,
+            pid,
+            _type: PhantomData,
+        })
+    }
+
+    /// Blocks until the process exits and returns its exit code.
+    pub fn wait(&self) -> ProcessResult<DWORD> {
+        let result = unsafe { synchapi::WaitForSingleObject(*self.handle, winbase::INFINITE) };
+        if result == winbase::WAIT_FAILED {
+            return Err(Error::last_os_error()
+                .with_ffi("WaitForSingleObject")
+                .into());
+        }
+
+        let mut code: DWORD = 0;
+        let result = unsafe { processthreadsapi::GetExitCodeProcess(*self.handle, &mut code) };
+        if result == 0 {
+            return Err(Error::last_os_error().with_ffi("GetExitCodeProcess").into());
+        }
+        debug_assert_ne!(code, minwinbase::STILL_ACTIVE);
+
+        Ok(code)
+    }
+}
@@ -63,3 +63,64 @@ impl Iterator for Snapshot {
         }
     }
 }
+
+/// Threads snapshot.
+///
+/// Contains threads of all system processes,
+/// it is up to the caller to filter them by the owner process ID.
+pub struct ThreadSnapshot {
+    handle: winnt::HANDLE,
+    first: bool,
+}
+
+impl ThreadSnapshot {
+    pub fn new() -> Result<ThreadSnapshot> {
+        let handle = unsafe { tlhelp32::CreateToolhelp32Snapshot(tlhelp32::TH32CS_SNAPTHREAD, 0) };
+        if handle == handleapi::INVALID_HANDLE_VALUE {
+            Err(Error::last_os_error().with_ffi("CreateToolhelp32Snapshot"))
+        } else {
+            Ok(ThreadSnapshot {
+                handle,
+                first: true,
+            })
+        }
+    }
+}
+
+impl Drop for ThreadSnapshot {
+    fn drop(&mut self) {
+        let result = unsafe { handleapi::CloseHandle(self.handle) };
+        debug_assert!(result != 0);
+    }
+}
+
+impl Iterator for ThreadSnapshot {
+    type Item = Result<tlhelp32::THREADENTRY32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut entry = mem::MaybeUninit::<tlhelp32::THREADENTRY32>::uninit();
+        let size = mem::size_of::<tlhelp32::THREADENTRY32>() as minwindef::DWORD;
+        unsafe {
+            (*entry.as_mut_ptr()).dwSize = size;
+        }
+
+        let result = if self.first {
+            self.first = false;
+
+            unsafe { tlhelp32::Thread32First(self.handle, entry.as_mut_ptr()) }
+        } else {
+            unsafe { tlhelp32::Thread32Next(self.handle, entry.as_mut_ptr()) }
+        };
+
+        if result == 1 {
+            let entry = unsafe { entry.assume_init() };
+            Some(Ok(entry))
+        } else {
+            let e = io::Error::last_os_error();
+            match e.raw_os_error() {
+                Some(code) if code as u32 == winerror::ERROR_NO_MORE_FILES => None,
+                _ => Some(Err(e.into())),
+            }
+        }
+    }
+}
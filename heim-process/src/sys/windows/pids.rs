@@ -5,7 +5,6 @@ use winapi::um::minwinbase;
 use super::bindings;
 use crate::{Pid, ProcessError, ProcessResult};
 
-#[allow(clippy::useless_conversion)]
 pub async fn pids() -> Result<impl Stream<Item = Result<Pid>>> {
     let pids = bindings::pids()?.into_iter().map(|pid| Ok(Pid::from(pid)));
 
@@ -7,6 +7,13 @@ pub struct CpuTime {
 }
 
 impl CpuTime {
+    pub fn new(user: Time, system: Time) -> CpuTime {
+        CpuTime {
+            user,
+            kernel: system,
+        }
+    }
+
     pub fn user(&self) -> Time {
         self.user
     }
@@ -14,4 +21,12 @@ impl CpuTime {
     pub fn system(&self) -> Time {
         self.kernel
     }
+
+    pub fn children_user(&self) -> Option<Time> {
+        None
+    }
+
+    pub fn children_system(&self) -> Option<Time> {
+        None
+    }
 }
@@ -11,7 +11,7 @@ const ERROR_ACCESS_DENIED: i32 = winerror::ERROR_ACCESS_DENIED as i32;
 const ERROR_PRIVILEGE_NOT_HELD: i32 = winerror::ERROR_PRIVILEGE_NOT_HELD as i32;
 
 fn traverse(pid: Pid) -> ProcessResult<Time> {
-    let pid_handle = pid as ntdef::HANDLE;
+    let pid_handle = *pid as ntdef::HANDLE;
     let processes = bindings::processes::NtProcesses::load()?;
     let process = processes
         .iter()
@@ -0,0 +1,22 @@
+use winapi::shared::ntdef;
+
+use super::bindings;
+use crate::{CtxSwitches, Pid, ProcessError, ProcessResult};
+
+pub fn ctx_switches(pid: Pid) -> ProcessResult<CtxSwitches> {
+    let pid_handle = *pid as ntdef::HANDLE;
+    let processes = bindings::processes::NtProcesses::load()?;
+    let process = processes
+        .iter()
+        .find(|process| process.process.UniqueProcessId == pid_handle)
+        .ok_or(ProcessError::NoSuchProcess(pid))?;
+
+    // Windows does not distinguish voluntary and involuntary context switches
+    let total = process
+        .threads
+        .iter()
+        .map(|thread| u64::from(thread.ContextSwitches))
+        .sum();
+
+    Ok(CtxSwitches::new(total, 0))
+}
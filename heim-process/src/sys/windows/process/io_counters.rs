@@ -8,6 +8,14 @@ use winapi::um::winnt;
 pub struct IoCounters(winnt::IO_COUNTERS);
 
 impl IoCounters {
+    pub fn read_count(&self) -> u64 {
+        self.read_iops()
+    }
+
+    pub fn write_count(&self) -> u64 {
+        self.write_iops()
+    }
+
     /// The number of read operations performed.
     pub fn read_iops(&self) -> u64 {
         self.0.ReadOperationCount
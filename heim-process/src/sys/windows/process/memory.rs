@@ -1,4 +1,5 @@
 use std::fmt;
+use std::mem;
 
 use heim_common::units::{information, Information};
 use winapi::um::psapi;
@@ -6,6 +7,15 @@ use winapi::um::psapi;
 pub struct Memory(psapi::PROCESS_MEMORY_COUNTERS_EX);
 
 impl Memory {
+    pub fn new(rss: Information, vms: Information) -> Memory {
+        let mut counters: psapi::PROCESS_MEMORY_COUNTERS_EX = unsafe { mem::zeroed() };
+        counters.cb = mem::size_of::<psapi::PROCESS_MEMORY_COUNTERS_EX>() as u32;
+        counters.WorkingSetSize = rss.get::<information::byte>() as usize;
+        counters.PagefileUsage = vms.get::<information::byte>() as usize;
+
+        Memory(counters)
+    }
+
     pub fn rss(&self) -> Information {
         // TODO: Possible truncation from `usize` to `u64`
         Information::new::<information::byte>(self.0.WorkingSetSize as u64)
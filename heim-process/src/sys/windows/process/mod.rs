@@ -1,26 +1,37 @@
 use std::cmp;
 use std::ffi::OsString;
 use std::hash;
+use std::io;
 use std::os::windows::ffi::OsStringExt;
+use std::os::windows::process::ExitStatusExt;
 use std::path::PathBuf;
+use std::process::ExitStatus;
 
 use heim_common::prelude::*;
 use heim_common::units::Time;
+use heim_runtime as rt;
 use winapi::um::processthreadsapi;
 
 use super::{bindings, pid_exists, pids};
 use crate::os::windows::Priority;
+use crate::process::attribute;
 use crate::sys::common::UniqueId;
-use crate::{Pid, ProcessError, ProcessResult, Status};
+use crate::sys::Thread;
+use crate::{
+    CtxSwitches, GuardAction, Pid, ProcessError, ProcessFields, ProcessInfo, ProcessOneShot,
+    ProcessResult, Status,
+};
 
 mod command;
 mod cpu_times;
 mod create_time;
+mod ctx_switches;
 mod env;
 mod io_counters;
 mod memory;
 mod priority;
 mod suspend;
+mod threads;
 
 pub use self::command::{Command, CommandIter};
 pub use self::cpu_times::CpuTime;
@@ -44,13 +55,13 @@ impl Process {
 
         snapshot
             .flatten()
-            .find(|entry| entry.th32ProcessID == self.pid)
-            .map(|entry| Ok(entry.th32ParentProcessID))
+            .find(|entry| self.pid == entry.th32ProcessID)
+            .map(|entry| Ok(Pid::from(entry.th32ParentProcessID)))
             .unwrap_or_else(|| Err(ProcessError::NoSuchProcess(self.pid)))
     }
 
     pub async fn name(&self) -> ProcessResult<String> {
-        let res = match self.pid {
+        let res = match *self.pid {
             0 => Ok("System Idle Process".to_string()),
             4 => Ok("System".to_string()),
             _ => Err(()),
@@ -75,7 +86,7 @@ impl Process {
 
             snapshot
                 .flatten()
-                .find(|entry| entry.th32ProcessID == self.pid)
+                .find(|entry| self.pid == entry.th32ProcessID)
                 .map(|entry| {
                     let first_null = entry
                         .szExeFile
@@ -104,6 +115,11 @@ impl Process {
         self::command::command(self.pid)
     }
 
+    pub async fn terminal(&self) -> ProcessResult<Option<PathBuf>> {
+        // There are no controlling terminals on Windows
+        Ok(None)
+    }
+
     pub async fn cwd(&self) -> ProcessResult<PathBuf> {
         let handle = bindings::ProcessHandle::query_limited_info(self.pid)?;
 
@@ -137,6 +153,24 @@ impl Process {
         }
     }
 
+    pub async fn num_ctx_switches(&self) -> ProcessResult<CtxSwitches> {
+        ctx_switches::ctx_switches(self.pid)
+    }
+
+    pub async fn threads(&self) -> ProcessResult<impl Stream<Item = ProcessResult<Thread>>> {
+        let threads = threads::threads(self.pid).await?;
+
+        Ok(stream::iter(threads).map(Ok))
+    }
+
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        threads::num_threads(self.pid)
+    }
+
+    pub async fn status_and_threads(&self) -> ProcessResult<(Status, u64)> {
+        suspend::status_and_threads(self.pid)
+    }
+
     pub async fn memory(&self) -> ProcessResult<Memory> {
         // TODO: Move that check into the `bindings::ProcessHandle`?
         if self.pid == 0 {
@@ -148,6 +182,46 @@ impl Process {
         }
     }
 
+    pub async fn oneshot(&self, fields: ProcessFields) -> ProcessResult<ProcessOneShot> {
+        let mut oneshot = ProcessOneShot::default();
+
+        if fields.contains(ProcessFields::PARENT_PID) {
+            oneshot.parent_pid = attribute(self.parent_pid().await)?;
+        }
+        if fields.contains(ProcessFields::NAME) {
+            oneshot.name = attribute(self.name().await)?;
+        }
+        if fields.contains(ProcessFields::STATUS) {
+            oneshot.status = attribute(self.status().await)?;
+        }
+        if fields.contains(ProcessFields::CREATE_TIME) {
+            oneshot.create_time = Some(self.unique_id.create_time());
+        }
+        // Same process handle serves both CPU time and memory
+        if fields.intersects(ProcessFields::CPU_TIME | ProcessFields::MEMORY) {
+            let handle = if self.pid == 0 {
+                Err(ProcessError::AccessDenied(self.pid))
+            } else {
+                bindings::ProcessHandle::query_limited_info(self.pid)
+            };
+            if let Some(handle) = attribute(handle)? {
+                if fields.contains(ProcessFields::CPU_TIME) {
+                    oneshot.cpu_time = attribute(handle.cpu_time())?.map(Into::into);
+                }
+                if fields.contains(ProcessFields::MEMORY) {
+                    oneshot.memory =
+                        attribute(handle.memory())?.map(|memory| Memory::from(memory).into());
+                }
+            }
+        }
+
+        if fields.contains(ProcessFields::IO_COUNTERS) {
+            oneshot.io_counters = attribute(self.io_counters().await)?.map(Into::into);
+        }
+
+        Ok(oneshot)
+    }
+
     pub async fn priority(&self) -> ProcessResult<Priority> {
         let handle = bindings::ProcessHandle::query_limited_info(self.pid)?;
         handle.priority()
@@ -159,10 +233,24 @@ impl Process {
         handle.set_priority(value).map_err(Into::into)
     }
 
-    pub async fn is_running(&self) -> ProcessResult<bool> {
-        let other = get(self.pid).await?;
+    pub async fn cpu_affinity(&self) -> ProcessResult<Vec<usize>> {
+        let inner = io::Error::from(io::ErrorKind::Other);
+
+        Err(Error::from(inner)
+            .with_message("CPU affinity is not supported on Windows")
+            .into())
+    }
 
-        Ok(other == *self)
+    pub async fn set_cpu_affinity(&self, _cpus: &[usize]) -> ProcessResult<()> {
+        self.cpu_affinity().await.map(|_| ())
+    }
+
+    pub async fn is_running(&self) -> ProcessResult<bool> {
+        match get(self.pid).await {
+            Ok(other) => Ok(other == *self),
+            Err(ProcessError::NoSuchProcess(..)) => Ok(false),
+            Err(e) => Err(e),
+        }
     }
 
     pub async fn suspend(&self) -> ProcessResult<()> {
@@ -187,8 +275,30 @@ impl Process {
         handle.terminate().map_err(Into::into)
     }
 
-    pub async fn wait(&self) -> ProcessResult<()> {
-        unimplemented!()
+    pub fn kill_sync(&self, _action: GuardAction) -> ProcessResult<()> {
+        // There is no graceful termination for Windows, same to the `terminate` method
+        let handle = bindings::ProcessHandle::for_termination(self.pid)?;
+
+        handle.terminate().map_err(Into::into)
+    }
+
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        if !self.is_running().await? {
+            return Ok(None);
+        }
+
+        let pid = self.pid;
+        // `WaitForSingleObject` blocks until the process exits
+        rt::spawn_blocking(move || {
+            let handle = match bindings::ProcessHandle::for_synchronize(pid) {
+                Ok(handle) => handle,
+                Err(ProcessError::NoSuchProcess(..)) => return Ok(None),
+                Err(e) => return Err(e),
+            };
+
+            handle.wait().map(|code| Some(ExitStatus::from_raw(code)))
+        })
+        .await
     }
 
     pub async fn io_counters(&self) -> ProcessResult<IoCounters> {
@@ -234,6 +344,20 @@ pub async fn processes() -> Result<impl Stream<Item = ProcessResult<Process>>> {
     Ok(stream.map_err(Into::into).and_then(get_unchecked))
 }
 
+pub async fn processes_with_info(
+) -> Result<impl Stream<Item = ProcessResult<(Process, ProcessInfo)>>> {
+    let stream = processes().await?;
+
+    Ok(stream.and_then(|process| async move {
+        let parent_pid = process.parent_pid().await?;
+        let name = process.name().await?;
+        let status = process.status().await?;
+        let info = ProcessInfo::new(parent_pid, name, status, process.unique_id.create_time());
+
+        Ok((process, info))
+    }))
+}
+
 pub async fn get(pid: Pid) -> ProcessResult<Process> {
     if pid_exists(pid).await? {
         get_unchecked(pid).await
@@ -243,7 +367,7 @@ pub async fn get(pid: Pid) -> ProcessResult<Process> {
 }
 
 pub async fn current() -> ProcessResult<Process> {
-    let pid = unsafe { processthreadsapi::GetCurrentProcessId() };
+    let pid = Pid::from(unsafe { processthreadsapi::GetCurrentProcessId() });
 
     get_unchecked(pid).await
 }
@@ -1,20 +1,42 @@
 use ntapi::ntkeapi;
 use winapi::shared::ntdef;
 
-use super::bindings;
-use crate::{Pid, ProcessError, ProcessResult};
+use super::bindings::processes::{NtProcess, NtProcesses};
+use crate::{Pid, ProcessError, ProcessResult, Status};
 
 pub fn is_suspended(pid: Pid) -> ProcessResult<bool> {
-    let pid_handle = pid as ntdef::HANDLE;
-    let processes = bindings::processes::NtProcesses::load()?;
-    let process = processes
+    let processes = NtProcesses::load()?;
+    let process = find(&processes, pid)?;
+
+    Ok(is_process_suspended(&process))
+}
+
+/// Fetches the process status and threads count from the same processes snapshot.
+pub fn status_and_threads(pid: Pid) -> ProcessResult<(Status, u64)> {
+    let processes = NtProcesses::load()?;
+    let process = find(&processes, pid)?;
+    let status = if is_process_suspended(&process) {
+        Status::Stopped
+    } else {
+        Status::Running
+    };
+
+    Ok((status, u64::from(process.process.NumberOfThreads)))
+}
+
+fn find(processes: &NtProcesses, pid: Pid) -> ProcessResult<NtProcess<'_>> {
+    let pid_handle = *pid as ntdef::HANDLE;
+
+    processes
         .iter()
         .find(|process| process.process.UniqueProcessId == pid_handle)
-        .ok_or(ProcessError::NoSuchProcess(pid))?;
+        .ok_or(ProcessError::NoSuchProcess(pid))
+}
 
+fn is_process_suspended(process: &NtProcess<'_>) -> bool {
     let is_running = process.threads.iter().any(|thread| {
         thread.ThreadState != ntkeapi::Waiting || thread.WaitReason != ntkeapi::Suspended
     });
 
-    Ok(!is_running)
+    !is_running
 }
@@ -0,0 +1,73 @@
+use std::mem;
+
+use winapi::shared::minwindef::FILETIME;
+use winapi::shared::ntdef;
+use winapi::um::{processthreadsapi, winnt};
+
+use heim_common::sys::windows::Handle;
+use heim_common::sys::IntoTime;
+
+use super::{bindings, pid_exists, CpuTime};
+use crate::sys::Thread;
+use crate::{Pid, ProcessError, ProcessResult};
+
+pub async fn threads(pid: Pid) -> ProcessResult<Vec<Thread>> {
+    let snapshot = bindings::snapshot::ThreadSnapshot::new()?;
+    let mut threads = Vec::new();
+
+    for entry in snapshot {
+        let entry = entry?;
+        if entry.th32OwnerProcessID != pid {
+            continue;
+        }
+
+        // Thread might exit after the snapshot was taken, skipping it in that case
+        if let Some(cpu_time) = thread_cpu_time(entry.th32ThreadID) {
+            threads.push(Thread::new(u64::from(entry.th32ThreadID), cpu_time));
+        }
+    }
+
+    // Snapshot does not fail for missing processes,
+    // so an empty list might mean that the process is gone
+    if threads.is_empty() && !pid_exists(pid).await? {
+        return Err(ProcessError::NoSuchProcess(pid));
+    }
+
+    Ok(threads)
+}
+
+pub fn num_threads(pid: Pid) -> ProcessResult<u64> {
+    let pid_handle = *pid as ntdef::HANDLE;
+    let processes = bindings::processes::NtProcesses::load()?;
+    let process = processes
+        .iter()
+        .find(|process| process.process.UniqueProcessId == pid_handle)
+        .ok_or(ProcessError::NoSuchProcess(pid))?;
+
+    Ok(u64::from(process.process.NumberOfThreads))
+}
+
+fn thread_cpu_time(tid: u32) -> Option<CpuTime> {
+    let handle =
+        unsafe { processthreadsapi::OpenThread(winnt::THREAD_QUERY_LIMITED_INFORMATION, 0, tid) };
+    if handle.is_null() {
+        return None;
+    }
+    let handle = Handle::new(handle);
+
+    let mut creation: FILETIME = unsafe { mem::zeroed() };
+    let mut exit: FILETIME = unsafe { mem::zeroed() };
+    let mut kernel: FILETIME = unsafe { mem::zeroed() };
+    let mut user: FILETIME = unsafe { mem::zeroed() };
+    let result = unsafe {
+        processthreadsapi::GetThreadTimes(*handle, &mut creation, &mut exit, &mut kernel, &mut user)
+    };
+    if result == 0 {
+        return None;
+    }
+
+    Some(CpuTime {
+        user: user.into_time(),
+        kernel: kernel.into_time(),
+    })
+}
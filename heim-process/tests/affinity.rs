@@ -0,0 +1,30 @@
+#![cfg(target_os = "linux")]
+
+use heim_process as process;
+use heim_process::ProcessError;
+
+#[heim_derive::test]
+async fn test_cpu_affinity() {
+    let current = process::current().await.unwrap();
+    let initial = current.cpu_affinity().await.unwrap();
+    assert!(!initial.is_empty());
+
+    current.set_cpu_affinity(&initial[..1]).await.unwrap();
+    assert_eq!(
+        &initial[..1],
+        current.cpu_affinity().await.unwrap().as_slice()
+    );
+
+    current.set_cpu_affinity(&initial).await.unwrap();
+    assert_eq!(initial, current.cpu_affinity().await.unwrap());
+}
+
+#[heim_derive::test]
+async fn test_set_cpu_affinity_out_of_bounds() {
+    let current = process::current().await.unwrap();
+
+    match current.set_cpu_affinity(&[usize::MAX]).await {
+        Err(ProcessError::Load(e)) => assert_eq!(Some(libc::EINVAL), e.raw_os_error()),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
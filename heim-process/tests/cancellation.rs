@@ -0,0 +1,67 @@
+//! Checks that `processes()` stream dropped in the middle of iteration
+//! frees everything it had allocated.
+//!
+//! Global allocator is replaced in this test binary, so it contains one test only.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+use heim_common::prelude::*;
+use heim_process as process;
+
+struct CountingAllocator;
+
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let _ = ALLOCATED.fetch_add(layout.size() as isize, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        let _ = ALLOCATED.fetch_sub(layout.size() as isize, Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn assert_send<T: Send>(value: T) -> T {
+    value
+}
+
+async fn take_first() {
+    let stream = assert_send(process::processes().await.unwrap());
+    let first = stream.take(1).collect::<Vec<_>>().await;
+    assert_eq!(1, first.len());
+}
+
+#[test]
+fn test_processes_take_first() {
+    smol::block_on(async {
+        // Warming up the runtime, as its blocking threads pool
+        // and lazy statics are allocated once and never freed
+        for _ in 0..3 {
+            take_first().await;
+        }
+
+        let before = ALLOCATED.load(Ordering::SeqCst);
+        for _ in 0..100 {
+            take_first().await;
+        }
+        let after = ALLOCATED.load(Ordering::SeqCst);
+
+        // Runtime threads might start or exit concurrently, so a few bytes are allowed
+        // to be off; leaked buffers would grow with each iteration instead.
+        assert!(
+            after - before < 4096,
+            "{} bytes leaked after dropping the processes stream",
+            after - before
+        );
+    });
+}
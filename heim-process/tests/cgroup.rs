@@ -0,0 +1,19 @@
+#![cfg(target_os = "linux")]
+
+use heim_common::units::time;
+use heim_process as process;
+use heim_process::os::linux::ProcessExt;
+
+#[heim_derive::test]
+async fn test_cgroup_limits() {
+    let current = process::current().await.unwrap();
+    let limits = current.cgroup_limits().await.unwrap();
+
+    if let Some((quota, period)) = limits.cpu_max() {
+        assert!(quota.get::<time::microsecond>() > 0.0);
+        assert!(period.get::<time::microsecond>() > 0.0);
+        assert!(limits.cpus().unwrap() > 0.0);
+    } else {
+        assert!(limits.cpus().is_none());
+    }
+}
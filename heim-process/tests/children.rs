@@ -0,0 +1,52 @@
+#![cfg(unix)]
+
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use heim_common::prelude::*;
+use heim_process as process;
+
+#[heim_derive::test]
+async fn test_children() {
+    let yes_path = match which::which("yes") {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!(
+                "Unable to find `yes` command, children test will be skipped: {:?}",
+                e
+            );
+            return;
+        }
+    };
+
+    // Child should be created strictly later than the current process,
+    // and creation time precision might be as low as a clock tick
+    thread::sleep(Duration::from_millis(100));
+
+    let mut child = Command::new(yes_path)
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let current = process::current().await.unwrap();
+    let children = current.children().await.unwrap();
+    let children = children.try_collect::<Vec<_>>().await.unwrap();
+
+    assert!(
+        children
+            .iter()
+            .any(|process| process.pid() == process::Pid::from(child.id() as process::RawPid)),
+        "Spawned process is missing in the children list: {:?}",
+        children
+    );
+    for process in children {
+        assert_eq!(current.pid(), process.parent_pid().await.unwrap());
+
+        let parent = process.parent().await.unwrap();
+        assert_eq!(Some(current.pid()), parent.map(|parent| parent.pid()));
+    }
+
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+}
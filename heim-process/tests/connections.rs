@@ -0,0 +1,40 @@
+#![cfg(unix)]
+
+use std::net::{TcpListener, UdpSocket};
+
+use heim_common::prelude::*;
+use heim_process as process;
+use heim_process::os::unix::{ConnectionKind, ProcessExt, Protocol, TcpState};
+
+#[heim_derive::test]
+async fn test_connections() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let listener_address = listener.local_addr().unwrap();
+    let socket_address = socket.local_addr().unwrap();
+
+    let current = process::current().await.unwrap();
+    let connections = current.connections(ConnectionKind::All).await.unwrap();
+    let connections = connections.try_collect::<Vec<_>>().await.unwrap();
+
+    let tcp = connections
+        .iter()
+        .find(|conn| conn.local_address() == listener_address)
+        .expect("TCP listener is missing in the connections list");
+    assert_eq!(tcp.protocol(), Protocol::Tcp);
+    assert_eq!(tcp.state(), Some(TcpState::Listen));
+    assert_eq!(tcp.remote_address(), None);
+
+    let udp = connections
+        .iter()
+        .find(|conn| conn.local_address() == socket_address)
+        .expect("UDP socket is missing in the connections list");
+    assert_eq!(udp.protocol(), Protocol::Udp);
+    assert_eq!(udp.state(), None);
+
+    let connections = current.connections(ConnectionKind::Tcp6).await.unwrap();
+    let connections = connections.try_collect::<Vec<_>>().await.unwrap();
+    assert!(connections
+        .iter()
+        .all(|conn| conn.protocol() == Protocol::Tcp && conn.local_address().is_ipv6()));
+}
@@ -0,0 +1,54 @@
+use heim_common::units::{information, time, Information, Time};
+use heim_process::{CpuTime, Memory};
+
+#[test]
+fn test_cpu_time_new() {
+    let cpu_time = CpuTime::new(
+        Time::new::<time::second>(1.5),
+        Time::new::<time::second>(0.5),
+    );
+
+    assert_eq!(Time::new::<time::second>(1.5), cpu_time.user());
+    assert_eq!(Time::new::<time::second>(0.5), cpu_time.system());
+    assert_eq!(None, cpu_time.children_user());
+    assert_eq!(None, cpu_time.children_system());
+}
+
+#[test]
+fn test_memory_new() {
+    let memory = Memory::new(
+        Information::new::<information::mebibyte>(10),
+        Information::new::<information::mebibyte>(100),
+    );
+
+    assert_eq!(Information::new::<information::mebibyte>(10), memory.rss());
+    assert_eq!(Information::new::<information::mebibyte>(100), memory.vms());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_memory_new_linux() {
+    use heim_process::os::linux::MemoryExt;
+
+    let memory = Memory::new(
+        Information::new::<information::byte>(1),
+        Information::new::<information::byte>(2),
+    );
+
+    assert_eq!(Information::new::<information::byte>(0), memory.shared());
+}
+
+#[cfg(target_os = "macos")]
+#[test]
+fn test_memory_new_macos() {
+    use heim_process::os::macos::MemoryExt;
+
+    let memory = Memory::new(
+        Information::new::<information::byte>(1),
+        Information::new::<information::byte>(2),
+    );
+
+    assert_eq!(None, memory.shared());
+    assert_eq!(None, memory.text());
+    assert_eq!(None, memory.data());
+}
@@ -0,0 +1,34 @@
+use heim_common::units::time;
+use heim_process as process;
+
+#[cfg(target_os = "linux")]
+#[heim_derive::test]
+async fn test_children_cpu_time() {
+    use std::process::Command;
+
+    let current = process::current().await.unwrap();
+    let before = current.cpu_time().await.unwrap();
+
+    // Child process is burning some CPU time and it is reaped by `status()` call
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg("i=0; while [ $i -lt 100000 ]; do i=$((i+1)); done")
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let after = current.cpu_time().await.unwrap();
+    let spent = (after.children_user().unwrap() + after.children_system().unwrap())
+        - (before.children_user().unwrap() + before.children_system().unwrap());
+    assert!(spent.get::<time::second>() > 0.0);
+}
+
+#[cfg(not(target_os = "linux"))]
+#[heim_derive::test]
+async fn test_children_cpu_time() {
+    let cpu_time = process::current().await.unwrap().cpu_time().await.unwrap();
+
+    assert!(cpu_time.user().get::<time::second>() >= 0.0);
+    assert!(cpu_time.children_user().is_none());
+    assert!(cpu_time.children_system().is_none());
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use futures::StreamExt;
+
+use heim_process as process;
+use heim_process::ProcessResult;
+
+// `Process` caches CPU usage inside, but it is not used for the `Hash` implementation
+#[allow(clippy::mutable_key_type)]
+#[heim_derive::test]
+async fn test_same_process_instance() -> ProcessResult<()> {
+    let current = process::current().await?;
+    let same = process::get(current.pid()).await?;
+    assert_eq!(current, same);
+
+    let set = vec![current, same].into_iter().collect::<HashSet<_>>();
+    assert_eq!(1, set.len());
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_validate_current() -> ProcessResult<()> {
+    let current = process::current().await?;
+
+    current.validate().await
+}
+
+#[heim_derive::test]
+async fn test_create_time_is_stable() -> ProcessResult<()> {
+    let current = process::current().await?;
+    let same = process::get(current.pid()).await?;
+    assert_eq!(current.create_time().await?, same.create_time().await?);
+
+    let processes = process::processes_with_info().await?;
+    futures::pin_mut!(processes);
+    while let Some(result) = processes.next().await {
+        if let Ok((process, info)) = result {
+            if process.pid() == current.pid() {
+                assert_eq!(current.create_time().await?, info.create_time());
+                assert_eq!(current, process);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+#![cfg(target_os = "linux")]
+
+use heim_process as process;
+use heim_process::{ProcessError, ProcessResult};
+
+#[heim_derive::test]
+async fn test_exe_current() -> ProcessResult<()> {
+    let process = process::current().await?;
+    let exe = process.exe().await?;
+
+    assert!(exe.is_absolute());
+    assert_eq!(std::env::current_exe().unwrap(), exe);
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_exe_kernel_thread() -> ProcessResult<()> {
+    // `kthreadd` is the parent of all kernel threads and has pid 2,
+    // but it might be missing in containers
+    let process = match process::get(process::Pid::new(2)).await {
+        Ok(process) if process.name().await? == "kthreadd" => process,
+        _ => return Ok(()),
+    };
+
+    match process.exe().await {
+        Err(ProcessError::NoExecutable(pid)) => assert_eq!(2, pid),
+        Err(ProcessError::AccessDenied(..)) => {}
+        other => panic!("Unexpected result for a kernel thread: {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_exe_deleted() -> ProcessResult<()> {
+    use heim_process::os::linux::ProcessExt;
+
+    let current = process::current().await?;
+    assert!(!current.exe_deleted().await?);
+
+    let sleep = match which::which("sleep") {
+        Ok(path) => path,
+        Err(..) => return Ok(()),
+    };
+    let copy = std::env::temp_dir().join(format!("heim-exe-deleted-{}", std::process::id()));
+    let _ = std::fs::copy(sleep, &copy)?;
+    let spawned = std::process::Command::new(&copy).arg("10").spawn();
+    std::fs::remove_file(&copy)?;
+    let mut child = match spawned {
+        Ok(child) => child,
+        // Temporary directory might be mounted with `noexec`
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid)).await?;
+    let exe = process.exe().await;
+    let deleted = process.exe_deleted().await;
+    child.kill()?;
+    let _ = child.wait()?;
+
+    assert_eq!(copy, exe?);
+    assert!(deleted?);
+
+    Ok(())
+}
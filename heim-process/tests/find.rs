@@ -0,0 +1,43 @@
+use futures::TryStreamExt;
+
+use heim_process as process;
+use heim_process::ProcessResult;
+
+#[heim_derive::test]
+async fn test_find_by_name() -> ProcessResult<()> {
+    let current = process::current().await?;
+    let name = current.name().await?;
+
+    let found = process::find_by_name(&name)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(found.contains(&current), "{:?} is not found", name);
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_find_by_name_missing() -> ProcessResult<()> {
+    let found = process::find_by_name("heim-missing-process")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(found.is_empty());
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_find_by_exe() -> ProcessResult<()> {
+    let current = process::current().await?;
+    let exe = current.exe().await?;
+
+    let found = process::find_by_exe(&exe)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(found.contains(&current), "{:?} is not found", exe);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use heim_common::prelude::*;
+use heim_process as process;
+use heim_process::ProcessError;
+
+#[heim_derive::test]
+async fn test_get_many() {
+    let current = process::current().await.unwrap();
+    // `RawPid::MAX` exceeds the maximum pid system can assign
+    // (ex. `pid_max` for Linux), so there is no such process
+    let missing = process::Pid::new(process::RawPid::MAX);
+    let pids = [missing, current.pid(), missing];
+
+    let results = process::get_many(&pids).collect::<Vec<_>>().await;
+    assert_eq!(3, results.len());
+
+    for ((pid, result), expected) in results.into_iter().zip(pids.iter()) {
+        assert_eq!(*expected, pid);
+        match result {
+            Ok(process) => assert_eq!(current, process),
+            Err(ProcessError::NoSuchProcess(missing_pid)) => assert_eq!(missing, missing_pid),
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+}
+
+#[heim_derive::test]
+async fn test_get_many_empty() {
+    let results = process::get_many(&[]).collect::<Vec<_>>().await;
+
+    assert!(results.is_empty());
+}
@@ -0,0 +1,50 @@
+#![cfg(unix)]
+
+use heim_process as process;
+use heim_process::os::unix::ProcessExt;
+
+#[heim_derive::test]
+async fn test_uids() {
+    let current = process::current().await.unwrap();
+    let uids = current.uids().await.unwrap();
+
+    assert_eq!(uids.real(), unsafe { libc::getuid() });
+    assert_eq!(uids.effective(), unsafe { libc::geteuid() });
+}
+
+#[heim_derive::test]
+async fn test_gids() {
+    let current = process::current().await.unwrap();
+    let gids = current.gids().await.unwrap();
+
+    assert_eq!(gids.real(), unsafe { libc::getgid() });
+    assert_eq!(gids.effective(), unsafe { libc::getegid() });
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn test_groups() {
+    let current = process::current().await.unwrap();
+    let mut groups = current.groups().await.unwrap();
+
+    let count = unsafe { libc::getgroups(0, std::ptr::null_mut()) };
+    let mut expected = vec![0; count as usize];
+    let count = unsafe { libc::getgroups(count, expected.as_mut_ptr()) };
+    expected.truncate(count as usize);
+
+    groups.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(expected, groups);
+}
+
+#[heim_derive::test]
+async fn test_username() {
+    let current = process::current().await.unwrap();
+    let username = current.username().await.unwrap();
+
+    assert!(!username.is_empty());
+    // `root` is always resolvable
+    if unsafe { libc::geteuid() } == 0 {
+        assert_eq!(username, "root");
+    }
+}
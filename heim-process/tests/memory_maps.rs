@@ -0,0 +1,36 @@
+#![cfg(target_os = "linux")]
+
+use heim_common::prelude::*;
+use heim_common::units::information;
+use heim_process as process;
+use heim_process::os::linux::ProcessExt;
+
+#[heim_derive::test]
+async fn test_memory_maps() {
+    let current = process::current().await.unwrap();
+    let maps = current
+        .memory_maps()
+        .await
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert!(!maps.is_empty());
+    assert!(maps.iter().any(|map| map.path().is_some()));
+
+    let rss = maps
+        .iter()
+        .map(|map| map.rss().get::<information::byte>())
+        .sum::<u64>();
+    assert!(rss > 0);
+
+    for map in &maps {
+        assert!(map.pss() <= map.rss(), "{:?}", map);
+        assert!(
+            map.private_clean() + map.private_dirty() <= map.rss(),
+            "{:?}",
+            map
+        );
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(unix)]
+
+use std::process::{Command, Stdio};
+
+use heim_process as process;
+use heim_process::os::unix::ProcessExt;
+use heim_process::ProcessError;
+
+#[heim_derive::test]
+async fn test_set_niceness() {
+    let yes_path = match which::which("yes") {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!(
+                "Unable to find `yes` command, niceness test will be skipped: {:?}",
+                e
+            );
+            return;
+        }
+    };
+
+    let mut child = Command::new(yes_path)
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    let initial = process.niceness().await.unwrap();
+
+    process.set_niceness(initial + 5).await.unwrap();
+    assert_eq!(initial + 5, process.niceness().await.unwrap());
+
+    // Lowering the niceness back requires privileges
+    match process.set_niceness(initial).await {
+        Ok(()) => assert_eq!(initial, process.niceness().await.unwrap()),
+        Err(ProcessError::AccessDenied(pid)) => assert_eq!(pid, process.pid()),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+
+    match process.set_niceness(initial + 5).await {
+        Err(ProcessError::NoSuchProcess(pid)) => assert_eq!(pid, process.pid()),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
@@ -0,0 +1,50 @@
+use heim_process as process;
+use heim_process::{ProcessFields, ProcessResult};
+
+#[heim_derive::test]
+async fn test_oneshot_all() -> ProcessResult<()> {
+    let process = process::current().await?;
+    let oneshot = process.oneshot(ProcessFields::all()).await?;
+
+    assert_eq!(Some(process.parent_pid().await?), oneshot.parent_pid());
+    assert_eq!(
+        Some(process.name().await?),
+        oneshot.name().map(String::from)
+    );
+    assert_eq!(Some(process.status().await?), oneshot.status());
+    assert_eq!(Some(process.create_time().await?), oneshot.create_time());
+    assert!(oneshot.cpu_time().is_some());
+    assert!(oneshot.memory().is_some());
+    assert!(oneshot.io_counters().is_some());
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_oneshot_selected() -> ProcessResult<()> {
+    let process = process::current().await?;
+    let oneshot = process
+        .oneshot(ProcessFields::NAME | ProcessFields::MEMORY)
+        .await?;
+
+    assert!(oneshot.name().is_some());
+    assert!(oneshot.memory().is_some());
+    assert!(oneshot.parent_pid().is_none());
+    assert!(oneshot.status().is_none());
+    assert!(oneshot.create_time().is_none());
+    assert!(oneshot.cpu_time().is_none());
+    assert!(oneshot.io_counters().is_none());
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_oneshot_empty() -> ProcessResult<()> {
+    let process = process::current().await?;
+    let oneshot = process.oneshot(ProcessFields::empty()).await?;
+
+    assert!(oneshot.name().is_none());
+    assert!(oneshot.memory().is_none());
+
+    Ok(())
+}
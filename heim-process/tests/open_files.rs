@@ -0,0 +1,73 @@
+#![cfg(unix)]
+
+use std::fs::{self, File};
+use std::os::unix::io::AsRawFd;
+
+use heim_common::prelude::*;
+use heim_process as process;
+use heim_process::os::unix::ProcessExt;
+
+#[heim_derive::test]
+async fn test_open_files() {
+    let path = std::env::temp_dir().join(format!("heim-open-files-{}", std::process::id()));
+    let file = File::create(&path).unwrap();
+    // Temporary directory might be a symlink (ex. `/tmp` at macOS)
+    let path = path.canonicalize().unwrap();
+
+    let current = process::current().await.unwrap();
+    let num_fds = current.num_fds().await.unwrap();
+    let files = current.open_files().await.unwrap();
+    let files = files.try_collect::<Vec<_>>().await.unwrap();
+
+    let fd = file.as_raw_fd();
+    drop(file);
+    fs::remove_file(&path).unwrap();
+
+    assert!(num_fds >= files.len() as u64);
+    assert!(
+        files
+            .iter()
+            .any(|file| file.fd() == fd && file.path() == path),
+        "Created file {:?} is missing in the open files list: {:?}",
+        path,
+        files
+    );
+}
+
+#[heim_derive::test]
+async fn test_open_files_mode() {
+    use std::fs::OpenOptions;
+    use std::io::Write;
+
+    use heim_process::os::unix::FileMode;
+
+    let path = std::env::temp_dir().join(format!("heim-open-files-mode-{}", std::process::id()));
+    let mut written = File::create(&path).unwrap();
+    written.write_all(b"heim").unwrap();
+    let appended = OpenOptions::new().append(true).open(&path).unwrap();
+    let read = File::open(&path).unwrap();
+
+    let current = process::current().await.unwrap();
+    let files = current.open_files().await.unwrap();
+    let files = files.try_collect::<Vec<_>>().await.unwrap();
+    let find = |fd| files.iter().find(|file| file.fd() == fd).cloned();
+    let (written_file, appended_file, read_file) = (
+        find(written.as_raw_fd()),
+        find(appended.as_raw_fd()),
+        find(read.as_raw_fd()),
+    );
+
+    drop((written, appended, read));
+    fs::remove_file(&path).unwrap();
+
+    let written_file = written_file.expect("Written file is missing");
+    assert_eq!(Some(FileMode::Write), written_file.mode());
+    assert_eq!(Some(4), written_file.position());
+    assert_eq!(
+        Some(FileMode::Append),
+        appended_file.expect("Appended file is missing").mode()
+    );
+    let read_file = read_file.expect("Read file is missing");
+    assert_eq!(Some(FileMode::Read), read_file.mode());
+    assert_eq!(Some(0), read_file.position());
+}
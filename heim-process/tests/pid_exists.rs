@@ -0,0 +1,38 @@
+use heim_process as process;
+use heim_process::ProcessResult;
+
+#[heim_derive::test]
+async fn test_pid_exists_current() -> ProcessResult<()> {
+    let current = process::current().await?;
+
+    assert!(process::pid_exists(current.pid()).await?);
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn test_pid_exists_missing() -> ProcessResult<()> {
+    // `RawPid::MAX` exceeds the maximum pid system can assign
+    // (ex. `pid_max` for Linux), so there is no such process
+    assert!(!process::pid_exists(process::Pid::new(process::RawPid::MAX)).await?);
+
+    Ok(())
+}
+
+#[heim_derive::test]
+#[cfg(unix)]
+async fn test_pid_exists_negative() -> ProcessResult<()> {
+    assert!(!process::pid_exists(process::Pid::new(-1)).await?);
+
+    Ok(())
+}
+
+// `init` process can't be signaled by the unprivileged users,
+// `EPERM` error still means that process exists
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn test_pid_exists_not_owned() -> ProcessResult<()> {
+    assert!(process::pid_exists(process::Pid::INIT).await?);
+
+    Ok(())
+}
@@ -1,10 +1,15 @@
 use std::error;
+use std::hash::Hash;
 
 use static_assertions::assert_impl_all;
 
-use heim_process::ProcessError;
+use heim_process::{Process, ProcessError, ProcessGuard, ProcessInfo, ProcessOneShot};
 
 #[test]
 fn test_public_api_contract() {
     assert_impl_all!(ProcessError: Send, Sync, error::Error);
+    assert_impl_all!(Process: Send, Sync, Eq, Hash);
+    assert_impl_all!(ProcessInfo: Send, Sync);
+    assert_impl_all!(ProcessOneShot: Send, Sync);
+    assert_impl_all!(ProcessGuard: Send, Sync);
 }
@@ -0,0 +1,64 @@
+#![cfg(target_os = "linux")]
+
+use std::process::{Command, Stdio};
+
+use heim_process as process;
+use heim_process::os::linux::{ProcessExt, Resource};
+use heim_process::ProcessError;
+
+#[heim_derive::test]
+async fn test_rlimit() {
+    let current = process::current().await.unwrap();
+
+    for resource in &[
+        Resource::AddressSpace,
+        Resource::Cpu,
+        Resource::NoFile,
+        Resource::NProc,
+        Resource::Stack,
+    ] {
+        let (soft, hard) = current.rlimit(*resource).await.unwrap();
+        assert!(soft <= hard, "{:?}: {} > {}", resource, soft, hard);
+    }
+}
+
+#[heim_derive::test]
+async fn test_set_rlimit() {
+    let yes_path = match which::which("yes") {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!(
+                "Unable to find `yes` command, rlimit test will be skipped: {:?}",
+                e
+            );
+            return;
+        }
+    };
+
+    let mut child = Command::new(yes_path)
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+
+    let (_, hard) = process.rlimit(Resource::Core).await.unwrap();
+    process.set_rlimit(Resource::Core, (0, hard)).await.unwrap();
+    assert_eq!((0, hard), process.rlimit(Resource::Core).await.unwrap());
+
+    // Raising the hard limit requires privileges
+    match process.set_rlimit(Resource::Core, (0, u64::MAX)).await {
+        Ok(()) => assert_eq!((0, u64::MAX), process.rlimit(Resource::Core).await.unwrap()),
+        Err(ProcessError::AccessDenied(pid)) => assert_eq!(pid, process.pid()),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+
+    match process.rlimit(Resource::Core).await {
+        Err(ProcessError::NoSuchProcess(pid)) => assert_eq!(pid, process.pid()),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(target_os = "linux")]
+
+use std::process::{Command, Stdio};
+
+use heim_process as process;
+use heim_process::os::linux::{ProcessExt, SchedPolicy};
+use heim_process::ProcessError;
+
+#[heim_derive::test]
+async fn test_sched_policy() {
+    let current = process::current().await.unwrap();
+    let (policy, priority) = current.sched_policy().await.unwrap();
+
+    if policy.is_realtime() {
+        assert!((1..=99).contains(&priority));
+    } else {
+        assert_eq!(0, priority);
+    }
+}
+
+#[heim_derive::test]
+async fn test_set_sched_policy() {
+    let yes_path = match which::which("yes") {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!(
+                "Unable to find `yes` command, scheduling policy test will be skipped: {:?}",
+                e
+            );
+            return;
+        }
+    };
+
+    let mut child = Command::new(yes_path)
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+
+    process
+        .set_sched_policy(SchedPolicy::Batch, 0)
+        .await
+        .unwrap();
+    assert_eq!(
+        (SchedPolicy::Batch, 0),
+        process.sched_policy().await.unwrap()
+    );
+
+    // Real-time policies require privileges
+    match process.set_sched_policy(SchedPolicy::Fifo, 10).await {
+        Ok(()) => assert_eq!(
+            (SchedPolicy::Fifo, 10),
+            process.sched_policy().await.unwrap()
+        ),
+        Err(ProcessError::AccessDenied(pid)) => assert_eq!(pid, process.pid()),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+
+    assert!(process
+        .set_sched_policy(SchedPolicy::Deadline, 0)
+        .await
+        .is_err());
+
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+
+    match process.sched_policy().await {
+        Err(ProcessError::NoSuchProcess(pid)) => assert_eq!(pid, process.pid()),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
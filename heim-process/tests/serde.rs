@@ -0,0 +1,100 @@
+#![cfg(feature = "serde")]
+
+use heim_common::prelude::*;
+use heim_common::units::{information, time};
+use heim_process as process;
+use heim_process::ProcessResult;
+
+#[heim_derive::test]
+async fn serde_cpu_time() -> ProcessResult<()> {
+    let cpu_time = process::current().await?.cpu_time().await?;
+
+    let value = serde_json::to_value(&cpu_time).unwrap();
+
+    assert_eq!(
+        value["user"].as_f64(),
+        Some(cpu_time.user().get::<time::second>())
+    );
+    assert_eq!(
+        value["system"].as_f64(),
+        Some(cpu_time.system().get::<time::second>())
+    );
+    assert_eq!(
+        value["children_user"].as_f64(),
+        cpu_time.children_user().map(|t| t.get::<time::second>())
+    );
+    assert_eq!(
+        value["children_system"].as_f64(),
+        cpu_time.children_system().map(|t| t.get::<time::second>())
+    );
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn serde_memory() -> ProcessResult<()> {
+    let memory = process::current().await?.memory().await?;
+
+    let value = serde_json::to_value(&memory).unwrap();
+
+    assert_eq!(
+        value["rss"].as_u64(),
+        Some(memory.rss().get::<information::byte>())
+    );
+    assert_eq!(
+        value["vms"].as_u64(),
+        Some(memory.vms().get::<information::byte>())
+    );
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn serde_status() -> ProcessResult<()> {
+    let status = process::current().await?.status().await?;
+
+    let json = serde_json::to_string(&status).unwrap();
+    let restored: process::Status = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(status, restored);
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn serde_ctx_switches() -> ProcessResult<()> {
+    let switches = process::current().await?.num_ctx_switches().await?;
+
+    let value = serde_json::to_value(switches).unwrap();
+
+    assert_eq!(value["voluntary"].as_u64(), Some(switches.voluntary()));
+    assert_eq!(value["involuntary"].as_u64(), Some(switches.involuntary()));
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn serde_process_info() -> ProcessResult<()> {
+    let processes = process::processes_with_info().await?;
+    ::futures::pin_mut!(processes);
+
+    if let Some((_, info)) = processes.try_next().await? {
+        let value = serde_json::to_value(&info).unwrap();
+
+        assert_eq!(
+            value["parent_pid"].as_i64(),
+            Some(i64::from(process::RawPid::from(info.parent_pid())))
+        );
+        assert_eq!(value["name"].as_str(), Some(info.name()));
+        assert_eq!(
+            serde_json::from_value::<process::Status>(value["status"].clone()).unwrap(),
+            info.status()
+        );
+        assert_eq!(
+            value["create_time"].as_f64(),
+            Some(info.create_time().get::<time::second>())
+        );
+    }
+
+    Ok(())
+}
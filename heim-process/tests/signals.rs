@@ -1,11 +1,15 @@
 #![cfg(unix)]
 
-use std::process::{Command, Stdio};
+use std::os::unix::process::ExitStatusExt;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
 
 use heim_process as process;
+use heim_process::os::unix::{ExitStatusExt as _, ProcessExt, Signal};
+use heim_process::{Process, ProcessResult, Status};
 
-#[heim_derive::test]
-async fn test_kill() {
+/// Spawns the long-running `yes` command, if it is available.
+fn spawn_yes() -> Option<Child> {
     let yes_path = match which::which("yes") {
         Ok(path) => path,
         Err(e) => {
@@ -13,16 +17,45 @@ async fn test_kill() {
                 "Unable to find `yes` command, signals test will be skipped: {:?}",
                 e
             );
-            return;
+            return None;
         }
     };
 
-    let mut child = Command::new(yes_path)
+    let child = Command::new(yes_path)
         .stdout(Stdio::null())
         .spawn()
         .unwrap();
 
-    let process = process::get(child.id() as process::Pid).await.unwrap();
+    Some(child)
+}
+
+/// Waits for a while until process status will be changed to the `expected` one.
+///
+/// Signals are delivered asynchronously, so the status might not be updated
+/// right after the `kill(2)` call.
+async fn wait_for_status(process: &Process, expected: Status) -> ProcessResult<Status> {
+    let mut status = process.status().await?;
+    for _ in 0..50 {
+        if status == expected {
+            break;
+        }
+        futures_timer::Delay::new(Duration::from_millis(10)).await;
+        status = process.status().await?;
+    }
+
+    Ok(status)
+}
+
+#[heim_derive::test]
+async fn test_kill() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
     let result = process.kill().await;
     assert!(result.is_ok(), "Failed to kill the process: {:?}", result);
 
@@ -37,3 +70,308 @@ async fn test_kill() {
         Err(e) => panic!("Process::signal failed to kill the test process: {:#?}", e),
     }
 }
+
+#[heim_derive::test]
+async fn test_terminate() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    let result = process.terminate().await;
+    assert!(
+        result.is_ok(),
+        "Failed to terminate the process: {:?}",
+        result
+    );
+
+    let status = child.wait().unwrap();
+    assert!(!status.success());
+}
+
+#[heim_derive::test]
+async fn test_signal() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    let result = process.signal(Signal::Int).await;
+    assert!(result.is_ok(), "Failed to signal the process: {:?}", result);
+
+    let status = child.wait().unwrap();
+    assert!(!status.success());
+}
+
+#[heim_derive::test]
+async fn test_kill_reaped() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+
+    // Process is gone at this point, the handle should not be able to signal anything
+    match process.kill().await {
+        Err(process::ProcessError::NoSuchProcess(pid)) => assert_eq!(pid, process.pid()),
+        other => panic!("Unexpected result for a reaped process: {:?}", other),
+    }
+}
+
+#[heim_derive::test]
+async fn test_kill_with_timeout() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    let result = process.kill_with_timeout(Duration::from_secs(5)).await;
+    assert!(
+        result.is_ok(),
+        "Failed to terminate the process: {:?}",
+        result
+    );
+
+    let status = child.wait().unwrap();
+    assert_eq!(Some(libc::SIGTERM), status.signal());
+}
+
+#[heim_derive::test]
+async fn test_kill_with_timeout_forced() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    // Stopped process will not handle `SIGTERM` until resumed
+    process.suspend().await.unwrap();
+    let status = wait_for_status(&process, Status::Stopped).await.unwrap();
+    assert_eq!(Status::Stopped, status);
+
+    let result = process.kill_with_timeout(Duration::from_millis(100)).await;
+    assert!(result.is_ok(), "Failed to kill the process: {:?}", result);
+
+    let status = child.wait().unwrap();
+    assert_eq!(Some(libc::SIGKILL), status.signal());
+}
+
+#[heim_derive::test]
+async fn test_kill_with_timeout_reaped() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+
+    assert!(process
+        .kill_with_timeout(Duration::from_millis(100))
+        .await
+        .is_ok());
+}
+
+#[heim_derive::test]
+async fn test_is_running() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    assert!(process.is_running().await.unwrap());
+
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+
+    assert!(!process.is_running().await.unwrap());
+}
+
+#[heim_derive::test]
+async fn test_wait_exit_code() {
+    let mut child = Command::new("sh").arg("-c").arg("exit 3").spawn().unwrap();
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+
+    let status = process.wait().await.unwrap().unwrap();
+    assert_eq!(Some(3), status.code());
+    assert!(!status.success());
+    assert_eq!(None, status.signal());
+    assert!(!status.core_dumped());
+
+    // Child was reaped by `Process::wait` already
+    assert!(child.wait().is_err());
+}
+
+#[heim_derive::test]
+async fn test_wait() {
+    let child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    process.kill().await.unwrap();
+
+    let status = process.wait().await.unwrap();
+    match status {
+        Some(status) => {
+            assert_eq!(Some(Signal::Kill), status.signal());
+            assert!(!status.core_dumped());
+            assert_eq!(None, status.code());
+            assert!(!status.success());
+        }
+        None => panic!("Exit status is not available for the child process"),
+    }
+    assert!(!process.is_running().await.unwrap());
+}
+
+#[heim_derive::test]
+async fn test_wait_reaped() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+
+    assert!(process.wait().await.unwrap().is_none());
+}
+
+#[heim_derive::test]
+async fn test_suspend_resume() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+
+    let result = process.suspend().await;
+    assert!(
+        result.is_ok(),
+        "Failed to suspend the process: {:?}",
+        result
+    );
+    let status = wait_for_status(&process, Status::Stopped).await.unwrap();
+    assert_eq!(Status::Stopped, status);
+
+    let result = process.resume().await;
+    assert!(result.is_ok(), "Failed to resume the process: {:?}", result);
+    let status = wait_for_status(&process, Status::Running).await.unwrap();
+    assert_ne!(Status::Stopped, status);
+
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+}
+
+#[heim_derive::test]
+async fn test_validate() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    assert!(process.validate().await.is_ok());
+
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+
+    match process.validate().await {
+        Err(process::ProcessError::NoSuchProcess(pid)) => assert_eq!(pid, process.pid()),
+        other => panic!("Unexpected result for a reaped process: {:?}", other),
+    }
+    match process.set_niceness(10).await {
+        Err(process::ProcessError::NoSuchProcess(..)) => {}
+        other => panic!("Reaped process niceness was changed: {:?}", other),
+    }
+}
+
+#[heim_derive::test]
+async fn test_guard_terminate() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    let guard = process::ProcessGuard::new(process);
+    drop(guard);
+
+    let status = child.wait().unwrap();
+    assert_eq!(Some(libc::SIGTERM), status.signal());
+}
+
+#[heim_derive::test]
+async fn test_guard_kill_on_panic() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    let result = std::panic::catch_unwind(move || {
+        let _guard = process::ProcessGuard::with_action(process, process::GuardAction::Kill);
+        panic!("Guard should kill the process on unwinding");
+    });
+    assert!(result.is_err());
+
+    let status = child.wait().unwrap();
+    assert_eq!(Some(libc::SIGKILL), status.signal());
+}
+
+#[heim_derive::test]
+async fn test_guard_into_inner() {
+    let mut child = match spawn_yes() {
+        Some(child) => child,
+        None => return,
+    };
+
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+    let guard = process::ProcessGuard::new(process);
+    let process = guard.into_inner();
+    assert!(process.is_running().await.unwrap());
+
+    child.kill().unwrap();
+    let _ = child.wait().unwrap();
+}
@@ -0,0 +1,49 @@
+use heim_common::prelude::*;
+use heim_process::{Pid, ProcessError, ProcessStreamExt};
+
+#[heim_derive::test]
+async fn test_skip_transient() {
+    let items = vec![
+        Ok(1),
+        Err(ProcessError::NoSuchProcess(Pid::new(2))),
+        Err(ProcessError::AccessDenied(Pid::new(3))),
+        Err(ProcessError::ZombieProcess(Pid::new(4))),
+        Ok(5),
+    ];
+
+    let results = stream::iter(items)
+        .skip_transient()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert_eq!(vec![1, 5], results);
+}
+
+#[heim_derive::test]
+async fn test_skip_transient_propagates_load_errors() {
+    let error = Error::from(std::io::Error::from(std::io::ErrorKind::InvalidData));
+    let items = vec![Ok(1), Err(ProcessError::Load(error)), Ok(3)];
+
+    let results = stream::iter(items)
+        .skip_transient()
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(3, results.len());
+    assert!(matches!(results[1], Err(ProcessError::Load(..))));
+}
+
+#[heim_derive::test]
+async fn test_skip_transient_processes() {
+    let current = heim_process::current().await.unwrap();
+    let processes = heim_process::processes()
+        .await
+        .unwrap()
+        .skip_transient()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert!(processes.contains(&current));
+}
@@ -1,10 +1,12 @@
+use std::time::Duration;
+
 use heim_common::prelude::*;
 use heim_process as process;
-use heim_process::ProcessError;
+use heim_process::{ProcessError, ProcessResult};
 
 #[heim_derive::test]
 async fn smoke_pid_exists() {
-    let result = process::pid_exists(1).await;
+    let result = process::pid_exists(process::Pid::new(1)).await;
 
     assert!(result.is_ok());
 }
@@ -54,32 +56,65 @@ async fn smoke_processes() -> Result<()> {
 
         let _ = process.pid();
         try_method!(process.parent_pid());
+        try_method!(process.parent());
         try_method!(process.name());
         try_method!(process.command());
+        try_method!(process.cmdline());
         try_method!(process.exe());
         try_method!(process.cwd());
         try_method!(process.status());
+        try_method!(process.is_zombie());
         #[cfg(any(target_os = "linux", target_os = "macos"))] // Not implemented yet for all platforms
         try_method!(process.environment());
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        try_method!(process.environ());
         try_method!(process.create_time());
         try_method!(process.cpu_time());
         try_method!(process.cpu_usage());
+        try_method!(process.cpu_percent(None));
         try_method!(process.memory());
+        try_method!(process.memory_percent());
+        try_method!(process.threads());
+        try_method!(process.num_threads());
+        try_method!(process.status_and_threads());
+        try_method!(process.num_ctx_switches());
         try_method!(process.is_running());
         try_method!(process.io_counters());
+        try_method!(process.cpu_affinity());
+        try_method!(process.terminal());
+        try_method!(process.oneshot(process::ProcessFields::all()));
 
         #[cfg(unix)]
         {
-            use heim_process::os::unix::ProcessExt;
+            use heim_process::os::unix::{ConnectionKind, ProcessExt};
 
             try_method!(process.niceness());
+            try_method!(process.uids());
+            try_method!(process.gids());
+            try_method!(process.groups());
+            try_method!(process.username());
+            try_method!(process.num_fds());
+            try_method!(process.open_files());
+            try_method!(process.connections(ConnectionKind::All));
         }
 
         #[cfg(target_os = "linux")]
         {
-            use heim_process::os::linux::ProcessExt;
+            use heim_process::os::linux::{ProcessExt, Resource};
 
             try_method!(process.net_io_counters());
+            try_method!(process.rlimit(Resource::NoFile));
+            try_method!(process.sched_policy());
+            try_method!(process.memory_maps());
+            try_method!(process.exe_deleted());
+            try_method!(process.cgroup_limits());
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            use heim_process::os::macos::ProcessExt;
+
+            try_method!(process.snapshot());
         }
 
         #[cfg(target_os = "windows")]
@@ -92,3 +127,87 @@ async fn smoke_processes() -> Result<()> {
 
     Ok(())
 }
+
+#[heim_derive::test]
+async fn smoke_processes_with_info() -> ProcessResult<()> {
+    let current = process::current().await?;
+    let processes = process::processes_with_info().await?;
+    ::futures::pin_mut!(processes);
+
+    let mut found = false;
+    while let Some(result) = processes.next().await {
+        let (process, info) = match result {
+            Ok(pair) => pair,
+            e @ Err(ProcessError::Load(..)) => panic!("{:#?}", e),
+            _ => continue,
+        };
+
+        assert_ne!(process::Status::Zombie, info.status());
+        if process == current {
+            assert_eq!(current.parent_pid().await?, info.parent_pid());
+            assert_eq!(current.name().await?, info.name());
+            assert_eq!(current.create_time().await?, info.create_time());
+            found = true;
+        }
+    }
+    assert!(found, "Current process is missing in the processes list");
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_cpu_percent() -> ProcessResult<()> {
+    let process = process::current().await?;
+
+    // Nothing to compare with yet
+    assert_eq!(0.0, process.cpu_percent(None).await?);
+
+    let usage = process.cpu_percent(None).await?;
+    assert!(usage >= 0.0);
+    let usage = process.cpu_percent(Some(Duration::from_millis(10))).await?;
+    assert!(usage >= 0.0);
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_memory_percent() -> ProcessResult<()> {
+    let process = process::current().await?;
+    let percent = process.memory_percent().await?;
+
+    assert!(percent > 0.0);
+    assert!(percent <= 100.0);
+
+    Ok(())
+}
+
+#[heim_derive::test]
+#[cfg(not(target_os = "macos"))] // Operations count is not available for macOS
+async fn smoke_io_counters() -> ProcessResult<()> {
+    use std::io::Write;
+
+    let process = process::current().await?;
+    let before = process.io_counters().await?;
+
+    let path = std::env::temp_dir().join(format!("heim-io-counters-{}", std::process::id()));
+    let mut file = std::fs::File::create(&path).map_err(Error::from)?;
+    file.write_all(b"heim").map_err(Error::from)?;
+    drop(file);
+    std::fs::remove_file(&path).map_err(Error::from)?;
+
+    let after = process.io_counters().await?;
+    assert!(after.write_count() > before.write_count());
+    assert!(after.read_count() >= before.read_count());
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_num_ctx_switches() -> ProcessResult<()> {
+    let process = process::current().await?;
+    let switches = process.num_ctx_switches().await?;
+
+    assert!(switches.voluntary() + switches.involuntary() > 0);
+
+    Ok(())
+}
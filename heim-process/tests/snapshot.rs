@@ -0,0 +1,17 @@
+#![cfg(target_os = "macos")]
+
+use heim_process as process;
+use heim_process::os::macos::ProcessExt;
+use heim_process::os::unix::ProcessExt as _;
+
+#[heim_derive::test]
+async fn test_snapshot() {
+    let current = process::current().await.unwrap();
+    let snapshot = current.snapshot().await.unwrap();
+
+    assert_eq!(current.pid(), snapshot.pid());
+    assert_eq!(current.parent_pid().await.unwrap(), snapshot.parent_pid());
+    assert_eq!(current.name().await.unwrap(), snapshot.name());
+    assert_eq!(current.uids().await.unwrap(), snapshot.uids());
+    assert_eq!(current.create_time().await.unwrap(), snapshot.create_time());
+}
@@ -0,0 +1,14 @@
+#![cfg(unix)]
+
+use heim_process as process;
+
+#[heim_derive::test]
+async fn test_terminal() {
+    let current = process::current().await.unwrap();
+
+    // Tests might be started without controlling terminal, as on CI
+    if let Some(path) = current.terminal().await.unwrap() {
+        assert!(path.starts_with("/dev"), "{:?}", path);
+        assert!(path.exists(), "{:?}", path);
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::mpsc;
+use std::thread;
+
+use heim_common::prelude::*;
+use heim_common::units::time;
+use heim_process as process;
+
+#[heim_derive::test]
+async fn test_threads() {
+    let (started_tx, started_rx) = mpsc::channel();
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let handle = thread::spawn(move || {
+        started_tx.send(()).unwrap();
+        let _ = stop_rx.recv();
+    });
+    started_rx.recv().unwrap();
+
+    let current = process::current().await.unwrap();
+    let threads = current.threads().await.unwrap();
+    let threads = threads.try_collect::<Vec<_>>().await.unwrap();
+
+    stop_tx.send(()).unwrap();
+    handle.join().unwrap();
+
+    assert!(
+        threads.len() >= 2,
+        "Spawned thread is missing in the threads list: {:?}",
+        threads
+    );
+    for thread in &threads {
+        assert!(thread.cpu_time().user().get::<time::second>() >= 0.0);
+        assert!(thread.cpu_time().system().get::<time::second>() >= 0.0);
+    }
+
+    // Main thread ID is equal to the process ID
+    #[cfg(target_os = "linux")]
+    assert!(threads
+        .iter()
+        .any(|thread| thread.id() == u64::from(*current.pid() as u32)));
+}
+
+#[heim_derive::test]
+async fn test_num_threads() {
+    let (started_tx, started_rx) = mpsc::channel();
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let handle = thread::spawn(move || {
+        started_tx.send(()).unwrap();
+        let _ = stop_rx.recv();
+    });
+    started_rx.recv().unwrap();
+
+    let current = process::current().await.unwrap();
+    let num_threads = current.num_threads().await.unwrap();
+
+    stop_tx.send(()).unwrap();
+    handle.join().unwrap();
+
+    assert!(num_threads >= 2, "Spawned thread is not counted");
+}
+
+#[heim_derive::test]
+async fn test_threads_cpu_time_sum() {
+    let current = process::current().await.unwrap();
+    let threads = current.threads().await.unwrap();
+    let threads = threads.try_collect::<Vec<_>>().await.unwrap();
+    let cpu_time = current.cpu_time().await.unwrap();
+
+    // Exited threads are not listed anymore, but their CPU time
+    // is still accounted for the process, so the sum can't exceed the process time
+    // (with some slack for the ticks rounding and threads running meanwhile)
+    let user = threads
+        .iter()
+        .map(|thread| thread.cpu_time().user().get::<time::second>())
+        .sum::<f64>();
+    assert!(user <= cpu_time.user().get::<time::second>() + 0.1);
+}
+
+#[heim_derive::test]
+async fn test_status_and_threads() {
+    let current = process::current().await.unwrap();
+    let (status, threads) = current.status_and_threads().await.unwrap();
+
+    assert_ne!(process::Status::Zombie, status);
+    assert!(threads >= 1);
+}
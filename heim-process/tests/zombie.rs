@@ -0,0 +1,46 @@
+#![cfg(unix)]
+
+use std::process::Command;
+use std::time::Duration;
+
+use heim_process as process;
+use heim_process::ProcessError;
+use heim_runtime as rt;
+
+#[heim_derive::test]
+async fn test_is_zombie() {
+    let true_path = match which::which("true") {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!(
+                "Unable to find `true` command, zombie test will be skipped: {:?}",
+                e
+            );
+            return;
+        }
+    };
+
+    let mut child = Command::new(true_path).spawn().unwrap();
+    let process = process::get(process::Pid::from(child.id() as process::RawPid))
+        .await
+        .unwrap();
+
+    // Child is not reaped until the `wait` call below
+    let mut is_zombie = false;
+    for _ in 0..100 {
+        is_zombie = process.is_zombie().await.unwrap();
+        if is_zombie {
+            break;
+        }
+        rt::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(is_zombie);
+
+    let _ = child.wait().unwrap();
+
+    match process.is_zombie().await {
+        Ok(false) => {}
+        Err(ProcessError::NoSuchProcess(pid)) => assert_eq!(pid, process.pid()),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
@@ -0,0 +1,103 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+pub use futures_timer::Delay;
+
+use crate::time::TimeoutError;
+
+/// Handle to the `async-std` task, which is detached on drop.
+pub struct JoinHandle<R>(async_std::task::JoinHandle<R>);
+
+impl<R> JoinHandle<R> {
+    pub fn detach(self) {}
+}
+
+impl<R> Future for JoinHandle<R> {
+    type Output = R;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+pub fn spawn<F, R>(f: F) -> JoinHandle<R>
+where
+    F: Future<Output = R> + Send + 'static,
+    R: Send + 'static,
+{
+    JoinHandle(async_std::task::spawn(f))
+}
+
+pub async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    async_std::task::spawn_blocking(f).await
+}
+
+pub async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await
+}
+
+pub async fn timeout<F>(duration: Duration, future: F) -> Result<F::Output, TimeoutError>
+where
+    F: Future,
+{
+    async_std::future::timeout(duration, future)
+        .await
+        .map_err(|_| TimeoutError::new())
+}
+
+/// File system operations, executed on the `async-std` blocking threads pool.
+pub mod fs {
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    use futures::stream;
+
+    pub use std::fs::{DirEntry, Metadata};
+
+    /// Stream over the directory entries.
+    pub type ReadDir = stream::Iter<std::vec::IntoIter<io::Result<DirEntry>>>;
+
+    use super::spawn_blocking;
+
+    pub async fn canonicalize<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
+        let path = path.as_ref().to_owned();
+        spawn_blocking(move || fs::canonicalize(path)).await
+    }
+
+    pub async fn metadata<P: AsRef<Path>>(path: P) -> io::Result<Metadata> {
+        let path = path.as_ref().to_owned();
+        spawn_blocking(move || fs::metadata(path)).await
+    }
+
+    pub async fn read<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+        let path = path.as_ref().to_owned();
+        spawn_blocking(move || fs::read(path)).await
+    }
+
+    /// Reads the whole directory at once, yielding its entries afterwards.
+    pub async fn read_dir<P: AsRef<Path>>(path: P) -> io::Result<ReadDir> {
+        let path = path.as_ref().to_owned();
+        let entries =
+            spawn_blocking(move || fs::read_dir(path).map(|entries| entries.collect::<Vec<_>>()))
+                .await?;
+
+        Ok(stream::iter(entries))
+    }
+
+    pub async fn read_link<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
+        let path = path.as_ref().to_owned();
+        spawn_blocking(move || fs::read_link(path)).await
+    }
+
+    pub async fn read_to_string<P: AsRef<Path>>(path: P) -> io::Result<String> {
+        let path = path.as_ref().to_owned();
+        spawn_blocking(move || fs::read_to_string(path)).await
+    }
+}
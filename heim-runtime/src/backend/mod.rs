@@ -0,0 +1,39 @@
+//! Async runtime backends.
+//!
+//! `smol` backend is used by default, other ones can be selected
+//! with the `runtime-*` crate features; only one of them can be enabled at once.
+//!
+//! Each backend provides the same set of items:
+//!
+//!  * `spawn` and `spawn_blocking` functions
+//!  * `sleep` and `timeout` functions
+//!  * `Delay` future, which can be reset
+//!  * `fs` module with the file system operations, running on the backend blocking threads pool
+
+#[cfg(any(
+    all(feature = "runtime-smol", feature = "runtime-tokio"),
+    all(feature = "runtime-smol", feature = "runtime-async-std"),
+    all(feature = "runtime-tokio", feature = "runtime-async-std"),
+))]
+compile_error!(
+    "`runtime-smol`, `runtime-tokio` and `runtime-async-std` features of `heim-runtime` \
+     are mutually exclusive, only one runtime backend can be enabled at once"
+);
+
+#[cfg(feature = "runtime-tokio")]
+mod tokio;
+
+#[cfg(feature = "runtime-tokio")]
+pub use self::tokio::*;
+
+#[cfg(all(feature = "runtime-async-std", not(feature = "runtime-tokio")))]
+mod async_std;
+
+#[cfg(all(feature = "runtime-async-std", not(feature = "runtime-tokio")))]
+pub use self::async_std::*;
+
+#[cfg(not(any(feature = "runtime-tokio", feature = "runtime-async-std")))]
+mod smol;
+
+#[cfg(not(any(feature = "runtime-tokio", feature = "runtime-async-std")))]
+pub use self::smol::*;
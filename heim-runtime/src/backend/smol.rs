@@ -0,0 +1,81 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+pub use futures_timer::Delay;
+
+use crate::time::TimeoutError;
+
+/// Handle to the `smol` task.
+///
+/// Dropped `smol::Task` cancels the task, so it is detached on drop instead,
+/// same as the other backends do.
+pub struct JoinHandle<R>(Option<smol::Task<R>>);
+
+impl<R> JoinHandle<R> {
+    pub fn detach(mut self) {
+        if let Some(task) = self.0.take() {
+            task.detach();
+        }
+    }
+}
+
+impl<R> Drop for JoinHandle<R> {
+    fn drop(&mut self) {
+        if let Some(task) = self.0.take() {
+            task.detach();
+        }
+    }
+}
+
+impl<R> Future for JoinHandle<R> {
+    type Output = R;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.0.as_mut() {
+            Some(task) => Pin::new(task).poll(cx),
+            None => unreachable!("Detached task can't be polled"),
+        }
+    }
+}
+
+pub fn spawn<F, R>(f: F) -> JoinHandle<R>
+where
+    F: Future<Output = R> + Send + 'static,
+    R: Send + 'static,
+{
+    JoinHandle(Some(smol::spawn(f)))
+}
+
+pub async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    smol::unblock(f).await
+}
+
+pub async fn sleep(duration: Duration) {
+    Delay::new(duration).await
+}
+
+pub async fn timeout<F>(duration: Duration, future: F) -> Result<F::Output, TimeoutError>
+where
+    F: Future,
+{
+    let future = async { Ok(future.await) };
+    let delay = async {
+        Delay::new(duration).await;
+        Err(TimeoutError::new())
+    };
+
+    smol::future::or(future, delay).await
+}
+
+/// File system operations, executed on the `smol` blocking threads pool.
+pub mod fs {
+    pub use smol::fs::{
+        canonicalize, metadata, read, read_dir, read_link, read_to_string, DirEntry,
+    };
+}
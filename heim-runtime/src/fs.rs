@@ -2,18 +2,21 @@ use std::io;
 use std::path::Path;
 use std::str::FromStr;
 
-use futures::{io::BufReader, AsyncBufReadExt, Stream, StreamExt, TryStreamExt};
-use smol::unblock;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+
+use crate::backend::spawn_blocking;
 
 // Public re-exports
-pub use smol::fs::{read, read_dir, read_link, read_to_string, File};
+pub use crate::backend::fs::{
+    canonicalize, metadata, read, read_dir, read_link, read_to_string, DirEntry,
+};
 
 pub async fn path_exists<T>(path: T) -> bool
 where
     T: AsRef<Path> + Send + 'static,
 {
     let path = path.as_ref().to_owned();
-    unblock(move || path.exists()).await
+    spawn_blocking(move || path.exists()).await
 }
 
 pub async fn read_into<T, R, E>(path: T) -> Result<R, E>
@@ -31,9 +34,13 @@ pub async fn read_lines<T>(path: T) -> io::Result<impl Stream<Item = io::Result<
 where
     T: AsRef<Path> + Send + 'static,
 {
-    let file = File::open(path).await?;
-    let reader = BufReader::new(file);
-    Ok(reader.lines())
+    let contents = read_to_string(path).await?;
+    let lines = contents
+        .lines()
+        .map(|line| Ok(line.to_string()))
+        .collect::<Vec<_>>();
+
+    Ok(stream::iter(lines))
 }
 
 pub async fn read_lines_into<T, R, E>(path: T) -> io::Result<impl Stream<Item = Result<R, E>>>
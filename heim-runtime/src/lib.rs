@@ -21,28 +21,43 @@
     unused_results
 )]
 
-use std::future::Future;
+//! Runtime shims for the `heim` crates.
+//!
+//! All async operations are executed by the [smol](https://crates.io/crates/smol) runtime by default.
+//! Enable the `runtime-tokio` or `runtime-async-std` feature in order to route file system access,
+//! other blocking operations and timers into the [tokio](https://crates.io/crates/tokio)
+//! or [async-std](https://crates.io/crates/async-std) runtime instead;
+//! with `runtime-tokio` feature `heim` functions should be called from the Tokio runtime context.
+//!
+//! Runtime features are mutually exclusive and enabling more than one of them
+//! results in a compilation error.
 
 pub use futures::pin_mut as pin;
 
+/// Polls multiple futures simultaneously, returning a tuple of all results once complete.
+///
+/// Must be used inside of the async functions or blocks.
+pub use futures::join;
+
+/// Polls multiple fallible futures simultaneously, returning a tuple of all results once complete
+/// or the first error encountered.
+///
+/// Must be used inside of the async functions or blocks.
+pub use futures::try_join;
+
+mod backend;
 pub mod fs;
 #[cfg(target_os = "linux")]
 pub mod linux;
+mod task;
 pub mod time;
 
-#[inline]
-pub async fn spawn<F, R>(f: F) -> R
-where
-    F: Future<Output = R> + Send + 'static,
-    R: Send + 'static,
-{
-    smol::spawn(f).await
-}
-
-pub async fn spawn_blocking<F, R>(f: F) -> R
-where
-    F: FnOnce() -> R + Send + 'static,
-    R: Send + 'static,
-{
-    smol::unblock(f).await
-}
+pub use self::time::{sleep, timeout, TimeoutError};
+
+pub use self::task::{spawn, JoinHandle};
+
+/// Runs the blocking function `f` on a thread pool dedicated for blocking operations
+/// and waits for its output.
+///
+/// Panics in `f` are propagated to the caller.
+pub use self::backend::spawn_blocking;
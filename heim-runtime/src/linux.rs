@@ -1,9 +1,16 @@
 #![cfg(target_os = "linux")]
 
 //! Linux-specific extension for configuring custom paths for Procfs
-/// and Sysfs.
+//! and Sysfs.
+//!
+//! Custom paths can be set either programmatically or with the `HEIM_PROCFS_ROOT`
+//! and `HEIM_SYSFS_ROOT` environment variables, ex. when `heim` is running in a container
+//! and the host `/proc` is mounted at `/host/proc`.
+//! Programmatically set paths take precedence, but only if they were set
+//! before any `heim` function was called.
 use once_cell::sync::OnceCell;
 use std::borrow::Cow;
+use std::env;
 use std::path::{Path, PathBuf};
 
 /// Holds global state for custom paths. Static's leveraging this type
@@ -24,9 +31,12 @@ pub fn set_procfs_root<T: Into<Cow<'static, Path>>>(root: T) {
 
 /// Returns the static `Path` value of a configured PROCFS_ROOT.
 ///
-/// If uninitialized, initializes the PROCFS_ROOT with the default path - `/proc`
+/// If uninitialized, initializes the PROCFS_ROOT with the `HEIM_PROCFS_ROOT`
+/// environment variable value or with the default path - `/proc`
 pub fn procfs_root() -> &'static Path {
-    PROCFS_ROOT.get_or_init(|| PathBuf::from("/proc")).as_ref()
+    PROCFS_ROOT
+        .get_or_init(|| root_from_env("HEIM_PROCFS_ROOT", "/proc"))
+        .as_ref()
 }
 
 /// Instantiates the OnceCell holding SYSFS_ROOT in the case that it hasn't
@@ -38,9 +48,20 @@ pub fn set_sysfs_root<T: Into<Cow<'static, Path>>>(root: T) {
     let _ = SYSFS_ROOT.get_or_init(|| root);
 }
 
-/// Returns the static `Path` value of a configured PROCFS_ROOT.
+/// Returns the static `Path` value of a configured SYSFS_ROOT.
 ///
-/// If uninitialized, initializes the PROCFS_ROOT with the default path - `/sys`
+/// If uninitialized, initializes the SYSFS_ROOT with the `HEIM_SYSFS_ROOT`
+/// environment variable value or with the default path - `/sys`
 pub fn sysfs_root() -> &'static Path {
-    SYSFS_ROOT.get_or_init(|| PathBuf::from("/sys")).as_ref()
+    SYSFS_ROOT
+        .get_or_init(|| root_from_env("HEIM_SYSFS_ROOT", "/sys"))
+        .as_ref()
+}
+
+/// Empty environment variable value is ignored same as unset one.
+fn root_from_env(var: &str, default: &str) -> PathBuf {
+    match env::var_os(var) {
+        Some(root) if !root.is_empty() => PathBuf::from(root),
+        _ => PathBuf::from(default),
+    }
 }
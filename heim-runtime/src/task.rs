@@ -0,0 +1,51 @@
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::future::Future;
+
+use crate::backend;
+
+/// Handle to the task started with [spawn] function.
+///
+/// Handle is a future which resolves into the task output;
+/// unlike the runtime-specific handles, it behaves the same way
+/// for all runtime backends: dropping it detaches the task, which keeps running
+/// in the background, and the task is never cancelled.
+///
+/// [spawn]: ./fn.spawn.html
+pub struct JoinHandle<T>(backend::JoinHandle<T>);
+
+impl<T> JoinHandle<T> {
+    /// Detaches the task to let it keep running in the background,
+    /// its output is discarded.
+    pub fn detach(self) {
+        self.0.detach()
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle").finish()
+    }
+}
+
+/// Spawns the future `f` on the selected runtime backend,
+/// returning the handle which can be awaited for its output or detached.
+///
+/// With `runtime-tokio` feature this function should be called from the Tokio runtime context.
+pub fn spawn<F>(f: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    JoinHandle(backend::spawn(f))
+}
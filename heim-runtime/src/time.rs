@@ -1,9 +1,12 @@
+use std::error;
+use std::fmt;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::Duration;
 
 use futures::{future::Future, stream::Stream};
-use futures_timer::Delay;
+
+use crate::backend::{self, Delay};
 
 /// Naive interval stream implementation.
 ///
@@ -33,3 +36,44 @@ pub fn interval(duration: Duration) -> Interval {
         delay: Delay::new(duration),
     }
 }
+
+/// Waits until `duration` has elapsed.
+pub async fn sleep(duration: Duration) {
+    backend::sleep(duration).await
+}
+
+/// Error returned by [timeout] when the deadline has elapsed
+/// before the future completed.
+///
+/// [timeout]: ./fn.timeout.html
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TimeoutError(());
+
+impl TimeoutError {
+    pub(crate) fn new() -> TimeoutError {
+        TimeoutError(())
+    }
+}
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Deadline has elapsed")
+    }
+}
+
+impl error::Error for TimeoutError {}
+
+/// Requires the `future` to complete before the `duration` has elapsed.
+///
+/// If the `future` completes in time, its output is returned,
+/// otherwise it is cancelled and [TimeoutError] is returned.
+///
+/// Timer of the selected runtime backend is used.
+///
+/// [TimeoutError]: ./struct.TimeoutError.html
+pub async fn timeout<F>(duration: Duration, future: F) -> Result<F::Output, TimeoutError>
+where
+    F: Future,
+{
+    backend::timeout(duration, future).await
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "runtime-async-std")]
+
+use std::time::Duration;
+
+use futures::{future, TryStreamExt};
+
+#[test]
+fn test_timeout_completes() {
+    let res = async_std::task::block_on(heim_runtime::timeout(
+        Duration::from_secs(5),
+        heim_runtime::spawn_blocking(|| 42),
+    ));
+
+    assert_eq!(res, Ok(42));
+}
+
+#[test]
+fn test_timeout_elapsed() {
+    let res = async_std::task::block_on(heim_runtime::timeout(
+        Duration::from_millis(10),
+        future::pending::<()>(),
+    ));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_fs() {
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    let contents =
+        async_std::task::block_on(heim_runtime::fs::read_to_string(root.join("Cargo.toml")));
+
+    assert!(contents.unwrap().contains("heim-runtime"));
+
+    let lines = async_std::task::block_on(async {
+        let lines = heim_runtime::fs::read_lines(root.join("Cargo.toml")).await?;
+        lines.try_collect::<Vec<_>>().await
+    });
+
+    assert_eq!(lines.unwrap()[0], "[package]");
+}
@@ -0,0 +1,61 @@
+#![cfg(not(feature = "runtime-tokio"))] // Tokio backend requires the Tokio runtime context
+
+use std::time::Duration;
+
+use futures::future;
+
+#[test]
+fn test_join_2() {
+    let res = smol::block_on(async { heim_runtime::join!(async { 1 }, future::ready("two")) });
+
+    assert_eq!(res, (1, "two"));
+}
+
+#[test]
+fn test_join_3() {
+    let res = smol::block_on(async {
+        heim_runtime::join!(
+            heim_runtime::spawn_blocking(|| 1),
+            heim_runtime::sleep(Duration::from_millis(1)),
+            future::ready(3u8),
+        )
+    });
+
+    assert_eq!(res, (1, (), 3));
+}
+
+#[test]
+fn test_join_5() {
+    let res = smol::block_on(async {
+        heim_runtime::join!(
+            future::ready(1),
+            future::ready(2),
+            future::ready(3),
+            future::ready(4),
+            future::ready(5),
+        )
+    });
+
+    assert_eq!(res, (1, 2, 3, 4, 5));
+}
+
+#[test]
+fn test_try_join() {
+    let res: Result<(i32, i32), &str> =
+        smol::block_on(async { heim_runtime::try_join!(future::ok(1), future::ok(2)) });
+
+    assert_eq!(res, Ok((1, 2)));
+}
+
+#[test]
+fn test_try_join_short_circuit() {
+    // Second future never completes, so the first error should be returned immediately
+    let res = smol::block_on(async {
+        heim_runtime::try_join!(
+            future::err::<(), _>("first"),
+            future::pending::<Result<(), _>>()
+        )
+    });
+
+    assert_eq!(res, Err("first"));
+}
@@ -34,3 +34,36 @@ rusty_fork_test! {
         assert_eq!(rt::linux::sysfs_root(), PathBuf::from("/host/sys"));
     }
 }
+
+rusty_fork_test! {
+    #[test]
+    fn test_env_proc_root() {
+        std::env::set_var("HEIM_PROCFS_ROOT", "/host/proc");
+        assert_eq!(rt::linux::procfs_root(), PathBuf::from("/host/proc"));
+    }
+}
+
+rusty_fork_test! {
+    #[test]
+    fn test_env_proc_root_overridden() {
+        std::env::set_var("HEIM_PROCFS_ROOT", "/host/proc");
+        rt::linux::set_procfs_root(Path::new("/custom/proc"));
+        assert_eq!(rt::linux::procfs_root(), PathBuf::from("/custom/proc"));
+    }
+}
+
+rusty_fork_test! {
+    #[test]
+    fn test_env_empty_proc_root() {
+        std::env::set_var("HEIM_PROCFS_ROOT", "");
+        assert_eq!(rt::linux::procfs_root(), PathBuf::from("/proc"));
+    }
+}
+
+rusty_fork_test! {
+    #[test]
+    fn test_env_sys_root() {
+        std::env::set_var("HEIM_SYSFS_ROOT", "/host/sys");
+        assert_eq!(rt::linux::sysfs_root(), PathBuf::from("/host/sys"));
+    }
+}
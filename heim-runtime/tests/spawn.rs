@@ -0,0 +1,35 @@
+#![cfg(not(feature = "runtime-tokio"))] // Tokio backend requires the Tokio runtime context
+
+use futures::channel::oneshot;
+
+#[test]
+fn test_spawn() {
+    let res = smol::block_on(heim_runtime::spawn(async { 42 }));
+
+    assert_eq!(res, 42);
+}
+
+#[test]
+fn test_spawn_detach() {
+    let (tx, rx) = oneshot::channel();
+    heim_runtime::spawn(async move {
+        let _ = tx.send(42);
+    })
+    .detach();
+
+    assert_eq!(smol::block_on(rx), Ok(42));
+}
+
+#[test]
+fn test_spawn_drop_detaches() {
+    let (tx, rx) = oneshot::channel();
+    let (started_tx, started_rx) = oneshot::channel::<()>();
+    let handle = heim_runtime::spawn(async move {
+        let _ = started_rx.await;
+        let _ = tx.send(42);
+    });
+    drop(handle);
+    let _ = started_tx.send(());
+
+    assert_eq!(smol::block_on(rx), Ok(42));
+}
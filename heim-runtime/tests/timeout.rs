@@ -0,0 +1,25 @@
+#![cfg(not(any(feature = "runtime-tokio", feature = "runtime-async-std")))]
+
+use std::time::Duration;
+
+use futures::future;
+
+#[test]
+fn test_timeout_completes() {
+    let res = smol::block_on(heim_runtime::timeout(
+        Duration::from_secs(5),
+        heim_runtime::spawn_blocking(|| 42),
+    ));
+
+    assert_eq!(res, Ok(42));
+}
+
+#[test]
+fn test_timeout_elapsed() {
+    let res = smol::block_on(heim_runtime::timeout(
+        Duration::from_millis(10),
+        future::pending::<()>(),
+    ));
+
+    assert_eq!(res, Err(heim_runtime::TimeoutError::default()));
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "runtime-tokio")]
+
+use std::time::{Duration, Instant};
+
+use futures::{StreamExt, TryStreamExt};
+
+fn block_on<F: std::future::Future>(f: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(f)
+}
+
+#[test]
+fn test_spawn() {
+    let res = block_on(async { heim_runtime::spawn(async { 42 }).await });
+
+    assert_eq!(res, 42);
+}
+
+#[test]
+fn test_spawn_detach() {
+    let (tx, rx) = futures::channel::oneshot::channel();
+    let res = block_on(async move {
+        heim_runtime::spawn(async move {
+            let _ = tx.send(42);
+        })
+        .detach();
+
+        rx.await
+    });
+
+    assert_eq!(res, Ok(42));
+}
+
+#[test]
+#[should_panic(expected = "boom")]
+fn test_spawn_panic() {
+    block_on(async { heim_runtime::spawn(async { panic!("boom") }).await })
+}
+
+#[test]
+fn test_spawn_blocking() {
+    let thread = block_on(heim_runtime::spawn_blocking(|| std::thread::current().id()));
+
+    assert_ne!(thread, std::thread::current().id());
+}
+
+#[test]
+#[should_panic(expected = "boom")]
+fn test_spawn_blocking_panic() {
+    block_on(heim_runtime::spawn_blocking(|| panic!("boom")))
+}
+
+#[test]
+fn test_sleep() {
+    let now = Instant::now();
+    block_on(heim_runtime::sleep(Duration::from_millis(10)));
+
+    assert!(now.elapsed() >= Duration::from_millis(10));
+}
+
+#[test]
+fn test_interval() {
+    let now = Instant::now();
+    block_on(async {
+        heim_runtime::time::interval(Duration::from_millis(5))
+            .take(2)
+            .for_each(|_| async {})
+            .await
+    });
+
+    assert!(now.elapsed() >= Duration::from_millis(10));
+}
+
+#[test]
+fn test_timeout_completes() {
+    let res = block_on(heim_runtime::timeout(
+        Duration::from_secs(5),
+        heim_runtime::spawn_blocking(|| 42),
+    ));
+
+    assert_eq!(res, Ok(42));
+}
+
+#[test]
+fn test_timeout_elapsed() {
+    let res = block_on(heim_runtime::timeout(
+        Duration::from_millis(10),
+        futures::future::pending::<()>(),
+    ));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_fs() {
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    let contents = block_on(heim_runtime::fs::read_to_string(root.join("Cargo.toml")));
+
+    assert!(contents.unwrap().contains("heim-runtime"));
+
+    let names = block_on(async {
+        let entries = heim_runtime::fs::read_dir(root).await?;
+        entries
+            .map_ok(|entry| entry.file_name())
+            .try_collect::<Vec<_>>()
+            .await
+    });
+
+    assert!(names.unwrap().iter().any(|name| name == "Cargo.toml"));
+}
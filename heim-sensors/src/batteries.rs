@@ -0,0 +1,104 @@
+use std::fmt;
+
+use heim_common::prelude::*;
+use heim_common::units::{Energy, Ratio, Time};
+
+use crate::sys;
+
+heim_common::serde! {
+    /// Battery charging state.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum BatteryState {
+        /// Battery is charging
+        Charging,
+        /// Battery is discharging
+        Discharging,
+        /// Battery is fully charged
+        Full,
+        /// Battery state is unknown,
+        /// for example, if it is plugged in, but not charging
+        Unknown,
+    }
+}
+
+/// Battery information.
+pub struct Battery {
+    pub(crate) percent: Ratio,
+    pub(crate) state: BatteryState,
+    pub(crate) time_to_full: Option<Time>,
+    pub(crate) time_to_empty: Option<Time>,
+    pub(crate) energy: Option<Energy>,
+    pub(crate) energy_full: Option<Energy>,
+}
+
+heim_common::serde!(Battery {
+    percent,
+    state,
+    time_to_full,
+    time_to_empty,
+    energy,
+    energy_full
+});
+
+impl Battery {
+    /// Returns battery charge level.
+    pub fn percent(&self) -> Ratio {
+        self.percent
+    }
+
+    /// Returns battery charging state.
+    pub fn state(&self) -> BatteryState {
+        self.state
+    }
+
+    /// Returns estimated time until the battery is fully charged.
+    ///
+    /// `None` is returned if battery is not charging or estimation is not available.
+    pub fn time_to_full(&self) -> Option<Time> {
+        self.time_to_full
+    }
+
+    /// Returns estimated time until the battery is fully discharged.
+    ///
+    /// `None` is returned if battery is not discharging or estimation is not available.
+    pub fn time_to_empty(&self) -> Option<Time> {
+        self.time_to_empty
+    }
+
+    /// Returns amount of energy currently stored in the battery.
+    pub fn energy(&self) -> Option<Energy> {
+        self.energy
+    }
+
+    /// Returns amount of energy stored in the battery when it is fully charged.
+    pub fn energy_full(&self) -> Option<Energy> {
+        self.energy_full
+    }
+}
+
+impl fmt::Debug for Battery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Battery")
+            .field("percent", &self.percent())
+            .field("state", &self.state())
+            .field("time_to_full", &self.time_to_full())
+            .field("time_to_empty", &self.time_to_empty())
+            .field("energy", &self.energy())
+            .field("energy_full", &self.energy_full())
+            .finish()
+    }
+}
+
+/// Returns a stream over the system [batteries].
+///
+/// Stream is empty if there are no batteries installed.
+///
+/// ## Compatibility
+///
+/// At the moment, this function works only with Linux and macOS.
+/// For Windows it returns an empty stream.
+///
+/// [batteries]: ./struct.Battery.html
+pub fn batteries() -> impl Stream<Item = Result<Battery>> {
+    sys::batteries()
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+
+use heim_common::prelude::*;
+use heim_common::units::AngularVelocity;
+
+use crate::sys;
+
+/// Hardware fan sensor.
+pub struct Fan {
+    pub(crate) unit: String,
+    pub(crate) label: Option<String>,
+    pub(crate) current: AngularVelocity,
+}
+
+heim_common::serde!(Fan {
+    unit,
+    label,
+    current
+});
+
+impl Fan {
+    /// Returns sensor unit name.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Returns sensor label.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns current fan speed.
+    ///
+    /// Stopped fans are reporting zero speed.
+    pub fn current(&self) -> AngularVelocity {
+        self.current
+    }
+}
+
+impl fmt::Debug for Fan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Fan")
+            .field("unit", &self.unit())
+            .field("label", &self.label())
+            .field("current", &self.current())
+            .finish()
+    }
+}
+
+/// Returns a stream over the hardware [fans] speed.
+///
+/// ## Compatibility
+///
+/// For Linux fans are loaded from the `/sys/class/hwmon` directory.
+///
+/// For macOS fans speed is read from the SMC `F*Ac` keys.
+///
+/// For Windows this function returns an empty stream.
+///
+/// [fans]: ./struct.Fan.html
+pub fn fans() -> impl Stream<Item = Result<Fan>> {
+    sys::fans()
+}
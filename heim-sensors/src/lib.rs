@@ -25,6 +25,10 @@
 
 mod sys;
 
+mod batteries;
+mod fans;
 mod temperatures;
 
+pub use self::batteries::*;
+pub use self::fans::*;
 pub use self::temperatures::*;
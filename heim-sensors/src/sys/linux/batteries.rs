@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::units::{energy, ratio, time, Energy, Ratio, Time};
+use heim_runtime as rt;
+
+use crate::{Battery, BatteryState};
+
+/// Parsed `uevent` file of the power supply, see
+/// https://www.kernel.org/doc/Documentation/ABI/testing/sysfs-class-power
+#[derive(Debug)]
+struct Uevent(HashMap<String, String>);
+
+impl Uevent {
+    fn parse(contents: &str) -> Uevent {
+        let values = contents
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(2, '=');
+                let key = parts.next()?.strip_prefix("POWER_SUPPLY_")?;
+                let value = parts.next()?;
+
+                Some((key.to_string(), value.to_string()))
+            })
+            .collect();
+
+        Uevent(values)
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get(key).and_then(|value| value.parse::<f64>().ok())
+    }
+}
+
+fn battery_state(status: Option<&str>) -> BatteryState {
+    match status {
+        Some("Charging") => BatteryState::Charging,
+        Some("Discharging") => BatteryState::Discharging,
+        Some("Full") => BatteryState::Full,
+        _ => BatteryState::Unknown,
+    }
+}
+
+/// Builds `Battery` from the power supply `uevent` values.
+///
+/// `None` is returned for peripheral devices batteries (mouses, keyboards and so on)
+/// and for the batteries removed from their slots.
+fn battery(uevent: &Uevent) -> Result<Option<Battery>> {
+    if uevent.get("SCOPE") == Some("Device") || uevent.get("PRESENT") == Some("0") {
+        return Ok(None);
+    }
+
+    let state = battery_state(uevent.get("STATUS"));
+    let voltage = uevent.get_f64("VOLTAGE_NOW");
+    // Energy values are in µWh, charge values are in µAh,
+    // and they should be multiplied by voltage (in µV) in order to get the energy amount
+    let from_charge = |key| match (uevent.get_f64(key), voltage) {
+        (Some(charge), Some(voltage)) => Some(charge * voltage / 1_000_000.0),
+        _ => None,
+    };
+    let energy_now = uevent
+        .get_f64("ENERGY_NOW")
+        .or_else(|| from_charge("CHARGE_NOW"));
+    let energy_full = uevent
+        .get_f64("ENERGY_FULL")
+        .or_else(|| from_charge("CHARGE_FULL"));
+    // Power value is in µW, current is in µA
+    let power = uevent
+        .get_f64("POWER_NOW")
+        .or_else(|| match (uevent.get_f64("CURRENT_NOW"), voltage) {
+            (Some(current), Some(voltage)) => Some(current * voltage / 1_000_000.0),
+            _ => None,
+        })
+        .filter(|power| *power > 0.0);
+
+    let percent = match (uevent.get_f64("CAPACITY"), energy_now, energy_full) {
+        (Some(capacity), _, _) => capacity / 100.0,
+        (None, Some(now), Some(full)) if full > 0.0 => now / full,
+        _ => {
+            let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+            return Err(Error::from(inner).with_message("Unable to determine battery capacity"));
+        }
+    };
+
+    // Kernel might provide estimations by itself, in seconds
+    let time_to_empty = match (uevent.get_f64("TIME_TO_EMPTY_NOW"), energy_now, power) {
+        (Some(seconds), _, _) => Some(seconds),
+        (None, Some(now), Some(power)) if state == BatteryState::Discharging => {
+            Some(now / power * 3_600.0)
+        }
+        _ => None,
+    };
+    let time_to_full = match (
+        uevent.get_f64("TIME_TO_FULL_NOW"),
+        energy_now,
+        energy_full,
+        power,
+    ) {
+        (Some(seconds), _, _, _) => Some(seconds),
+        (None, Some(now), Some(full), Some(power)) if state == BatteryState::Charging => {
+            Some((full - now).max(0.0) / power * 3_600.0)
+        }
+        _ => None,
+    };
+
+    Ok(Some(Battery {
+        percent: Ratio::new::<ratio::ratio>(percent.min(1.0) as f32),
+        state,
+        time_to_full: time_to_full.map(Time::new::<time::second>),
+        time_to_empty: time_to_empty.map(Time::new::<time::second>),
+        energy: energy_now.map(|value| Energy::new::<energy::microwatt_hour>(value as f32)),
+        energy_full: energy_full.map(|value| Energy::new::<energy::microwatt_hour>(value as f32)),
+    }))
+}
+
+async fn power_supply(root: PathBuf) -> Result<Option<Battery>> {
+    let kind = rt::fs::read_to_string(root.join("type"))
+        .await
+        .map_err(|e| Error::from(e).with_file(root.join("type")))?;
+    if kind.trim_end() != "Battery" {
+        return Ok(None);
+    }
+
+    let contents = rt::fs::read_to_string(root.join("uevent"))
+        .await
+        .map_err(|e| Error::from(e).with_file(root.join("uevent")))?;
+
+    battery(&Uevent::parse(&contents))
+}
+
+async fn power_supplies() -> Result<Vec<PathBuf>> {
+    let root = rt::linux::sysfs_root().join("class/power_supply");
+    let entries = match rt::fs::read_dir(&root).await {
+        Ok(entries) => entries,
+        // There are no power supplies at all, ex. inside of the containers
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(Error::from(e).with_file(root)),
+    };
+
+    entries
+        .map_ok(|entry| entry.path())
+        .map_err(Error::from)
+        .try_collect()
+        .await
+}
+
+pub fn batteries() -> impl Stream<Item = Result<Battery>> {
+    power_supplies()
+        .map_ok(|paths| stream::iter(paths).map(Ok))
+        .try_flatten_stream()
+        .and_then(power_supply)
+        .try_filter_map(future::ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::{energy, ratio, time};
+
+    use super::{battery, Uevent};
+    use crate::BatteryState;
+
+    #[test]
+    fn test_energy_battery() {
+        let uevent = Uevent::parse(
+            "POWER_SUPPLY_NAME=BAT0
+POWER_SUPPLY_TYPE=Battery
+POWER_SUPPLY_STATUS=Discharging
+POWER_SUPPLY_PRESENT=1
+POWER_SUPPLY_VOLTAGE_NOW=12000000
+POWER_SUPPLY_POWER_NOW=10000000
+POWER_SUPPLY_ENERGY_FULL=50000000
+POWER_SUPPLY_ENERGY_NOW=25000000
+POWER_SUPPLY_CAPACITY=50
+",
+        );
+        let battery = battery(&uevent).unwrap().unwrap();
+
+        assert_eq!(BatteryState::Discharging, battery.state());
+        assert_eq!(0.5, battery.percent().get::<ratio::ratio>());
+        assert_eq!(
+            Some(25.0),
+            battery.energy().map(|e| e.get::<energy::watt_hour>())
+        );
+        assert_eq!(
+            Some(50.0),
+            battery.energy_full().map(|e| e.get::<energy::watt_hour>())
+        );
+        assert_eq!(
+            Some(9_000.0),
+            battery.time_to_empty().map(|t| t.get::<time::second>())
+        );
+        assert_eq!(None, battery.time_to_full());
+    }
+
+    #[test]
+    fn test_charge_battery() {
+        let uevent = Uevent::parse(
+            "POWER_SUPPLY_STATUS=Charging
+POWER_SUPPLY_VOLTAGE_NOW=10000000
+POWER_SUPPLY_CURRENT_NOW=1000000
+POWER_SUPPLY_CHARGE_FULL=4000000
+POWER_SUPPLY_CHARGE_NOW=3000000
+",
+        );
+        let battery = battery(&uevent).unwrap().unwrap();
+
+        assert_eq!(BatteryState::Charging, battery.state());
+        assert_eq!(0.75, battery.percent().get::<ratio::ratio>());
+        assert_eq!(
+            Some(30.0),
+            battery.energy().map(|e| e.get::<energy::watt_hour>())
+        );
+        assert_eq!(
+            Some(3_600.0),
+            battery.time_to_full().map(|t| t.get::<time::second>())
+        );
+        assert_eq!(None, battery.time_to_empty());
+    }
+
+    #[test]
+    fn test_peripheral_battery() {
+        let uevent = Uevent::parse(
+            "POWER_SUPPLY_SCOPE=Device
+POWER_SUPPLY_STATUS=Discharging
+POWER_SUPPLY_CAPACITY=80
+",
+        );
+
+        assert!(battery(&uevent).unwrap().is_none());
+    }
+    #[test]
+    fn test_absent_battery() {
+        let uevent = Uevent::parse(
+            "POWER_SUPPLY_NAME=BAT1
+POWER_SUPPLY_TYPE=Battery
+POWER_SUPPLY_STATUS=Unknown
+POWER_SUPPLY_PRESENT=0
+",
+        );
+
+        assert!(battery(&uevent).unwrap().is_none());
+    }
+}
@@ -0,0 +1,46 @@
+use std::ffi::OsStr;
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::units::{angular_velocity, AngularVelocity};
+use heim_runtime as rt;
+
+use super::hwmon::{file_name, inputs};
+use crate::Fan;
+
+async fn fan(input: PathBuf) -> Result<Option<Fan>> {
+    // It is guaranteed by `hwmon::inputs` directory traversal,
+    // that it is not a root directory and it points to a file.
+    let root = input.parent().unwrap_or_else(|| unreachable!());
+    let prefix = match input.file_name() {
+        Some(name) => {
+            let offset = name.len() - b"input".len();
+            OsStr::from_bytes(&name.as_bytes()[..offset])
+        }
+        None => unreachable!(),
+    };
+
+    // Value is in RPM; unpopulated fans slots might fail with `ENODATA` or `EIO` errors on read
+    let current = match rt::fs::read_to_string(&input).await {
+        Ok(contents) => contents.trim_end().parse::<f32>()?,
+        Err(..) => return Ok(None),
+    };
+    let unit = rt::fs::read_to_string(root.join("name"))
+        .await
+        .map_err(|e| Error::from(e).with_file(root.join("name")))?;
+    let label = rt::fs::read_to_string(root.join(file_name(prefix, b"label")))
+        .await
+        .ok()
+        .map(|label| label.trim_end().to_string());
+
+    Ok(Some(Fan {
+        unit: unit.trim_end().to_string(),
+        label,
+        current: AngularVelocity::new::<angular_velocity::revolution_per_minute>(current),
+    }))
+}
+
+pub fn fans() -> impl Stream<Item = Result<Fan>> {
+    inputs(b"fan").and_then(fan).try_filter_map(future::ok)
+}
@@ -0,0 +1,53 @@
+//! Shared routines for the `/sys/class/hwmon` sensors,
+//! see https://www.kernel.org/doc/Documentation/hwmon/sysfs-interface
+
+use std::ffi::{OsStr, OsString};
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+#[inline]
+pub fn file_name(prefix: &OsStr, postfix: &[u8]) -> OsString {
+    let mut name = OsString::with_capacity(prefix.len() + postfix.len());
+    name.push(prefix);
+    name.push(OsStr::from_bytes(postfix));
+
+    name
+}
+
+/// Reads the directory entries, yielding nothing if directory does not exist.
+pub fn read_dir(path: PathBuf) -> impl Stream<Item = io::Result<rt::fs::DirEntry>> {
+    rt::fs::read_dir(path)
+        .map(|result| match result {
+            Ok(entries) => Ok(entries.left_stream()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(stream::empty().right_stream()),
+            Err(e) => Err(e),
+        })
+        .try_flatten_stream()
+}
+
+/// Returns paths to the `{kind}*_input` files of all `hwmon` devices.
+///
+/// CentOS has an intermediate /device directory, so it is checked too:
+/// https://github.com/giampaolo/psutil/issues/971
+pub fn inputs(kind: &'static [u8]) -> impl Stream<Item = Result<PathBuf>> {
+    read_dir(rt::linux::sysfs_root().join("class/hwmon"))
+        .try_filter(|entry| future::ready(entry.file_name().as_bytes().starts_with(b"hwmon")))
+        .map_ok(|entry| {
+            let root = entry.path();
+
+            stream::iter(vec![read_dir(root.join("device")), read_dir(root)]).flatten()
+        })
+        .try_flatten()
+        .try_filter(move |entry| {
+            let name = entry.file_name();
+            let bytes = name.as_bytes();
+
+            future::ready(bytes.starts_with(kind) && bytes.ends_with(b"_input"))
+        })
+        .map_ok(|entry| entry.path())
+        .map_err(Error::from)
+}
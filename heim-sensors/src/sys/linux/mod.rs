@@ -1,3 +1,8 @@
+mod batteries;
+mod fans;
+mod hwmon;
 mod temperatures;
 
+pub use self::batteries::*;
+pub use self::fans::*;
 pub use self::temperatures::*;
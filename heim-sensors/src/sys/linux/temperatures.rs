@@ -1,4 +1,4 @@
-use std::ffi::{OsStr, OsString};
+use std::ffi::OsStr;
 use std::os::unix::ffi::OsStrExt;
 use std::path::PathBuf;
 
@@ -7,17 +7,9 @@ use heim_common::units::{thermodynamic_temperature, ThermodynamicTemperature};
 use heim_common::utils::stream::HeimStreamExt;
 use heim_runtime as rt;
 
+use super::hwmon::{file_name, read_dir};
 use crate::TemperatureSensor;
 
-#[inline]
-fn file_name(prefix: &OsStr, postfix: &[u8]) -> OsString {
-    let mut name = OsString::with_capacity(prefix.len() + postfix.len());
-    name.push(prefix);
-    name.push(OsStr::from_bytes(postfix));
-
-    name
-}
-
 async fn read_temperature(path: PathBuf) -> Result<ThermodynamicTemperature> {
     let contents = rt::fs::read_to_string(path).await?;
     // Originally value is in millidegrees of Celsius
@@ -28,7 +20,12 @@ async fn read_temperature(path: PathBuf) -> Result<ThermodynamicTemperature> {
     >(value / 1_000.0))
 }
 
-async fn hwmon_sensor(input: PathBuf) -> Result<TemperatureSensor> {
+/// Unpopulated sensors slots are reporting zero or negative values.
+fn is_valid(temperature: &ThermodynamicTemperature) -> bool {
+    temperature.get::<thermodynamic_temperature::degree_celsius>() > 0.0
+}
+
+async fn hwmon_sensor(input: PathBuf) -> Result<Option<TemperatureSensor>> {
     // It is guaranteed by `hwmon` and `hwmon_sensor` directory traversals,
     // that it is not a root directory and it points to a file.
     // Otherwise it is an implementation bug.
@@ -60,26 +57,34 @@ async fn hwmon_sensor(input: PathBuf) -> Result<TemperatureSensor> {
             future::ok::<_, Error>(None)
         });
     let high = read_temperature(root.join(file_name(prefix, b"max")))
-        .map_ok(Some)
+        .map_ok(|temp| Some(temp).filter(is_valid))
         .or_else(|_e| {
             // TODO: Would it be reasonable to propagate errors other than NotFound?
             future::ok::<_, Error>(None)
         });
     let critical = read_temperature(root.join(file_name(prefix, b"crit")))
-        .map_ok(Some)
+        .map_ok(|temp| Some(temp).filter(is_valid))
         .or_else(|_e| {
             // TODO: Would it be reasonable to propagate errors other than NotFound?
             future::ok::<_, Error>(None)
         });
-    let current = read_temperature(input);
+    let current = read_temperature(input)
+        .map_ok(Some)
+        .or_else(|e| match e.raw_os_error() {
+            // Unpopulated sensors slots might fail with `ENODATA` or `EIO` errors on read
+            Some(libc::ENODATA) | Some(libc::EIO) => future::ok(None),
+            _ => future::err(e),
+        });
 
     future::try_join5(unit_name, label, current, high, critical)
-        .map_ok(|(unit, label, current, high, critical)| TemperatureSensor {
-            unit,
-            label,
-            current,
-            high,
-            critical,
+        .map_ok(|(unit, label, current, high, critical)| {
+            current.map(|current| TemperatureSensor {
+                unit,
+                label,
+                current,
+                high,
+                critical,
+            })
         })
         .await
 }
@@ -87,8 +92,7 @@ async fn hwmon_sensor(input: PathBuf) -> Result<TemperatureSensor> {
 fn hwmon() -> impl Stream<Item = Result<TemperatureSensor>> {
     // TODO: It would be nice to have async glob matchers :(
     // Basically we are searching for `/sys/class/hwmon/temp*_*` files here
-    rt::fs::read_dir(rt::linux::sysfs_root().join("class/hwmon"))
-        .try_flatten_stream()
+    read_dir(rt::linux::sysfs_root().join("class/hwmon"))
         .try_filter(|entry| future::ready(entry.file_name().as_bytes().starts_with(b"hwmon")))
         .and_then(|entry| {
             let inner = rt::fs::read_dir(entry.path())
@@ -105,6 +109,7 @@ fn hwmon() -> impl Stream<Item = Result<TemperatureSensor>> {
         .try_flatten()
         .map_err(Error::from)
         .and_then(|entry| hwmon_sensor(entry.path()))
+        .try_filter_map(future::ok)
 }
 
 // CentOS has an intermediate /device directory:
@@ -113,8 +118,7 @@ fn hwmon() -> impl Stream<Item = Result<TemperatureSensor>> {
 fn hwmon_device() -> impl Stream<Item = Result<TemperatureSensor>> {
     // TODO: It would be nice to have async glob matchers :(
     // Basically we are searching for `/sys/class/hwmon/temp*_*` files here
-    rt::fs::read_dir(rt::linux::sysfs_root().join("class/hwmon"))
-        .try_flatten_stream()
+    read_dir(rt::linux::sysfs_root().join("class/hwmon"))
         .try_filter(|entry| future::ready(entry.file_name().as_bytes().starts_with(b"hwmon")))
         .try_filter(|entry| {
             // TODO: `entry.path()` allocates memory for `PathBuf` twice
@@ -136,12 +140,12 @@ fn hwmon_device() -> impl Stream<Item = Result<TemperatureSensor>> {
         .try_flatten()
         .map_err(Error::from)
         .and_then(|entry| hwmon_sensor(entry.path()))
+        .try_filter_map(future::ok)
 }
 
 // https://www.kernel.org/doc/Documentation/thermal/sysfs-api.txt
 fn thermal_zone() -> impl Stream<Item = Result<TemperatureSensor>> {
-    rt::fs::read_dir(rt::linux::sysfs_root().join("class/thermal"))
-        .try_flatten_stream()
+    read_dir(rt::linux::sysfs_root().join("class/thermal"))
         .try_filter(|entry| {
             future::ready(entry.file_name().as_bytes().starts_with(b"thermal_zone"))
         })
@@ -193,13 +197,13 @@ fn thermal_zone() -> impl Stream<Item = Result<TemperatureSensor>> {
                         .and_then(move |content| match content.as_str() {
                             "critical\n" => read_temperature(temp_path)
                                 .and_then(move |temp| {
-                                    acc.critical = Some(temp);
+                                    acc.critical = Some(temp).filter(is_valid);
                                     future::ok(acc)
                                 })
                                 .boxed(),
                             "high\n" => read_temperature(temp_path)
                                 .and_then(move |temp| {
-                                    acc.high = Some(temp);
+                                    acc.high = Some(temp).filter(is_valid);
                                     future::ok(acc)
                                 })
                                 .boxed(),
@@ -213,5 +217,7 @@ pub fn temperatures() -> impl Stream<Item = Result<TemperatureSensor>> {
     let hwmon = stream::select(hwmon(), hwmon_device());
 
     // We need the `thermal_zone` items, only if `hwmon` stream yielded nothing
-    hwmon.choose_chain(thermal_zone())
+    hwmon
+        .choose_chain(thermal_zone())
+        .try_filter(|sensor| future::ready(is_valid(&sensor.current)))
 }
@@ -0,0 +1,88 @@
+use heim_common::prelude::*;
+use heim_common::sys::macos::iokit::{self, DictionaryProps};
+use heim_common::units::{energy, ratio, time, Energy, Ratio, Time};
+
+use crate::{Battery, BatteryState};
+
+// `AvgTimeToEmpty` and `AvgTimeToFull` values are set to this one if they are still calculated
+const UNKNOWN_TIME: i64 = 0xFFFF;
+
+fn battery(source: iokit::IoObject) -> Result<Battery> {
+    let props = source.properties()?;
+
+    let is_charging = props.get_bool("IsCharging")?;
+    let is_fully_charged = props.get_bool("FullyCharged").unwrap_or(false);
+    let is_external = props.get_bool("ExternalConnected").unwrap_or(false);
+    let state = match (is_charging, is_fully_charged, is_external) {
+        (true, _, _) => BatteryState::Charging,
+        (false, true, _) => BatteryState::Full,
+        (false, false, false) => BatteryState::Discharging,
+        (false, false, true) => BatteryState::Unknown,
+    };
+
+    // For Apple Silicon machines `CurrentCapacity` and `MaxCapacity` are percents,
+    // while the raw values (in mAh) are provided via separate keys
+    let current = props.get_i64("CurrentCapacity")?;
+    let max = props.get_i64("MaxCapacity")?;
+    let percent = if max > 0 {
+        (current as f32 / max as f32).min(1.0)
+    } else {
+        0.0
+    };
+    let raw_current = props.get_i64("AppleRawCurrentCapacity").unwrap_or(current);
+    let raw_max = props.get_i64("AppleRawMaxCapacity").unwrap_or(max);
+
+    // Voltage is in mV, so mAh * mV results in µWh
+    let (energy, energy_full) = match props.get_i64("Voltage") {
+        Ok(voltage) => (
+            Some(Energy::new::<energy::microwatt_hour>(
+                (raw_current * voltage) as f32,
+            )),
+            Some(Energy::new::<energy::microwatt_hour>(
+                (raw_max * voltage) as f32,
+            )),
+        ),
+        Err(..) => (None, None),
+    };
+
+    // Estimations are in minutes
+    let estimation = |key| match props.get_i64(key) {
+        Ok(minutes) if minutes > 0 && minutes != UNKNOWN_TIME => {
+            Some(Time::new::<time::minute>(minutes as f64))
+        }
+        _ => None,
+    };
+    let time_to_empty = match state {
+        BatteryState::Discharging => estimation("AvgTimeToEmpty"),
+        _ => None,
+    };
+    let time_to_full = match state {
+        BatteryState::Charging => estimation("AvgTimeToFull"),
+        _ => None,
+    };
+
+    Ok(Battery {
+        percent: Ratio::new::<ratio::ratio>(percent),
+        state,
+        time_to_full,
+        time_to_empty,
+        energy,
+        energy_full,
+    })
+}
+
+fn power_sources() -> Result<Vec<Result<Battery>>> {
+    let port = iokit::IoMasterPort::new()?;
+
+    // `AppleSmartBattery` is a subclass of the `IOPMPowerSource`,
+    // desktops are not providing these services at all
+    let batteries = port.get_services(b"IOPMPowerSource\0")?.map(battery);
+
+    Ok(batteries.collect())
+}
+
+pub fn batteries() -> impl Stream<Item = Result<Battery>> {
+    future::ready(power_sources())
+        .map_ok(stream::iter)
+        .try_flatten_stream()
+}
@@ -0,0 +1,47 @@
+use heim_common::prelude::*;
+use heim_common::units::{angular_velocity, AngularVelocity};
+
+use super::smc::Smc;
+use crate::Fan;
+
+fn smc_fans() -> Result<Vec<Result<Fan>>> {
+    let smc = Smc::open()?;
+    let count = match smc.read(b"FNum")?.and_then(|value| value.to_f32()) {
+        Some(count) => count as u8,
+        None => return Ok(vec![]),
+    };
+
+    let mut fans = Vec::with_capacity(usize::from(count));
+    for idx in 0..count {
+        // Fans are indexed with a single hex digit, ex. `F0Ac`, `F1Ac`
+        let digit = match std::char::from_digit(u32::from(idx), 16) {
+            Some(digit) => digit.to_ascii_uppercase() as u8,
+            None => break,
+        };
+        let key = [b'F', digit, b'A', b'c'];
+
+        match smc.read(&key) {
+            Ok(Some(value)) => {
+                if let Some(current) = value.to_f32() {
+                    fans.push(Ok(Fan {
+                        unit: "smc".to_string(),
+                        label: Some(format!("Fan {}", idx)),
+                        current: AngularVelocity::new::<angular_velocity::revolution_per_minute>(
+                            current,
+                        ),
+                    }));
+                }
+            }
+            Ok(None) => continue,
+            Err(e) => fans.push(Err(e)),
+        }
+    }
+
+    Ok(fans)
+}
+
+pub fn fans() -> impl Stream<Item = Result<Fan>> {
+    future::ready(smc_fans())
+        .map_ok(stream::iter)
+        .try_flatten_stream()
+}
@@ -0,0 +1,247 @@
+//! Bindings and a tiny wrapper for the System Management Controller (SMC) access.
+//!
+//! See https://github.com/hholtmann/smcFanControl/blob/master/smc-command/smc.h
+//! for the structs layout reference.
+
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::io;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+
+use mach::{kern_return, port, traps};
+
+use heim_common::prelude::*;
+
+const KERNEL_INDEX_SMC: u32 = 2;
+const SMC_CMD_READ_BYTES: u8 = 5;
+const SMC_CMD_READ_KEYINFO: u8 = 9;
+
+type io_object_t = port::mach_port_t;
+type io_connect_t = port::mach_port_t;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+struct SMCKeyData_vers_t {
+    major: u8,
+    minor: u8,
+    build: u8,
+    reserved: [u8; 1],
+    release: u16,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+struct SMCKeyData_pLimitData_t {
+    version: u16,
+    length: u16,
+    cpuPLimit: u32,
+    gpuPLimit: u32,
+    memPLimit: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+struct SMCKeyData_keyInfo_t {
+    dataSize: u32,
+    dataType: u32,
+    dataAttributes: u8,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+struct SMCKeyData_t {
+    key: u32,
+    vers: SMCKeyData_vers_t,
+    pLimitData: SMCKeyData_pLimitData_t,
+    keyInfo: SMCKeyData_keyInfo_t,
+    result: u8,
+    status: u8,
+    data8: u8,
+    data32: u32,
+    bytes: [u8; 32],
+}
+
+extern "C" {
+    static kIOMasterPortDefault: port::mach_port_t;
+
+    fn IOServiceMatching(name: *const c_char) -> *mut c_void;
+
+    fn IOServiceGetMatchingService(
+        masterPort: port::mach_port_t,
+        matching: *mut c_void,
+    ) -> io_object_t;
+
+    fn IOServiceOpen(
+        service: io_object_t,
+        owningTask: port::mach_port_t,
+        r#type: u32,
+        connect: *mut io_connect_t,
+    ) -> kern_return::kern_return_t;
+
+    fn IOServiceClose(connect: io_connect_t) -> kern_return::kern_return_t;
+
+    fn IOObjectRelease(object: io_object_t) -> kern_return::kern_return_t;
+
+    fn IOConnectCallStructMethod(
+        connection: io_connect_t,
+        selector: u32,
+        inputStruct: *const SMCKeyData_t,
+        inputStructCnt: usize,
+        outputStruct: *mut SMCKeyData_t,
+        outputStructCnt: *mut usize,
+    ) -> kern_return::kern_return_t;
+}
+
+/// Value read from the SMC key.
+#[derive(Debug)]
+pub struct Value {
+    /// Four chars data type, ex. `sp78` or `flt `
+    pub kind: [u8; 4],
+    pub bytes: Vec<u8>,
+}
+
+impl Value {
+    /// Converts value into the `f32` number.
+    ///
+    /// `None` is returned for unsupported data types.
+    pub fn to_f32(&self) -> Option<f32> {
+        match (&self.kind, self.bytes.as_slice()) {
+            // Signed fixed point number, 7 bits for integer part and 8 bits for fraction
+            (b"sp78", [high, low, ..]) => {
+                Some(f32::from(i16::from_be_bytes([*high, *low])) / 256.0)
+            }
+            // Unsigned fixed point number, 14 bits for integer part and 2 bits for fraction
+            (b"fpe2", [high, low, ..]) => Some(f32::from(u16::from_be_bytes([*high, *low])) / 4.0),
+            // Apple Silicon machines are using native floats
+            (b"flt ", [a, b, c, d, ..]) => Some(f32::from_le_bytes([*a, *b, *c, *d])),
+            (b"ui8 ", [value, ..]) => Some(f32::from(*value)),
+            (b"ui16", [high, low, ..]) => Some(f32::from(u16::from_be_bytes([*high, *low]))),
+            _ => None,
+        }
+    }
+}
+
+/// Opened connection to the `AppleSMC` service.
+#[derive(Debug)]
+pub struct Smc(io_connect_t);
+
+impl Smc {
+    pub fn open() -> Result<Smc> {
+        unsafe {
+            let matching = IOServiceMatching(b"AppleSMC\0".as_ptr() as *const c_char);
+            // `matching` reference is consumed by this call
+            let service = IOServiceGetMatchingService(kIOMasterPortDefault, matching);
+            if service == port::MACH_PORT_NULL {
+                // Function does not provide any error code, service is just missing
+                let inner = io::Error::from(io::ErrorKind::NotFound);
+                return Err(Error::from(inner).with_ffi("IOServiceGetMatchingService"));
+            }
+
+            let mut connection: io_connect_t = port::MACH_PORT_NULL;
+            let result = IOServiceOpen(service, traps::mach_task_self(), 0, &mut connection);
+            let _ = IOObjectRelease(service);
+
+            if result != kern_return::KERN_SUCCESS {
+                Err(Error::from_raw_os_error(result).with_ffi("IOServiceOpen"))
+            } else {
+                Ok(Smc(connection))
+            }
+        }
+    }
+
+    fn call(&self, input: &SMCKeyData_t) -> Result<SMCKeyData_t> {
+        let mut output = SMCKeyData_t::default();
+        let mut output_size = mem::size_of::<SMCKeyData_t>();
+
+        let result = unsafe {
+            IOConnectCallStructMethod(
+                self.0,
+                KERNEL_INDEX_SMC,
+                input,
+                mem::size_of::<SMCKeyData_t>(),
+                &mut output,
+                &mut output_size,
+            )
+        };
+
+        if result != kern_return::KERN_SUCCESS {
+            Err(Error::from_raw_os_error(result).with_ffi("IOConnectCallStructMethod"))
+        } else {
+            Ok(output)
+        }
+    }
+
+    /// Reads the `key` value.
+    ///
+    /// `None` is returned if key is not supported by this SMC.
+    pub fn read(&self, key: &[u8; 4]) -> Result<Option<Value>> {
+        let mut input = SMCKeyData_t {
+            key: u32::from_be_bytes(*key),
+            data8: SMC_CMD_READ_KEYINFO,
+            ..Default::default()
+        };
+        let info = self.call(&input)?;
+        if info.result != 0 {
+            return Ok(None);
+        }
+
+        input.keyInfo.dataSize = info.keyInfo.dataSize;
+        input.data8 = SMC_CMD_READ_BYTES;
+        let output = self.call(&input)?;
+        if output.result != 0 {
+            return Ok(None);
+        }
+
+        let size = (info.keyInfo.dataSize as usize).min(output.bytes.len());
+
+        Ok(Some(Value {
+            kind: info.keyInfo.dataType.to_be_bytes(),
+            bytes: output.bytes[..size].to_vec(),
+        }))
+    }
+}
+
+impl Drop for Smc {
+    fn drop(&mut self) {
+        let _ = unsafe { IOServiceClose(self.0) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    #[test]
+    fn test_to_f32() {
+        let value = Value {
+            kind: *b"sp78",
+            bytes: vec![0x2A, 0x80],
+        };
+        assert_eq!(Some(42.5), value.to_f32());
+
+        let value = Value {
+            kind: *b"fpe2",
+            bytes: vec![0x1F, 0x40],
+        };
+        assert_eq!(Some(2000.0), value.to_f32());
+
+        let value = Value {
+            kind: *b"flt ",
+            bytes: 36.6f32.to_le_bytes().to_vec(),
+        };
+        assert_eq!(Some(36.6), value.to_f32());
+
+        let value = Value {
+            kind: *b"ui8 ",
+            bytes: vec![2],
+        };
+        assert_eq!(Some(2.0), value.to_f32());
+
+        let value = Value {
+            kind: *b"ch8*",
+            bytes: vec![0x41],
+        };
+        assert_eq!(None, value.to_f32());
+    }
+}
@@ -1,8 +1,64 @@
 use heim_common::prelude::*;
+use heim_common::units::{thermodynamic_temperature, ThermodynamicTemperature};
 
+use super::smc::Smc;
 use crate::TemperatureSensor;
 
+/// Known SMC temperature keys with their labels.
+///
+/// Keys availability varies from one Mac model to another,
+/// missing ones are skipped.
+const SENSORS: &[(&[u8; 4], &str)] = &[
+    (b"TA0P", "Ambient"),
+    (b"TB0T", "Battery"),
+    (b"TC0D", "CPU Die"),
+    (b"TC0E", "CPU Die (PECI)"),
+    (b"TC0H", "CPU Heatsink"),
+    (b"TC0P", "CPU Proximity"),
+    (b"TG0D", "GPU Die"),
+    (b"TG0H", "GPU Heatsink"),
+    (b"TG0P", "GPU Proximity"),
+    (b"TH0P", "HDD Proximity"),
+    (b"TM0P", "Memory Proximity"),
+    (b"TN0P", "Northbridge Proximity"),
+    (b"TW0P", "Airport Proximity"),
+    (b"Ts0P", "Palm Rest"),
+];
+
+fn smc_sensors() -> Result<Vec<Result<TemperatureSensor>>> {
+    let smc = Smc::open()?;
+    let mut sensors = Vec::with_capacity(SENSORS.len());
+
+    for (key, label) in SENSORS {
+        let value = match smc.read(key) {
+            Ok(Some(value)) => value,
+            Ok(None) => continue,
+            Err(e) => {
+                sensors.push(Err(e));
+                continue;
+            }
+        };
+
+        // Unpopulated sensors slots are reporting zero or negative values
+        match value.to_f32() {
+            Some(current) if current > 0.0 => sensors.push(Ok(TemperatureSensor {
+                unit: "smc".to_string(),
+                label: Some(label.to_string()),
+                current: ThermodynamicTemperature::new::<thermodynamic_temperature::degree_celsius>(
+                    current,
+                ),
+                high: None,
+                critical: None,
+            })),
+            _ => continue,
+        }
+    }
+
+    Ok(sensors)
+}
+
 pub fn temperatures() -> impl Stream<Item = Result<TemperatureSensor>> {
-    // TODO: Stub
-    stream::iter(vec![])
+    future::ready(smc_sensors())
+        .map_ok(stream::iter)
+        .try_flatten_stream()
 }
@@ -0,0 +1,8 @@
+use heim_common::prelude::*;
+
+use crate::Battery;
+
+pub fn batteries() -> impl Stream<Item = Result<Battery>> {
+    // TODO: Stub
+    stream::iter(vec![])
+}
@@ -0,0 +1,8 @@
+use heim_common::prelude::*;
+
+use crate::Fan;
+
+pub fn fans() -> impl Stream<Item = Result<Fan>> {
+    // TODO: Stub
+    stream::iter(vec![])
+}
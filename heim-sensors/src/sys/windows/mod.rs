@@ -1,3 +1,7 @@
+mod batteries;
+mod fans;
 mod temperatures;
 
+pub use self::batteries::*;
+pub use self::fans::*;
 pub use self::temperatures::*;
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use heim_common::prelude::*;
-use heim_common::units::ThermodynamicTemperature;
+use heim_common::units::{thermodynamic_temperature, ThermodynamicTemperature};
 
 use crate::sys;
 
@@ -14,6 +14,14 @@ pub struct TemperatureSensor {
     pub(crate) critical: Option<ThermodynamicTemperature>,
 }
 
+heim_common::serde!(TemperatureSensor {
+    unit,
+    label,
+    current,
+    high,
+    critical
+});
+
 impl TemperatureSensor {
     /// Returns sensor unit name.
     pub fn unit(&self) -> &str {
@@ -40,6 +48,20 @@ impl TemperatureSensor {
     pub fn critical(&self) -> Option<ThermodynamicTemperature> {
         self.critical
     }
+
+    /// Checks if current temperature exceeds the sensor `high` trip point,
+    /// or the [DEFAULT_HIGH_TEMPERATURE] if sensor does not provide it.
+    ///
+    /// [DEFAULT_HIGH_TEMPERATURE]: ./constant.DEFAULT_HIGH_TEMPERATURE.html
+    pub fn is_hot(&self) -> bool {
+        let high = self.high.unwrap_or_else(|| {
+            ThermodynamicTemperature::new::<thermodynamic_temperature::degree_celsius>(
+                DEFAULT_HIGH_TEMPERATURE,
+            )
+        });
+
+        self.current > high
+    }
 }
 
 impl fmt::Debug for TemperatureSensor {
@@ -54,14 +76,94 @@ impl fmt::Debug for TemperatureSensor {
     }
 }
 
+/// Temperature threshold in degrees Celsius, used by the [hot_sensors]
+/// for sensors without a `high` trip point.
+///
+/// [hot_sensors]: ./fn.hot_sensors.html
+pub const DEFAULT_HIGH_TEMPERATURE: f32 = 80.0;
+
 /// Returns a stream over the [temperature sensors] statistics.
 ///
+/// Sensors reporting zero or negative temperatures are considered as unpopulated
+/// and they are skipped; same applies to the `high` and `critical` trip points.
+///
 /// ## Compatibility
 ///
-/// At the moment, this function works only with Linux.
-/// For other platforms it returns an empty stream.
+/// For Linux sensors are loaded from the `/sys/class/hwmon` directory,
+/// or from the `/sys/class/thermal` if there are no `hwmon` sensors.
+///
+/// For macOS only the following SMC keys are supported at the moment
+/// (their availability varies from one Mac model to another):
+///
+///  * `TA0P` - ambient
+///  * `TB0T` - battery
+///  * `TC0D`, `TC0E` - CPU die
+///  * `TC0H` - CPU heatsink
+///  * `TC0P` - CPU proximity
+///  * `TG0D` - GPU die
+///  * `TG0H` - GPU heatsink
+///  * `TG0P` - GPU proximity
+///  * `TH0P` - HDD proximity
+///  * `TM0P` - memory proximity
+///  * `TN0P` - northbridge proximity
+///  * `TW0P` - Airport proximity
+///  * `Ts0P` - palm rest
+///
+/// SMC sensors are not providing `high` and `critical` trip points.
+///
+/// For Windows this function returns an empty stream.
 ///
 /// [temperature sensors]: ./struct.TemperatureSensor.html
 pub fn temperatures() -> impl Stream<Item = Result<TemperatureSensor>> {
     sys::temperatures()
 }
+
+/// Returns a stream over the [temperature sensors] which current temperature
+/// exceeds their `high` trip point.
+///
+/// Sensors without a `high` trip point (ex. all macOS SMC sensors)
+/// are compared with the [DEFAULT_HIGH_TEMPERATURE] instead.
+///
+/// Errors from the [temperatures] stream are yielded as is.
+///
+/// [temperature sensors]: ./struct.TemperatureSensor.html
+/// [DEFAULT_HIGH_TEMPERATURE]: ./constant.DEFAULT_HIGH_TEMPERATURE.html
+/// [temperatures]: ./fn.temperatures.html
+pub fn hot_sensors() -> impl Stream<Item = Result<TemperatureSensor>> {
+    temperatures().try_filter(|sensor| future::ready(sensor.is_hot()))
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::{thermodynamic_temperature, ThermodynamicTemperature};
+
+    use super::TemperatureSensor;
+
+    fn celsius(value: f32) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<thermodynamic_temperature::degree_celsius>(value)
+    }
+
+    fn sensor(current: f32, high: Option<f32>) -> TemperatureSensor {
+        TemperatureSensor {
+            unit: "test".to_string(),
+            label: None,
+            current: celsius(current),
+            high: high.map(celsius),
+            critical: None,
+        }
+    }
+
+    #[test]
+    fn test_is_hot_with_high() {
+        assert!(sensor(70.0, Some(60.0)).is_hot());
+        assert!(!sensor(60.0, Some(60.0)).is_hot());
+        assert!(!sensor(85.0, Some(100.0)).is_hot());
+    }
+
+    #[test]
+    fn test_is_hot_default() {
+        assert!(sensor(85.0, None).is_hot());
+        assert!(!sensor(80.0, None).is_hot());
+        assert!(!sensor(45.0, None).is_hot());
+    }
+}
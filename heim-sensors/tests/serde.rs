@@ -0,0 +1,49 @@
+#![cfg(feature = "serde")]
+
+use heim_common::prelude::*;
+use heim_common::units::{ratio, thermodynamic_temperature};
+use heim_sensors as sensors;
+
+#[heim_derive::test]
+async fn serde_temperatures() {
+    let mut temperatures = sensors::temperatures().boxed_local();
+    while let Some(sensor) = temperatures.next().await {
+        let sensor = sensor.unwrap();
+
+        let value = serde_json::to_value(&sensor).unwrap();
+
+        assert_eq!(value["unit"].as_str(), Some(sensor.unit()));
+        assert_eq!(value["label"].as_str(), sensor.label());
+        assert_eq!(
+            value["current"].as_f64(),
+            Some(f64::from(
+                sensor.current().get::<thermodynamic_temperature::kelvin>()
+            ))
+        );
+        for field in &["high", "critical"] {
+            assert!(value.get(field).is_some(), "{} field is missing", field);
+        }
+    }
+}
+
+#[heim_derive::test]
+async fn serde_batteries() {
+    let mut batteries = sensors::batteries().boxed_local();
+    while let Some(battery) = batteries.next().await {
+        let battery = battery.unwrap();
+
+        let value = serde_json::to_value(&battery).unwrap();
+
+        assert_eq!(
+            serde_json::from_value::<sensors::BatteryState>(value["state"].clone()).unwrap(),
+            battery.state()
+        );
+        assert_eq!(
+            value["percent"].as_f64(),
+            Some(f64::from(battery.percent().get::<ratio::ratio>()))
+        );
+        for field in &["time_to_full", "time_to_empty", "energy", "energy_full"] {
+            assert!(value.get(field).is_some(), "{} field is missing", field);
+        }
+    }
+}
@@ -14,3 +14,38 @@ async fn smoke_temperatures() {
         let _ = sensor.critical();
     }
 }
+
+#[heim_derive::test]
+async fn smoke_batteries() {
+    let mut batteries = sensors::batteries().boxed_local();
+    while let Some(battery) = batteries.next().await {
+        let battery = battery.unwrap();
+
+        let _ = battery.percent();
+        let _ = battery.state();
+        let _ = battery.time_to_full();
+        let _ = battery.time_to_empty();
+        let _ = battery.energy();
+        let _ = battery.energy_full();
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_fans() {
+    let mut fans = sensors::fans().boxed_local();
+    while let Some(fan) = fans.next().await {
+        let fan = fan.unwrap();
+
+        let _ = fan.unit();
+        let _ = fan.label();
+        let _ = fan.current();
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_hot_sensors() {
+    let mut sensors = sensors::hot_sensors().boxed_local();
+    while let Some(sensor) = sensors.next().await {
+        assert!(sensor.unwrap().is_hot());
+    }
+}
@@ -31,77 +31,79 @@
 
 mod sys;
 
-/// Virtualization systems (both VMs and containers)
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Hash)]
-#[non_exhaustive]
-pub enum Virtualization {
-    // VMs
-    /// Kernel Virtual Machine (https://www.linux-kvm.org)
-    Kvm,
+heim_common::serde! {
+    /// Virtualization systems (both VMs and containers)
+    #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Hash)]
+    #[non_exhaustive]
+    pub enum Virtualization {
+        // VMs
+        /// Kernel Virtual Machine (https://www.linux-kvm.org)
+        Kvm,
 
-    /// QEMU (https://www.qemu.org/)
-    Qemu,
+        /// QEMU (https://www.qemu.org/)
+        Qemu,
 
-    /// Bochs IA-32 emulator (http://bochs.sourceforge.net/)
-    Bochs,
+        /// Bochs IA-32 emulator (http://bochs.sourceforge.net/)
+        Bochs,
 
-    /// Xen project (https://xenproject.org/)
-    Xen,
+        /// Xen project (https://xenproject.org/)
+        Xen,
 
-    /// User-Mode Linux (http://user-mode-linux.sourceforge.net/)
-    Uml,
+        /// User-Mode Linux (http://user-mode-linux.sourceforge.net/)
+        Uml,
 
-    /// VMware (https://www.vmware.com)
-    Vmware,
+        /// VMware (https://www.vmware.com)
+        Vmware,
 
-    /// Oracle virtualization (https://www.oracle.com/virtualization/)
-    Oracle,
+        /// Oracle virtualization (https://www.oracle.com/virtualization/)
+        Oracle,
 
-    /// Microsoft Hyper-V (http://www.microsoft.com/hyper-v)
-    HyperV,
+        /// Microsoft Hyper-V (http://www.microsoft.com/hyper-v)
+        HyperV,
 
-    /// FreeBSD bhyve (https://wiki.freebsd.org/bhyve)
-    Bhyve,
-    //    Zvm,
-    /// Parallels (https://www.parallels.com/)
-    Parallels,
+        /// FreeBSD bhyve (https://wiki.freebsd.org/bhyve)
+        Bhyve,
+        //    Zvm,
+        /// Parallels (https://www.parallels.com/)
+        Parallels,
 
-    /// QNX hypervisor (https://blackberry.qnx.com/en/products/hypervisor/index)
-    Qnx,
+        /// QNX hypervisor (https://blackberry.qnx.com/en/products/hypervisor/index)
+        Qnx,
 
-    /// ACRN hypervisor (https://projectacrn.org/)
-    Acrn,
+        /// ACRN hypervisor (https://projectacrn.org/)
+        Acrn,
 
-    // Containers
-    /// `systemd-nspawn` container manager (https://www.freedesktop.org/wiki/Software/systemd/)
-    SystemdNspawn,
+        // Containers
+        /// `systemd-nspawn` container manager (https://www.freedesktop.org/wiki/Software/systemd/)
+        SystemdNspawn,
 
-    /// `lxc-libvirt` (https://libvirt.org/drvlxc.html)
-    LxcLibvirt,
+        /// `lxc-libvirt` (https://libvirt.org/drvlxc.html)
+        LxcLibvirt,
 
-    /// Linux Containers (https://linuxcontainers.org/lxc)
-    Lxc,
+        /// Linux Containers (https://linuxcontainers.org/lxc)
+        Lxc,
 
-    /// OpenVz (https://openvz.org/)
-    OpenVz,
+        /// OpenVz (https://openvz.org/)
+        OpenVz,
 
-    /// Docker (https://www.docker.com/)
-    Docker,
+        /// Docker (https://www.docker.com/)
+        Docker,
 
-    /// Podman (https://podman.io/)
-    Podman,
+        /// Podman (https://podman.io/)
+        Podman,
 
-    /// CoreOS rkt (https://coreos.com/rkt/)
-    Rkt,
+        /// CoreOS rkt (https://coreos.com/rkt/)
+        Rkt,
 
-    /// Microsoft WSL (https://docs.microsoft.com/en-us/windows/wsl/about)
-    Wsl,
+        /// Microsoft WSL (https://docs.microsoft.com/en-us/windows/wsl/about)
+        Wsl,
 
-    /// Unknown virtualization system.
-    ///
-    /// Usually means that there are symptoms of being running in some virtualization system,
-    /// but it can't be determined specifically.
-    Unknown,
+        /// Unknown virtualization system.
+        ///
+        /// Usually means that there are symptoms of being running in some virtualization system,
+        /// but it can't be determined specifically.
+        Unknown,
+    }
 }
 
 impl Virtualization {
@@ -170,10 +172,24 @@ impl Virtualization {
 
 /// Attempts to guess if the running process is running in some [Virtualization] system.
 ///
+/// `None` is returned if no virtualization was detected, which means
+/// that process is running on a bare metal machine.
+///
+/// Container detection takes precedence over the VM one,
+/// so for a Docker container running inside of the KVM guest
+/// [`Virtualization::Docker`] is returned.
+///
 /// ## Compatibility
 ///
-/// At the moment this function works only for Linux (partially)
-/// and always returns `None` for macOS and Windows.
+/// For Linux detection logic mimics the `systemd-detect-virt` tool:
+///
+///  * containers are recognized by the `/proc/sys/kernel/osrelease` contents (WSL),
+///    `/run/systemd/container` file, `container` environment variable of the PID 1,
+///    `/.dockerenv` and `/run/.containerenv` marker files and `/proc/1/cgroup` contents
+///  * VMs are recognized by the DMI strings from the `/sys/class/dmi/id/` directory,
+///    CPUID hypervisor bit and vendor leaf and the `/proc/device-tree/hypervisor/compatible` file
+///
+/// At the moment this function always returns `None` for macOS and Windows.
 pub async fn detect() -> Option<Virtualization> {
     self::sys::detect().await
 }
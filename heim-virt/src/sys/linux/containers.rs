@@ -59,6 +59,7 @@ where
             // TODO: Is it `lxc` or `lxc-libvirt` here?
             Ok(l) if l.contains("lxc") => return Ok(Virtualization::Lxc),
             Ok(l) if l.contains("docker") => return Ok(Virtualization::Docker),
+            Ok(l) if l.contains("libpod") => return Ok(Virtualization::Podman),
             Ok(l) if l.contains("rkt") => return Ok(Virtualization::Rkt),
             _ => continue,
         }
@@ -67,12 +68,23 @@ where
     Err(())
 }
 
+async fn detect_marker_file<T>(path: T, virt: Virtualization) -> Result<Virtualization, ()>
+where
+    T: AsRef<Path> + Send + Unpin + 'static,
+{
+    if rt::fs::path_exists(path).await {
+        Ok(virt)
+    } else {
+        Err(())
+    }
+}
+
 async fn detect_openvz() -> Result<Virtualization, ()> {
     // TODO: Can be done in a blocking task completely
     let f1 = rt::fs::path_exists(rt::linux::procfs_root().join("vz"));
     let f2 = rt::fs::path_exists(rt::linux::procfs_root().join("bc"));
 
-    match futures::join!(f1, f2) {
+    match rt::join!(f1, f2) {
         // `/proc/vz` exists in container and outside of the container,
         // `/proc/bc` only outside of the container.
         (true, false) => Ok(Virtualization::OpenVz),
@@ -112,15 +124,18 @@ pub async fn detect_container() -> Result<Virtualization, ()> {
         .or_else(|_| detect_openvz())
         .or_else(|_| detect_wsl(rt::linux::procfs_root().join("sys/kernel/osrelease")))
         .or_else(|_| detect_systemd_container("/run/systemd/container"))
-        .or_else(|_| detect_init_env("/proc/1/environ"))
-        // TODO: Check for a `/proc/1/environ` if there is `container` env var exists
-        .or_else(|_| detect_cgroups("/proc/self/cgroup"))
+        .or_else(|_| detect_init_env(rt::linux::procfs_root().join("1/environ")))
+        // Container engines are dropping these files into the container root filesystem
+        .or_else(|_| detect_marker_file("/.dockerenv", Virtualization::Docker))
+        .or_else(|_| detect_marker_file("/run/.containerenv", Virtualization::Podman))
+        .or_else(|_| detect_cgroups(rt::linux::procfs_root().join("1/cgroup")))
+        .or_else(|_| detect_cgroups(rt::linux::procfs_root().join("self/cgroup")))
         .await
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{detect_init_env, detect_wsl};
+    use super::{detect_cgroups, detect_init_env, detect_marker_file, detect_wsl};
     use std::io::Write;
 
     use crate::Virtualization;
@@ -152,4 +167,36 @@ mod tests {
 
         assert_eq!(res, Ok(Virtualization::Podman))
     }
+
+    #[heim_derive::test]
+    async fn test_cgroups_docker() {
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        f.write_all(b"12:pids:/docker/1a2b3c\n11:memory:/docker/1a2b3c\n")
+            .unwrap();
+        let res = detect_cgroups(f).await;
+
+        assert_eq!(res, Ok(Virtualization::Docker))
+    }
+
+    #[heim_derive::test]
+    async fn test_cgroups_host() {
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        f.write_all(b"12:pids:/user.slice\n0::/init.scope\n")
+            .unwrap();
+        let res = detect_cgroups(f).await;
+
+        assert_eq!(res, Err(()))
+    }
+
+    #[heim_derive::test]
+    async fn test_marker_file() {
+        let f = tempfile::NamedTempFile::new().unwrap();
+        let path = f.path().to_path_buf();
+        let res = detect_marker_file(path.clone(), Virtualization::Docker).await;
+        assert_eq!(res, Ok(Virtualization::Docker));
+
+        drop(f);
+        let res = detect_marker_file(path, Virtualization::Docker).await;
+        assert_eq!(res, Err(()));
+    }
 }
@@ -5,7 +5,14 @@ use crate::Virtualization;
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub fn detect_vm_cpuid() -> Result<Virtualization, ()> {
-    let cpuid = match CpuId::new().get_hypervisor_info() {
+    let cpuid = CpuId::new();
+    // Hypervisor leaf contents are meaningful only if the "hypervisor present" bit is set
+    match cpuid.get_feature_info() {
+        Some(info) if info.has_hypervisor() => {}
+        _ => return Err(()),
+    }
+
+    let cpuid = match cpuid.get_hypervisor_info() {
         Some(info) => info,
         None => return Err(()),
     };
@@ -25,10 +32,10 @@ pub fn detect_vm_cpuid() -> Result<Virtualization, ()> {
         Hypervisor::Unknown(0x5158_4e51, 0x5342_4d56, 0x0000_4751) => Ok(Virtualization::Qnx),
         // ACRNACRNACRN
         Hypervisor::Unknown(0x4e52_4341, 0x4e52_4341, 0x4e52_4341) => Ok(Virtualization::Acrn),
-        Hypervisor::Unknown(_b, _c, _d) => {
-            // TODO: Logging
-            Err(())
-        }
+        // `VBoxVBoxVBox`
+        Hypervisor::Unknown(0x786f_4256, 0x786f_4256, 0x786f_4256) => Ok(Virtualization::Oracle),
+        // Some hypervisor is present, but it is not a known one
+        Hypervisor::Unknown(_b, _c, _d) => Ok(Virtualization::Unknown),
     }
 }
 
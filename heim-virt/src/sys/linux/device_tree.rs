@@ -13,12 +13,6 @@ use heim_runtime as rt;
 
 use crate::Virtualization;
 
-#[allow(unused)]
-const DEVICE_TREE_ROOT: &str = "/proc/device-tree";
-
-#[allow(unused)]
-const HYPERVISOR_COMPAT_PATH: &str = "/proc/device-tree/hypervisor/compatible";
-
 #[allow(unused)]
 async fn hypervisor<T>(path: T) -> Result<Virtualization, ()>
 where
@@ -57,8 +51,10 @@ where
     target_arch = "powerpc64"
 ))]
 pub async fn detect_vm_device_tree() -> Result<Virtualization, ()> {
-    hypervisor(HYPERVISOR_COMPAT_PATH)
-        .or_else(|_| device_tree(DEVICE_TREE_ROOT))
+    let root = rt::linux::procfs_root().join("device-tree");
+
+    hypervisor(root.join("hypervisor/compatible"))
+        .or_else(|_| device_tree(root))
         .await
 }
 
@@ -28,6 +28,8 @@ pub async fn detect_vm_dmi() -> Result<Virtualization, ()> {
             _ if line.starts_with("VMware") => return Ok(Virtualization::Vmware),
             _ if line.starts_with("VMW") => return Ok(Virtualization::Vmware),
             _ if line.starts_with("innotek GmbH") => return Ok(Virtualization::Oracle),
+            _ if line.starts_with("VirtualBox") => return Ok(Virtualization::Oracle),
+            _ if line.starts_with("Oracle Corporation") => return Ok(Virtualization::Oracle),
             _ if line.starts_with("Xen") => return Ok(Virtualization::Xen),
             _ if line.starts_with("Bochs") => return Ok(Virtualization::Bochs),
             _ if line.starts_with("Parallels") => return Ok(Virtualization::Parallels),
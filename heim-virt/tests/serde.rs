@@ -0,0 +1,11 @@
+#![cfg(feature = "serde")]
+
+use heim_virt::Virtualization;
+
+#[test]
+fn serde_virtualization() {
+    let json = serde_json::to_string(&Virtualization::SystemdNspawn).unwrap();
+    let restored: Virtualization = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored, Virtualization::SystemdNspawn);
+}
@@ -32,6 +32,29 @@
 //!
 //! Alternatively you can use `full` feature to enable all components at once.
 //!
+//! `serde` feature implements `Serialize` trait for the CPU, disk, host, memory, network,
+//! process and sensors data types; they are serialized as a struct of their accessor methods values,
+//! so the fields set is the same for all platforms and platform-specific values are not included.
+//! Plain enums, ex. `process::Status`, implement `Deserialize` trait too.
+//! See [`units`] module documentation for the units representation.\
+//! Note that it enables the `cpu`, `disk`, `host`, `memory`, `net`, `process`, `sensors`
+//! and `virt` components too.
+//!
+//! `fmt` feature enables human-friendly formatting helpers in the `units::fmt` module.
+//!
+//! ## Runtime
+//!
+//! File system access, other blocking operations and timers are handled
+//! by the [smol](https://crates.io/crates/smol) runtime by default.
+//! Enable the `runtime-tokio` or `runtime-async-std` feature to run all of them
+//! via [tokio](https://tokio.rs) or [async-std](https://async.rs) instead,
+//! without starting the `smol` blocking threads pool in the background;
+//! with `runtime-tokio` feature `heim` functions must be awaited within the Tokio runtime context.\
+//! Runtime features are mutually exclusive, enabling more than one leads to a compilation error.
+//!
+//! Any `heim` future can be limited in time with the [`timeout`] function,
+//! which uses the timer of the selected runtime.
+//!
 //! ## Documentation
 //!
 //! Note that `heim` also provides platform-specific APIs.
@@ -109,6 +132,7 @@ pub use heim_sensors as sensors;
 
 pub use heim_common::units;
 pub use heim_common::{Error, Result};
+pub use heim_runtime::{timeout, TimeoutError};
 
 pub mod os {
     //! Runtime specific re-exports.
@@ -116,6 +140,9 @@ pub mod os {
     pub mod linux {
         //! Linux-specific extensions.
         //!
+        //! Custom procfs and sysfs roots can be also set with the `HEIM_PROCFS_ROOT`
+        //! and `HEIM_SYSFS_ROOT` environment variables.
+        //!
         //! Available only for `cfg(target_os = "linux")`
         #[doc(inline)]
         #[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
@@ -0,0 +1,16 @@
+#![cfg(all(feature = "host", feature = "runtime-async-std"))]
+
+#[test]
+fn smoke_uptime() {
+    let uptime = async_std::task::block_on(heim::host::uptime());
+
+    assert!(uptime.is_ok());
+}
+
+#[test]
+#[cfg(feature = "memory")]
+fn smoke_memory() {
+    let memory = async_std::task::block_on(heim::memory::memory());
+
+    assert!(memory.is_ok());
+}
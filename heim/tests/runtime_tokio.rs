@@ -0,0 +1,24 @@
+#![cfg(all(feature = "host", feature = "runtime-tokio"))]
+
+#[test]
+fn smoke_uptime() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+    let uptime = runtime.block_on(heim::host::uptime());
+
+    assert!(uptime.is_ok());
+}
+
+#[test]
+#[cfg(feature = "memory")]
+fn smoke_memory() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+    let memory = runtime.block_on(heim::memory::memory());
+
+    assert!(memory.is_ok());
+}